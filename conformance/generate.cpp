@@ -0,0 +1,129 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+// Generate a conformance corpus with the Monero reference base58 implementation.
+//
+// Every line of the corpus is a record of space separated fields:
+//
+//   encode <hex data> <base58>
+//   decode <base58> <hex data | ->
+//   encode_addr <tag> <hex data> <base58>
+//   decode_addr <base58> <tag | -> <hex data | ->
+//
+// where `-` means the reference implementation rejected the input. Base58 fields can be empty,
+// the field is then an empty string between two spaces or at the end of the line.
+
+#include <cstdint>
+#include <cstdlib>
+#include <iostream>
+#include <random>
+#include <string>
+
+#include "common/base58.h"
+
+static const std::string ALPHABET =
+    "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+// Characters outside of the alphabet, visually similar or common separators
+static const std::string INVALID = "0OIl+/=_-";
+
+static std::string to_hex(const std::string &data)
+{
+  static const char digits[] = "0123456789abcdef";
+  std::string res;
+  for (unsigned char c : data)
+  {
+    res.push_back(digits[c >> 4]);
+    res.push_back(digits[c & 0x0f]);
+  }
+  return res;
+}
+
+static std::string random_bytes(std::mt19937_64 &rng, size_t len)
+{
+  std::string res;
+  for (size_t i = 0; i < len; i++)
+    res.push_back(static_cast<char>(rng() & 0xff));
+  return res;
+}
+
+// Random string mostly composed of base58 symbols, with a few invalid characters
+static std::string random_base58(std::mt19937_64 &rng, size_t len)
+{
+  std::string res;
+  for (size_t i = 0; i < len; i++)
+  {
+    if (rng() % 64 == 0)
+      res.push_back(INVALID[rng() % INVALID.size()]);
+    else
+      res.push_back(ALPHABET[rng() % ALPHABET.size()]);
+  }
+  return res;
+}
+
+// Replace one symbol of an encoded string
+static std::string mutate(std::mt19937_64 &rng, std::string enc)
+{
+  if (!enc.empty())
+    enc[rng() % enc.size()] = ALPHABET[rng() % ALPHABET.size()];
+  return enc;
+}
+
+static void decode(const std::string &enc)
+{
+  std::string data;
+  std::cout << "decode " << enc << " ";
+  if (tools::base58::decode(enc, data))
+    std::cout << to_hex(data) << "\n";
+  else
+    std::cout << "-\n";
+}
+
+static void decode_addr(const std::string &addr)
+{
+  uint64_t tag;
+  std::string data;
+  std::cout << "decode_addr " << addr << " ";
+  if (tools::base58::decode_addr(addr, tag, data))
+    std::cout << tag << " " << to_hex(data) << "\n";
+  else
+    std::cout << "- -\n";
+}
+
+int main(int argc, char **argv)
+{
+  // Number of records per kind and seed of the generator, fixed seed for a reproducible corpus
+  size_t count = argc > 1 ? std::strtoull(argv[1], nullptr, 10) : 10000;
+  std::mt19937_64 rng(argc > 2 ? std::strtoull(argv[2], nullptr, 10) : 0);
+
+  for (size_t i = 0; i < count; i++)
+  {
+    std::string data = random_bytes(rng, rng() % 128);
+    std::string enc = tools::base58::encode(data);
+    std::cout << "encode " << to_hex(data) << " " << enc << "\n";
+    decode(enc);
+    decode(mutate(rng, enc));
+    decode(random_base58(rng, rng() % 48));
+
+    // Standard and integrated addresses payloads with one and two bytes tags
+    uint64_t tag = rng() % 2 ? rng() % 0x80 : rng() % 0x4000;
+    std::string payload = random_bytes(rng, rng() % 2 ? 64 : 72);
+    std::string addr = tools::base58::encode_addr(tag, payload);
+    std::cout << "encode_addr " << tag << " " << to_hex(payload) << " " << addr << "\n";
+    decode_addr(addr);
+    decode_addr(mutate(rng, addr));
+  }
+
+  return 0;
+}
@@ -0,0 +1,11 @@
+// Smallest program encoding and decoding base58, built for wasm32 in CI to compare the binary
+// size with and without the `small` feature.
+use std::hint::black_box;
+
+use base58_monero::{decode, encode};
+
+fn main() {
+    let encoded = encode(black_box(b"Hello World")).unwrap();
+    let decoded = decode(black_box(encoded)).unwrap();
+    black_box(decoded);
+}
@@ -0,0 +1,11 @@
+#![no_main]
+
+use base58_monero::{decode, encode};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    // Any valid base58 string has a single representation
+    if let Ok(bytes) = decode(data) {
+        assert_eq!(Ok(String::from(data)), encode(&bytes));
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use base58_monero::{decode_check, encode_check};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(bytes) = decode_check(data) {
+        assert_eq!(Ok(String::from(data)), encode_check(&bytes));
+    }
+});
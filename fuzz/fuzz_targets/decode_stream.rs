@@ -0,0 +1,32 @@
+#![no_main]
+
+use base58_monero::base58::Result;
+use base58_monero::{decode, decode_check, decode_stream, decode_stream_check};
+use futures_util::pin_mut;
+use futures_util::stream::{Stream, StreamExt};
+use libfuzzer_sys::fuzz_target;
+
+// Collect a stream, stopping at the first error
+async fn collect(stream: impl Stream<Item = Result<u8>>) -> Result<Vec<u8>> {
+    pin_mut!(stream);
+    let mut res = Vec::new();
+    while let Some(value) = stream.next().await {
+        res.push(value?);
+    }
+    Ok(res)
+}
+
+fuzz_target!(|data: &str| {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    // Stream decoders must agree with the one-shot decoders
+    let mut input = data.as_bytes();
+    let res = runtime.block_on(collect(decode_stream(&mut input)));
+    assert_eq!(decode(data).ok(), res.ok());
+
+    let mut input = data.as_bytes();
+    let res = runtime.block_on(collect(decode_stream_check(&mut input)));
+    assert_eq!(decode_check(data).ok(), res.ok());
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use base58_monero::{decode, decode_check, encode, encode_check};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let encoded = encode(data).unwrap();
+    assert_eq!(Ok(Vec::from(data)), decode(&encoded));
+
+    let encoded = encode_check(data).unwrap();
+    assert_eq!(Ok(Vec::from(data)), decode_check(&encoded));
+});
@@ -0,0 +1,737 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Monero address helpers built on top of base58-check encoding
+//!
+//! A Monero address is the base58-check encoding of a varint network prefix followed by the
+//! public spend key and the public view key, integrated addresses carry an additional 8 bytes
+//! payment ID. The prefix identifies both the network and the address type.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::address::{classify_address, AddressKind, Network};
+//! use base58_monero::Error;
+//!
+//! let addr = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+//! let kind = classify_address(addr)?;
+//!
+//! assert_eq!(AddressKind::Standard(Network::Mainnet), kind);
+//! # Ok::<(), Error>(())
+//! ```
+
+use crate::base58::{decode, decode_check, encode_check, Checksum, Error, Result, CHECKSUM_SIZE};
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Size of a public key (spend or view key) in an address
+pub const PUBLIC_KEY_SIZE: usize = 32;
+/// Size of the payment ID embedded in integrated addresses
+pub const PAYMENT_ID_SIZE: usize = 8;
+/// Size of the payload following the prefix in standard addresses and subaddresses
+pub const STANDARD_PAYLOAD_SIZE: usize = 2 * PUBLIC_KEY_SIZE;
+/// Size of the payload following the prefix in integrated addresses
+pub const INTEGRATED_PAYLOAD_SIZE: usize = STANDARD_PAYLOAD_SIZE + PAYMENT_ID_SIZE;
+/// Length of an encoded standard address or subaddress
+pub const STANDARD_ADDRESS_LENGTH: usize = 95;
+/// Length of an encoded integrated address
+pub const INTEGRATED_ADDRESS_LENGTH: usize = 106;
+
+/// Address prefixes used by a network, one per address type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Prefixes {
+    /// Prefix of standard addresses
+    pub standard: u64,
+    /// Prefix of integrated addresses
+    pub integrated: u64,
+    /// Prefix of subaddresses
+    pub subaddress: u64,
+}
+
+/// Address prefixes of Monero mainnet
+pub const MAINNET_PREFIXES: Prefixes = Prefixes {
+    standard: 18,
+    integrated: 19,
+    subaddress: 42,
+};
+/// Address prefixes of Monero testnet
+pub const TESTNET_PREFIXES: Prefixes = Prefixes {
+    standard: 53,
+    integrated: 54,
+    subaddress: 63,
+};
+/// Address prefixes of Monero stagenet
+pub const STAGENET_PREFIXES: Prefixes = Prefixes {
+    standard: 24,
+    integrated: 25,
+    subaddress: 36,
+};
+
+/// Address prefixes of all Monero networks
+pub const MONERO_PREFIXES: [(Network, Prefixes); 3] = [
+    (Network::Mainnet, MAINNET_PREFIXES),
+    (Network::Testnet, TESTNET_PREFIXES),
+    (Network::Stagenet, STAGENET_PREFIXES),
+];
+
+/// Address prefixes of Wownero mainnet
+#[cfg(feature = "coins")]
+#[cfg_attr(docsrs, doc(cfg(feature = "coins")))]
+pub const WOWNERO_PREFIXES: Prefixes = Prefixes {
+    standard: 4146,
+    integrated: 6810,
+    subaddress: 12208,
+};
+/// Address prefixes of Aeon mainnet
+#[cfg(feature = "coins")]
+#[cfg_attr(docsrs, doc(cfg(feature = "coins")))]
+pub const AEON_PREFIXES: Prefixes = Prefixes {
+    standard: 0xb2,
+    integrated: 0x2733,
+    subaddress: 0x06b8,
+};
+
+/// Monero networks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// Mainnet network
+    Mainnet,
+    /// Testnet network
+    Testnet,
+    /// Stagenet network
+    Stagenet,
+}
+
+impl Network {
+    /// All known Monero networks
+    pub const ALL: [Network; 3] = [Network::Mainnet, Network::Testnet, Network::Stagenet];
+
+    /// Return the address prefixes used by the network
+    pub const fn prefixes(self) -> Prefixes {
+        match self {
+            Network::Mainnet => MAINNET_PREFIXES,
+            Network::Testnet => TESTNET_PREFIXES,
+            Network::Stagenet => STAGENET_PREFIXES,
+        }
+    }
+}
+
+/// Kind of address, i.e. the address type and the network it belongs to
+///
+/// The network defaults to the Monero [`Network`], any other type can be used to identify the
+/// networks of a caller-supplied prefix table, see [`classify_address_in`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressKind<N = Network> {
+    /// Standard address, public spend and view keys
+    Standard(N),
+    /// Integrated address, standard address with an 8 bytes payment ID
+    Integrated(N),
+    /// Subaddress, public spend and view keys of a subaddress
+    Subaddress(N),
+}
+
+impl<N: Copy> AddressKind<N> {
+    /// Return the network of the address
+    pub fn network(&self) -> N {
+        match *self {
+            AddressKind::Standard(network)
+            | AddressKind::Integrated(network)
+            | AddressKind::Subaddress(network) => network,
+        }
+    }
+
+    /// Return the size of the payload following the prefix for this kind of address
+    pub fn payload_size(&self) -> usize {
+        match self {
+            AddressKind::Integrated(_) => INTEGRATED_PAYLOAD_SIZE,
+            _ => STANDARD_PAYLOAD_SIZE,
+        }
+    }
+
+    /// Find the kind of address matching a prefix in a table of network prefixes, if any
+    pub fn from_prefix_in(prefix: u64, table: &[(N, Prefixes)]) -> Option<AddressKind<N>> {
+        table.iter().find_map(|&(network, prefixes)| {
+            if prefix == prefixes.standard {
+                Some(AddressKind::Standard(network))
+            } else if prefix == prefixes.integrated {
+                Some(AddressKind::Integrated(network))
+            } else if prefix == prefixes.subaddress {
+                Some(AddressKind::Subaddress(network))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl AddressKind {
+    /// Return the prefix used to encode this kind of address
+    pub fn prefix(&self) -> u64 {
+        let prefixes = self.network().prefixes();
+        match self {
+            AddressKind::Standard(_) => prefixes.standard,
+            AddressKind::Integrated(_) => prefixes.integrated,
+            AddressKind::Subaddress(_) => prefixes.subaddress,
+        }
+    }
+
+    /// Find the kind of address matching a prefix, if any
+    pub fn from_prefix(prefix: u64) -> Option<AddressKind> {
+        AddressKind::from_prefix_in(prefix, &MONERO_PREFIXES)
+    }
+}
+
+/// Raw components of an encoded address, see [`parse_address_parts`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AddressParts {
+    /// Varint decoded prefix
+    pub prefix: u64,
+    /// Bytes between the prefix and the checksum
+    pub payload: Vec<u8>,
+    /// Trailing 4 bytes checksum, as found in the address
+    pub checksum: Checksum,
+}
+
+// Read a varint encoded prefix, return the value and the number of bytes consumed
+pub(crate) fn read_varint(data: &[u8]) -> Result<(u64, usize)> {
+    let mut res = 0u64;
+    for (i, b) in data.iter().enumerate() {
+        // A u64 fits in at most 10 varint bytes
+        if i >= 10 {
+            break;
+        }
+        let bits = (*b & 0x7f) as u64;
+        if i == 9 && bits > 1 {
+            return Err(Error::InvalidPrefix);
+        }
+        res |= bits << (7 * i);
+        if b & 0x80 == 0 {
+            // Reject non-canonical encodings with trailing zero bytes
+            if i > 0 && *b == 0 {
+                return Err(Error::InvalidPrefix);
+            }
+            return Ok((res, i + 1));
+        }
+    }
+    Err(Error::InvalidPrefix)
+}
+
+// Append a varint encoded prefix to a buffer
+pub(crate) fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Encode a standard address or subaddress from its network prefix and public keys
+///
+/// The payload `prefix || spend_key || view_key` is encoded with its 4 bytes checksum, with a
+/// single byte prefix (all Monero prefixes) the address is 95 characters long.
+pub fn encode_address(
+    prefix: u64,
+    spend_key: &[u8; PUBLIC_KEY_SIZE],
+    view_key: &[u8; PUBLIC_KEY_SIZE],
+) -> Result<String> {
+    let mut bytes = Vec::with_capacity(10 + STANDARD_PAYLOAD_SIZE);
+    write_varint(&mut bytes, prefix);
+    bytes.extend_from_slice(spend_key);
+    bytes.extend_from_slice(view_key);
+    encode_check(&bytes)
+}
+
+/// Encode an integrated address from its network prefix, public keys and payment ID
+///
+/// The payload `prefix || spend_key || view_key || payment_id` is encoded with its 4 bytes
+/// checksum, with a single byte prefix (all Monero prefixes) the address is 106 characters long.
+pub fn encode_integrated_address(
+    prefix: u64,
+    spend_key: &[u8; PUBLIC_KEY_SIZE],
+    view_key: &[u8; PUBLIC_KEY_SIZE],
+    payment_id: &[u8; PAYMENT_ID_SIZE],
+) -> Result<String> {
+    let mut bytes = Vec::with_capacity(10 + INTEGRATED_PAYLOAD_SIZE);
+    write_varint(&mut bytes, prefix);
+    bytes.extend_from_slice(spend_key);
+    bytes.extend_from_slice(view_key);
+    bytes.extend_from_slice(payment_id);
+    encode_check(&bytes)
+}
+
+/// Decode a base58-check encoded address into its prefix and payload bytes
+///
+/// Only strings with the exact length of a Monero address, i.e. [`STANDARD_ADDRESS_LENGTH`] or
+/// [`INTEGRATED_ADDRESS_LENGTH`] characters, are accepted. Any other length is rejected with
+/// [`Error::InvalidLength`] before decoding any block.
+pub fn decode_address(addr: &str) -> Result<Vec<u8>> {
+    match addr.len() {
+        STANDARD_ADDRESS_LENGTH | INTEGRATED_ADDRESS_LENGTH => decode_check(addr),
+        _ => Err(Error::InvalidLength),
+    }
+}
+
+/// Prefix expected at the start of a base58-check payload, see [`decode_check_with_prefix`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpectedPrefix<'a> {
+    /// Varint encoded value, e.g. a Monero network prefix
+    Varint(u64),
+    /// Raw bytes
+    Bytes(&'a [u8]),
+}
+
+/// Decode a base58-check string, verify the payload begins with a prefix and return the remainder
+///
+/// The checksum is verified first, then a payload not starting with the expected prefix is
+/// rejected with [`Error::InvalidPrefix`].
+///
+/// ```rust
+/// use base58_monero::address::{decode_check_with_prefix, ExpectedPrefix, MAINNET_PREFIXES};
+///
+/// let addr = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+/// let keys = decode_check_with_prefix(addr, ExpectedPrefix::Varint(MAINNET_PREFIXES.standard))?;
+/// assert_eq!(64, keys.len());
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn decode_check_with_prefix(data: &str, prefix: ExpectedPrefix) -> Result<Vec<u8>> {
+    let mut bytes = decode_check(data)?;
+    let len = match prefix {
+        ExpectedPrefix::Varint(expected) => match read_varint(&bytes)? {
+            (value, len) if value == expected => len,
+            _ => return Err(Error::InvalidPrefix),
+        },
+        ExpectedPrefix::Bytes(expected) if bytes.starts_with(expected) => expected.len(),
+        ExpectedPrefix::Bytes(_) => return Err(Error::InvalidPrefix),
+    };
+    bytes.drain(..len);
+    Ok(bytes)
+}
+
+/// Split an encoded address into its varint prefix, payload and checksum
+///
+/// No interpretation is imposed on the components: the prefix does not have to be known, the
+/// payload can have any size and the checksum is returned without being verified.
+pub fn parse_address_parts(addr: &str) -> Result<AddressParts> {
+    let bytes = decode(addr)?;
+    if bytes.len() < CHECKSUM_SIZE {
+        return Err(Error::InvalidLength);
+    }
+    let (bytes, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+    let (prefix, len) = read_varint(bytes)?;
+    let mut check = [0; CHECKSUM_SIZE];
+    check.copy_from_slice(checksum);
+    Ok(AddressParts {
+        prefix,
+        payload: Vec::from(&bytes[len..]),
+        checksum: Checksum::from(check),
+    })
+}
+
+/// Classify a base58-check encoded address by network and address type
+///
+/// The checksum is verified, then the varint prefix is matched against the known Monero prefixes
+/// and the payload size is checked against the expected size for that type of address.
+pub fn classify_address(addr: &str) -> Result<AddressKind> {
+    classify_address_in(addr, &MONERO_PREFIXES)
+}
+
+/// Classify a base58-check encoded address against a caller-supplied table of network prefixes
+///
+/// Works as [`classify_address`] for any CryptoNote coin sharing the Monero address layout, the
+/// first network of the table with a matching prefix is returned.
+pub fn classify_address_in<N: Copy>(addr: &str, table: &[(N, Prefixes)]) -> Result<AddressKind<N>> {
+    let bytes = decode_check(addr)?;
+    let (prefix, len) = read_varint(&bytes)?;
+    let kind = AddressKind::from_prefix_in(prefix, table).ok_or(Error::InvalidPrefix)?;
+    if bytes.len() - len != kind.payload_size() {
+        return Err(Error::InvalidLength);
+    }
+    Ok(kind)
+}
+
+/// Validate a base58-check encoded address against a network
+///
+/// The address must be a valid address of any type for the given network, an address of another
+/// network is rejected with [`Error::InvalidPrefix`].
+pub fn validate_address(addr: &str, network: Network) -> Result<()> {
+    validate_address_in(addr, network, &MONERO_PREFIXES)
+}
+
+/// Validate a base58-check encoded address against a network of a caller-supplied prefix table
+pub fn validate_address_in<N: Copy + PartialEq>(
+    addr: &str,
+    network: N,
+    table: &[(N, Prefixes)],
+) -> Result<()> {
+    if classify_address_in(addr, table)?.network() != network {
+        return Err(Error::InvalidPrefix);
+    }
+    Ok(())
+}
+
+/// Validate a batch of addresses against a network in parallel
+///
+/// Each address is validated with [`validate_address`] on the rayon global thread pool, results
+/// are returned in the same order as the input addresses.
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn validate_addresses_par(addrs: &[&str], network: Network) -> Vec<Result<()>> {
+    addrs
+        .par_iter()
+        .map(|addr| validate_address(addr, network))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{
+        classify_address, classify_address_in, decode_address, decode_check_with_prefix,
+        encode_address, encode_integrated_address, parse_address_parts, read_varint,
+        validate_address, validate_address_in, write_varint, AddressKind, ExpectedPrefix, Network,
+        Prefixes, INTEGRATED_PAYLOAD_SIZE, MAINNET_PREFIXES, STAGENET_PREFIXES,
+    };
+    use crate::base58::{encode_check, Error};
+
+    #[cfg(feature = "parallel")]
+    use super::validate_addresses_par;
+    #[cfg(feature = "coins")]
+    use super::{AEON_PREFIXES, WOWNERO_PREFIXES};
+
+    fn address(prefix: &[u8], payload_size: usize) -> String {
+        let mut bytes = Vec::from(prefix);
+        bytes.extend((0..payload_size).map(|i| i as u8));
+        encode_check(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_read_varint() {
+        assert_eq!(Ok((0x12, 1)), read_varint(b"\x12\xff"));
+        assert_eq!(Ok((0x80, 2)), read_varint(b"\x80\x01"));
+        assert_eq!(Ok((0x3fff, 2)), read_varint(b"\xff\x7f"));
+        assert_eq!(Err(Error::InvalidPrefix), read_varint(b""));
+        assert_eq!(Err(Error::InvalidPrefix), read_varint(b"\x80"));
+        assert_eq!(Err(Error::InvalidPrefix), read_varint(b"\x80\x00"));
+        assert_eq!(Err(Error::InvalidPrefix), read_varint(&[0xff; 11]));
+    }
+
+    #[test]
+    fn test_write_varint() {
+        for value in [0, 0x12, 0x7f, 0x80, 0x3fff, 0x4000, u64::MAX] {
+            let mut buf = Vec::new();
+            write_varint(&mut buf, value);
+            assert_eq!(Ok((value, buf.len())), read_varint(&buf));
+        }
+    }
+
+    #[test]
+    fn test_encode_address() {
+        let bytes = hex::decode("f4bd0587c43594b0ddb2ef4e616d24232d14eee07f45b46ac19ef3b11e7c7e6be2a59b6284ad5b1a1b43051d07e788756dcfff36008637322a1c975eeb614927").unwrap();
+        let spend_key: [u8; 32] = bytes[..32].try_into().unwrap();
+        let view_key: [u8; 32] = bytes[32..].try_into().unwrap();
+
+        let addr = encode_address(MAINNET_PREFIXES.standard, &spend_key, &view_key).unwrap();
+        assert_eq!(
+            "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5",
+            addr
+        );
+        assert_eq!(95, addr.len());
+
+        let addr = encode_address(STAGENET_PREFIXES.subaddress, &spend_key, &view_key).unwrap();
+        assert_eq!(95, addr.len());
+        assert_eq!(
+            Ok(AddressKind::Subaddress(Network::Stagenet)),
+            classify_address(&addr)
+        );
+
+        let addr = encode_integrated_address(
+            MAINNET_PREFIXES.integrated,
+            &spend_key,
+            &view_key,
+            &[0xab; 8],
+        )
+        .unwrap();
+        assert_eq!(106, addr.len());
+        assert_eq!(
+            Ok(AddressKind::Integrated(Network::Mainnet)),
+            classify_address(&addr)
+        );
+    }
+
+    #[test]
+    fn test_decode_address() {
+        let addr = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+        assert_eq!(
+            hex::decode("12f4bd0587c43594b0ddb2ef4e616d24232d14eee07f45b46ac19ef3b11e7c7e6be2a59b6284ad5b1a1b43051d07e788756dcfff36008637322a1c975eeb614927").unwrap(),
+            decode_address(addr).unwrap()
+        );
+        let addr = address(&[19], INTEGRATED_PAYLOAD_SIZE);
+        assert_eq!(
+            1 + INTEGRATED_PAYLOAD_SIZE,
+            decode_address(&addr).unwrap().len()
+        );
+
+        // Valid base58-check strings with a non address length
+        assert_eq!(Err(Error::InvalidLength), decode_address(""));
+        assert_eq!(
+            Err(Error::InvalidLength),
+            decode_address(&address(&[18], 63))
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            decode_address(&address(&[0x92, 0x01], 64))
+        );
+        // Length is checked before the symbols
+        assert_eq!(Err(Error::InvalidLength), decode_address("0OIl"));
+        // Corrupted checksum
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_address("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk6")
+        );
+    }
+
+    #[test]
+    fn test_decode_check_with_prefix() {
+        let addr = address(b"\x12", 64);
+        let payload: Vec<u8> = (0..64).collect();
+        assert_eq!(
+            Ok(payload.clone()),
+            decode_check_with_prefix(&addr, ExpectedPrefix::Varint(18))
+        );
+        assert_eq!(
+            Ok(payload),
+            decode_check_with_prefix(&addr, ExpectedPrefix::Bytes(b"\x12"))
+        );
+        assert_eq!(
+            Ok(65),
+            decode_check_with_prefix(&addr, ExpectedPrefix::Bytes(b"")).map(|bytes| bytes.len())
+        );
+
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            decode_check_with_prefix(&addr, ExpectedPrefix::Varint(53))
+        );
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            decode_check_with_prefix(&addr, ExpectedPrefix::Bytes(b"\x12\x01"))
+        );
+        // Multi-byte varint prefix
+        let addr = address(b"\x80\x01", 8);
+        assert_eq!(
+            8,
+            decode_check_with_prefix(&addr, ExpectedPrefix::Varint(0x80))
+                .unwrap()
+                .len()
+        );
+        // Checksum is verified before the prefix
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_with_prefix("D7LMXYjUbXc1fS9Z", ExpectedPrefix::Varint(18))
+        );
+    }
+
+    #[test]
+    fn test_parse_address_parts() {
+        let parts = parse_address_parts("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5").unwrap();
+        assert_eq!(18, parts.prefix);
+        assert_eq!(
+            hex::decode("f4bd0587c43594b0ddb2ef4e616d24232d14eee07f45b46ac19ef3b11e7c7e6be2a59b6284ad5b1a1b43051d07e788756dcfff36008637322a1c975eeb614927").unwrap(),
+            parts.payload
+        );
+        assert_eq!(&[0x46, 0x47, 0x45, 0x1e], parts.checksum.as_bytes());
+
+        // Checksum is not verified
+        let parts = parse_address_parts("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk6").unwrap();
+        assert_eq!(&[0x46, 0x47, 0x45, 0x1f], parts.checksum.as_bytes());
+
+        // Unknown prefix and arbitrary payload size
+        let parts = parse_address_parts(&address(&[0x92, 0x01], 3)).unwrap();
+        assert_eq!(0x92, parts.prefix);
+        assert_eq!(Vec::from([0, 1, 2]), parts.payload);
+
+        assert_eq!(Err(Error::InvalidLength), parse_address_parts("111"));
+        assert_eq!(Err(Error::InvalidPrefix), parse_address_parts("111111"));
+        assert_eq!(Err(Error::InvalidSymbol), parse_address_parts("0OI"));
+    }
+
+    #[test]
+    fn test_classify_address() {
+        assert_eq!(
+            Ok(AddressKind::Standard(Network::Mainnet)),
+            classify_address("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5")
+        );
+        assert_eq!(
+            Ok(AddressKind::Standard(Network::Mainnet)),
+            classify_address("44AFFq5kSiGBoZ4NMDwYtN18obc8AemS33DBLWs3H7otXft3XjrpDtQGv7SqSsaBYBb98uNbr2VBBEt7f2wfn3RVGQBEP3A")
+        );
+
+        for network in Network::ALL {
+            let prefixes = network.prefixes();
+            assert_eq!(
+                Ok(AddressKind::Standard(network)),
+                classify_address(&address(&[prefixes.standard as u8], 64))
+            );
+            assert_eq!(
+                Ok(AddressKind::Integrated(network)),
+                classify_address(&address(
+                    &[prefixes.integrated as u8],
+                    INTEGRATED_PAYLOAD_SIZE
+                ))
+            );
+            assert_eq!(
+                Ok(AddressKind::Subaddress(network)),
+                classify_address(&address(&[prefixes.subaddress as u8], 64))
+            );
+        }
+    }
+
+    #[test]
+    fn test_classify_address_invalid() {
+        // Unknown prefix
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            classify_address(&address(&[0x11], 64))
+        );
+        // Multi-bytes prefix not in the table
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            classify_address(&address(&[0x92, 0x01], 64))
+        );
+        // Integrated prefix with a standard payload and vice versa
+        assert_eq!(
+            Err(Error::InvalidLength),
+            classify_address(&address(&[19], 64))
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            classify_address(&address(&[18], INTEGRATED_PAYLOAD_SIZE))
+        );
+        // Corrupted checksum
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            classify_address("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk6")
+        );
+    }
+
+    #[test]
+    fn test_validate_address() {
+        let addr = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+        assert_eq!(Ok(()), validate_address(addr, Network::Mainnet));
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            validate_address(addr, Network::Stagenet)
+        );
+        assert_eq!(
+            Ok(()),
+            validate_address(&address(&[63], 64), Network::Testnet)
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            validate_address(&address(&[53], INTEGRATED_PAYLOAD_SIZE), Network::Testnet)
+        );
+    }
+
+    #[test]
+    fn test_classify_address_in() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Coin {
+            Foo,
+            Bar,
+        }
+        let foo = Prefixes {
+            standard: 0x10,
+            integrated: 0x11,
+            subaddress: 0x12,
+        };
+        let bar = Prefixes {
+            standard: 0x1232,
+            integrated: 0x1233,
+            subaddress: 0x1234,
+        };
+        let table = [(Coin::Foo, foo), (Coin::Bar, bar)];
+
+        assert_eq!(
+            Ok(AddressKind::Subaddress(Coin::Foo)),
+            classify_address_in(&address(&[0x12], 64), &table)
+        );
+        assert_eq!(
+            Ok(AddressKind::Integrated(Coin::Bar)),
+            classify_address_in(&address(&[0xb3, 0x24], INTEGRATED_PAYLOAD_SIZE), &table)
+        );
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            classify_address_in(&address(&[42], 64), &table)
+        );
+        assert_eq!(
+            Ok(()),
+            validate_address_in(&address(&[0xb2, 0x24], 64), Coin::Bar, &table)
+        );
+        assert_eq!(
+            Err(Error::InvalidPrefix),
+            validate_address_in(&address(&[0xb2, 0x24], 64), Coin::Foo, &table)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "coins")]
+    fn test_coins_prefixes() {
+        let table = [("wownero", WOWNERO_PREFIXES), ("aeon", AEON_PREFIXES)];
+
+        let addr = encode_address(WOWNERO_PREFIXES.standard, &[0; 32], &[0; 32]).unwrap();
+        assert!(addr.starts_with("Wo"));
+        assert_eq!(
+            Ok(AddressKind::Standard("wownero")),
+            classify_address_in(&addr, &table)
+        );
+        let addr = encode_address(AEON_PREFIXES.subaddress, &[0; 32], &[0; 32]).unwrap();
+        assert!(addr.starts_with("Xn"));
+        assert_eq!(
+            Ok(AddressKind::Subaddress("aeon")),
+            classify_address_in(&addr, &table)
+        );
+        assert_eq!(Err(Error::InvalidPrefix), classify_address(&addr));
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_validate_addresses_par() {
+        let addrs = [
+            address(&[18], 64),
+            address(&[19], INTEGRATED_PAYLOAD_SIZE),
+            address(&[24], 64),
+            address(&[0x11], 64),
+            String::from("4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk6"),
+        ];
+        let addrs: Vec<&str> = addrs.iter().map(String::as_str).collect();
+        assert_eq!(
+            vec![
+                Ok(()),
+                Ok(()),
+                Err(Error::InvalidPrefix),
+                Err(Error::InvalidPrefix),
+                Err(Error::InvalidChecksum),
+            ],
+            validate_addresses_par(&addrs, Network::Mainnet)
+        );
+    }
+}
@@ -0,0 +1,280 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Axum extractors of base58 parameters
+//!
+//! [`Base58<T>`] and [`Base58Check<T>`] decode a base58, or base58-check, parameter and convert
+//! the bytes into `T`, e.g. `Vec<u8>` or `[u8; 32]` to require an exact length. Both types are:
+//!
+//!  * extractors of the single path parameter of a route, implementing `FromRequestParts`,
+//!  * deserializable from strings, to be used in `Path<(..)>` tuples and `Query<T>` structs.
+//!
+//! Invalid parameters are rejected with `400 Bad Request` and a [`Base58Rejection`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use axum::routing::get;
+//! use axum::Router;
+//! use base58_monero::axum::Base58Check;
+//!
+//! async fn key(Base58Check(key): Base58Check<[u8; 32]>) -> String {
+//!     format!("{:02x?}", key)
+//! }
+//!
+//! let app: Router = Router::new().route("/keys/{key}", get(key));
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::axum::extract::rejection::PathRejection;
+use ::axum::extract::{FromRequestParts, Path};
+use ::axum::http::request::Parts;
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+
+use crate::base58::{decode, decode_check, Error, Result};
+
+/// Parameter decoded from base58
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58<T>(pub T);
+
+/// Parameter decoded from base58 with its 4 bytes checksum verified
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base58Check<T>(pub T);
+
+// Convert the decoded bytes, a conversion failure can only be caused by the length
+fn convert<T: TryFrom<Vec<u8>>>(bytes: Result<Vec<u8>>) -> Result<T> {
+    T::try_from(bytes?).map_err(|_| Error::InvalidLength)
+}
+
+/// Rejection of the base58 extractors
+#[derive(Debug)]
+pub enum Base58Rejection {
+    /// The route has no single path parameter
+    Path(PathRejection),
+    /// The parameter is invalid or does not decode to the expected length
+    Invalid(Error),
+}
+
+impl fmt::Display for Base58Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base58Rejection::Path(e) => write!(f, "{}", e),
+            Base58Rejection::Invalid(e) => write!(f, "Invalid base58 parameter: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Base58Rejection {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Base58Rejection::Path(e) => Some(e),
+            Base58Rejection::Invalid(e) => Some(e),
+        }
+    }
+}
+
+impl IntoResponse for Base58Rejection {
+    fn into_response(self) -> Response {
+        match self {
+            Base58Rejection::Path(e) => e.into_response(),
+            Base58Rejection::Invalid(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+        }
+    }
+}
+
+// Extract the single path parameter of the route
+async fn path_param<S: Send + Sync>(
+    parts: &mut Parts,
+    state: &S,
+) -> core::result::Result<String, Base58Rejection> {
+    let Path(param) = Path::<String>::from_request_parts(parts, state)
+        .await
+        .map_err(Base58Rejection::Path)?;
+    Ok(param)
+}
+
+impl<T, S> FromRequestParts<S> for Base58<T>
+where
+    T: TryFrom<Vec<u8>>,
+    S: Send + Sync,
+{
+    type Rejection = Base58Rejection;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> core::result::Result<Self, Self::Rejection> {
+        let param = path_param(parts, state).await?;
+        convert(decode(param))
+            .map(Self)
+            .map_err(Base58Rejection::Invalid)
+    }
+}
+
+impl<T, S> FromRequestParts<S> for Base58Check<T>
+where
+    T: TryFrom<Vec<u8>>,
+    S: Send + Sync,
+{
+    type Rejection = Base58Rejection;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> core::result::Result<Self, Self::Rejection> {
+        let param = path_param(parts, state).await?;
+        convert(decode_check(param))
+            .map(Self)
+            .map_err(Base58Rejection::Invalid)
+    }
+}
+
+// Visitor decoding a string with `decode`, then converting the bytes into `T`
+struct DecodeVisitor<T> {
+    decode: fn(&str) -> Result<Vec<u8>>,
+    marker: PhantomData<T>,
+}
+
+impl<T: TryFrom<Vec<u8>>> Visitor<'_> for DecodeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a base58 string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> core::result::Result<T, E> {
+        convert((self.decode)(v)).map_err(E::custom)
+    }
+}
+
+impl<'de, T: TryFrom<Vec<u8>>> Deserialize<'de> for Base58<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        deserializer
+            .deserialize_str(DecodeVisitor {
+                decode: |s| decode(s),
+                marker: PhantomData,
+            })
+            .map(Self)
+    }
+}
+
+impl<'de, T: TryFrom<Vec<u8>>> Deserialize<'de> for Base58Check<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        deserializer
+            .deserialize_str(DecodeVisitor {
+                decode: |s| decode_check(s),
+                marker: PhantomData,
+            })
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use ::axum::body::Body;
+    use ::axum::extract::{Path, Query};
+    use ::axum::http::{Request, StatusCode};
+    use ::axum::routing::get;
+    use ::axum::Router;
+    use http_body_util::BodyExt;
+    use serde::Deserialize;
+    use tower::ServiceExt;
+
+    use super::{Base58, Base58Check};
+
+    type Pair = (Base58<Vec<u8>>, Base58Check<Vec<u8>>);
+
+    #[derive(Deserialize)]
+    struct Params {
+        id: Base58<Vec<u8>>,
+        key: Option<Base58Check<[u8; 11]>>,
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/raw/{data}",
+                get(
+                    |Base58(data): Base58<Vec<u8>>| async move { String::from_utf8(data).unwrap() },
+                ),
+            )
+            .route(
+                "/check/{key}",
+                get(|Base58Check(key): Base58Check<[u8; 11]>| async move { key.len().to_string() }),
+            )
+            .route(
+                "/pair/{a}/{b}",
+                get(|Path((a, b)): Path<Pair>| async move { (a.0.len() + b.0.len()).to_string() }),
+            )
+            .route(
+                "/query",
+                get(|Query(p): Query<Params>| async move {
+                    format!("{} {}", p.id.0.len(), p.key.is_some())
+                }),
+            )
+    }
+
+    async fn get_uri(uri: &str) -> (StatusCode, String) {
+        let request = Request::get(uri).body(Body::empty()).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(Vec::from(&body[..])).unwrap())
+    }
+
+    #[test]
+    fn test_axum_extractors() {
+        tokio_test::block_on(async {
+            let ok = |body: &str| (StatusCode::OK, String::from(body));
+            assert_eq!(ok("Hello World"), get_uri("/raw/D7LMXYjUbXc1fS9Z").await);
+            assert_eq!(ok("11"), get_uri("/check/D7LMXYjUbXc5LVkq6vWDY").await);
+            assert_eq!(
+                ok("22"),
+                get_uri("/pair/D7LMXYjUbXc1fS9Z/D7LMXYjUbXc5LVkq6vWDY").await
+            );
+            assert_eq!(ok("11 false"), get_uri("/query?id=D7LMXYjUbXc1fS9Z").await);
+            assert_eq!(
+                ok("11 true"),
+                get_uri("/query?id=D7LMXYjUbXc1fS9Z&key=D7LMXYjUbXc5LVkq6vWDY").await
+            );
+
+            let (status, body) = get_uri("/raw/10").await;
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("Invalid base58 parameter: Invalid symbol error", body);
+            let (status, body) = get_uri("/check/D7LMXYjUbXc1fS9Z").await;
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("Invalid base58 parameter: Invalid checksum error", body);
+            // Valid checksum, wrong length
+            let (status, body) = get_uri("/check/64HByS").await;
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+            assert_eq!("Invalid base58 parameter: Invalid length error", body);
+            let (status, _) = get_uri("/query?id=D7LMXYjUbXc1fS9Z&key=64HByS").await;
+            assert_eq!(StatusCode::BAD_REQUEST, status);
+        });
+    }
+}
@@ -20,6 +20,8 @@
 //! Async streams can be used with the `stream` feature:
 //!
 //! ```rust
+//! # #[cfg(feature = "stream")]
+//! # fn main() -> Result<(), base58_monero::Error> {
 //! use futures_util::pin_mut;
 //! use futures_util::stream::StreamExt;
 //! use base58_monero::{encode_stream, Error};
@@ -41,12 +43,23 @@
 //!     # Ok::<(), Error>(())
 //! }
 //! # )?;
-//! # Ok::<(), Error>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "stream"))]
+//! # fn main() {}
 //! ```
+//!
+//! Streams are fused, polling them after completion returns `None`. They are `Send` when the
+//! input reader is `Send` and are always `Unpin`, their state is boxed so they can be polled, e.g.
+//! with `StreamExt::next`, or stored in `select!` loops without pinning. The input reader is
+//! pinned within the stream and does not have to be `Unpin`, e.g. some TLS or compression readers.
+//!
 //! Async decoding with `decode_stream` and `decode_stream_check` is available with the features `check` and
 //! `stream` enabled:
 //!
 //! ```rust
+//! # #[cfg(all(feature = "check", feature = "stream"))]
+//! # fn main() -> Result<(), base58_monero::Error> {
 //! use futures_util::pin_mut;
 //! use futures_util::stream::StreamExt;
 //! use base58_monero::{decode_stream_check, Error};
@@ -67,35 +80,143 @@
 //!     # Ok::<(), Error>(())
 //! }
 //! # )?;
-//! # Ok::<(), Error>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(all(feature = "check", feature = "stream")))]
+//! # fn main() {}
 //! ```
 
 #[cfg(feature = "stream")]
 use async_stream::try_stream;
+#[cfg(feature = "bytes")]
+use bytes::Buf;
 #[cfg(feature = "stream")]
-use futures_util::stream::Stream;
+use core::pin::Pin;
+#[cfg(feature = "stream")]
+use core::task::Context;
+use core::task::Poll;
+#[cfg(feature = "stream")]
+use futures_util::stream::FusedStream;
 #[cfg(all(feature = "check", feature = "stream"))]
-use futures_util::{pin_mut, stream::StreamExt};
+use futures_util::stream::Stream;
+#[cfg(feature = "stream")]
+use futures_util::{pin_mut, ready, sink::Sink, stream::StreamExt};
+// generic-array 0.14 is deprecated in favor of 1.x, but 0.14 is the version used by the RustCrypto
+// traits
+#[cfg(feature = "generic-array")]
+#[allow(deprecated)]
+use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+#[cfg(feature = "check")]
+use subtle::ConstantTimeEq;
+#[cfg(all(feature = "check", feature = "alloc"))]
+use tiny_keccak::Kmac;
 #[cfg(feature = "check")]
 use tiny_keccak::{Hasher, Keccak};
 
 #[cfg(feature = "stream")]
 use tokio::io;
 #[cfg(feature = "stream")]
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncRead, AsyncReadExt};
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", not(feature = "small")))]
 use thiserror::Error;
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use core::num::Wrapping;
 
 /// Base58 alphabet, does not contains visualy similar characters
 pub const BASE58_CHARS: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// Reverse of [`BASE58_CHARS`], digit of each ASCII character, [`INVALID_DIGIT`] if the character
+/// is not in the alphabet
+pub const BASE58_DIGITS: [u8; 128] = Alphabet::MONERO.digits;
+/// Value of the characters not in the alphabet in [`BASE58_DIGITS`]
+pub const INVALID_DIGIT: u8 = 0xff;
+
+// Digit of each byte, `BASE58_DIGITS` extended to all bytes so decoding indexes it without
+// checking the byte is ASCII first
+#[cfg(not(feature = "small"))]
+const DECODE_TABLE: [u8; 256] = {
+    let mut table = [INVALID_DIGIT; 256];
+    let mut i = 0;
+    while i < BASE58_DIGITS.len() {
+        table[i] = BASE58_DIGITS[i];
+        i += 1;
+    }
+    table
+};
+
+// Digit of a byte, `INVALID_DIGIT` if not in the alphabet
+#[cfg(not(feature = "small"))]
+#[inline]
+fn decode_digit(c: u8) -> u8 {
+    DECODE_TABLE[c as usize]
+}
+
+// Digit of a byte computed from the ranges of the alphabet, the `small` feature does not keep the
+// lookup table
+#[cfg(feature = "small")]
+fn decode_digit(c: u8) -> u8 {
+    match c {
+        b'1'..=b'9' => c - b'1',
+        b'A'..=b'H' => c - b'A' + 9,
+        b'J'..=b'N' => c - b'J' + 17,
+        b'P'..=b'Z' => c - b'P' + 22,
+        b'a'..=b'k' => c - b'a' + 33,
+        b'm'..=b'z' => c - b'm' + 44,
+        _ => INVALID_DIGIT,
+    }
+}
+
+/// Return the digit of a character of the base58 alphabet, `None` if not in the alphabet
+///
+/// ```rust
+/// use base58_monero::base58::{char_to_index, index_to_char};
+///
+/// assert_eq!(Some(0), char_to_index('1'));
+/// assert_eq!(None, char_to_index('l'));
+/// assert_eq!(Some('z'), index_to_char(57));
+/// ```
+pub const fn char_to_index(c: char) -> Option<u8> {
+    if c.is_ascii() {
+        Alphabet::MONERO.digit(c as u8)
+    } else {
+        None
+    }
+}
+
+/// Return the character of a digit of the base58 alphabet, `None` if the digit is over `57`
+pub const fn index_to_char(digit: u8) -> Option<char> {
+    if (digit as usize) < BASE58_CHARS.len() {
+        Some(BASE58_CHARS[digit as usize] as char)
+    } else {
+        None
+    }
+}
 /// Resulted block size given a `0..=8` bytes block
 pub const ENCODED_BLOCK_SIZES: [usize; 9] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+/// Resulted block size given a `0..=11` characters block, `None` if no block encodes to this size
+///
+/// Inverse of [`ENCODED_BLOCK_SIZES`].
+pub const DECODED_BLOCK_SIZES: [Option<usize>; 12] = [
+    Some(0),
+    None,
+    Some(1),
+    Some(2),
+    None,
+    Some(3),
+    Some(4),
+    Some(5),
+    None,
+    Some(6),
+    Some(7),
+    Some(8),
+];
 /// Maximum size of block to encode
 pub const FULL_BLOCK_SIZE: usize = 8;
 /// Size of an encoded 8 bytes block, i.e. maximum encoded block size
@@ -103,9 +224,102 @@ pub const FULL_ENCODED_BLOCK_SIZE: usize = ENCODED_BLOCK_SIZES[FULL_BLOCK_SIZE];
 /// Size of checksum
 pub const CHECKSUM_SIZE: usize = 4;
 
+/// Return the size of the block decoded from `encoded_len` characters
+///
+/// Returns `None` if `encoded_len` is over [`FULL_ENCODED_BLOCK_SIZE`] or is not the encoded size
+/// of any block, see [`DECODED_BLOCK_SIZES`].
+pub const fn decoded_block_size(encoded_len: usize) -> Option<usize> {
+    if encoded_len < DECODED_BLOCK_SIZES.len() {
+        DECODED_BLOCK_SIZES[encoded_len]
+    } else {
+        None
+    }
+}
+
+/// Return the length of the base58 string encoding `len` bytes
+pub const fn encoded_len(len: usize) -> usize {
+    len / FULL_BLOCK_SIZE * FULL_ENCODED_BLOCK_SIZE + ENCODED_BLOCK_SIZES[len % FULL_BLOCK_SIZE]
+}
+
+/// Return the number of bytes decoded from a base58 string of `encoded_len` characters
+///
+/// Returns `None` if the last block has an invalid size, i.e. decoding would fail with
+/// [`Error::InvalidBlockSize`].
+pub const fn decoded_len(encoded_len: usize) -> Option<usize> {
+    match decoded_block_size(encoded_len % FULL_ENCODED_BLOCK_SIZE) {
+        Some(size) => Some(encoded_len / FULL_ENCODED_BLOCK_SIZE * FULL_BLOCK_SIZE + size),
+        None => None,
+    }
+}
+
+/// Base58 alphabet mapping the 58 digits to ASCII characters
+///
+/// Alphabets are validated when created, [`Alphabet::new`] is a `const fn` so a misconfigured
+/// alphabet defined as a constant fails to compile:
+///
+/// ```compile_fail
+/// use base58_monero::base58::Alphabet;
+///
+/// // Duplicate character
+/// const ALPHABET: Alphabet = Alphabet::new(&[b'1'; 58]);
+/// let _ = ALPHABET;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    chars: [u8; 58],
+    // Digit of each ASCII character, `INVALID_DIGIT` if not in the alphabet
+    digits: [u8; 128],
+}
+
+impl Alphabet {
+    /// Monero base58 alphabet, i.e. [`BASE58_CHARS`]
+    pub const MONERO: Alphabet =
+        Alphabet::new(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// Create an alphabet from its 58 characters ordered by digit value
+    ///
+    /// # Panics
+    ///
+    /// Panics, at compile time in const contexts, if a character is not ASCII or is duplicated.
+    pub const fn new(chars: &[u8; 58]) -> Self {
+        let mut digits = [INVALID_DIGIT; 128];
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if !c.is_ascii() {
+                panic!("non-ASCII character in base58 alphabet");
+            }
+            if digits[c as usize] != INVALID_DIGIT {
+                panic!("duplicate character in base58 alphabet");
+            }
+            digits[c as usize] = i as u8;
+            i += 1;
+        }
+        Self {
+            chars: *chars,
+            digits,
+        }
+    }
+
+    /// Return the characters of the alphabet ordered by digit value
+    pub const fn as_bytes(&self) -> &[u8; 58] {
+        &self.chars
+    }
+
+    /// Return the digit value of a character, `None` if not in the alphabet
+    pub const fn digit(&self, c: u8) -> Option<u8> {
+        if c.is_ascii() && self.digits[c as usize] != INVALID_DIGIT {
+            Some(self.digits[c as usize])
+        } else {
+            None
+        }
+    }
+}
+
 /// Possible errors when encoding/decoding base58 and base58-check strings
 #[derive(Debug)]
-#[cfg_attr(feature = "std", derive(Error))]
+#[cfg_attr(all(feature = "std", not(feature = "small")), derive(Error))]
+#[non_exhaustive]
 pub enum Error {
     /// Invalid block size, must be `1..=8`
     InvalidBlockSize,
@@ -115,8 +329,18 @@ pub enum Error {
     #[cfg(feature = "check")]
     #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
     InvalidChecksum,
+    /// Unknown or malformed address prefix
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    InvalidPrefix,
+    /// Invalid length of the decoded data
+    InvalidLength,
     /// Decoding overflow
     Overflow,
+    /// Output buffer too small for the encoded or decoded data
+    BufferTooSmall,
+    /// Memory allocation failure in the fallible allocation functions
+    OutOfMemory,
     /// IO error on stream
     ///
     /// [PartialEq] implementation return true if the other error is also and IO error but do NOT
@@ -126,6 +350,10 @@ pub enum Error {
     Io(io::Error),
 }
 
+// Without the derive macro to keep the generated code minimal, see the `small` feature.
+#[cfg(all(feature = "std", feature = "small"))]
+impl std::error::Error for Error {}
+
 // Implementation of the From trait to allow conversion from an io::Error to Error variant Io.
 #[cfg(feature = "stream")]
 impl From<io::Error> for Error {
@@ -134,6 +362,13 @@ impl From<io::Error> for Error {
     }
 }
 
+// Allow infallible sinks and writers to be used where a base58 error is expected.
+impl From<core::convert::Infallible> for Error {
+    fn from(v: core::convert::Infallible) -> Self {
+        match v {}
+    }
+}
+
 // Message to display when Error variants thrown.
 impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -142,12 +377,68 @@ impl core::fmt::Display for Error {
             Error::InvalidSymbol => "Invalid symbol error",
             #[cfg(feature = "check")]
             Error::InvalidChecksum => "Invalid checksum error",
+            #[cfg(feature = "check")]
+            Error::InvalidPrefix => "Invalid prefix error",
+            Error::InvalidLength => "Invalid length error",
             Error::Overflow => "Overflow error",
+            Error::BufferTooSmall => "Buffer too small error",
+            Error::OutOfMemory => "Out of memory error",
             #[cfg(feature = "stream")]
             // Ignore what Io error is wrapped
             Error::Io(_) => "IO error: {0}",
         };
-        write!(f, "{}", message)
+        f.write_str(message)
+    }
+}
+
+impl Error {
+    /// Return a stable identifier of the error, e.g. `"base58.invalid_checksum"`
+    ///
+    /// Identifiers never change between releases, applications can map them to localized messages
+    /// instead of parsing the English [`Display`](core::fmt::Display) output. The IO error wrapped
+    /// by `Error::Io` is not part of the identifier, match on the variant to access it.
+    ///
+    /// ```rust
+    /// use base58_monero::{decode, Error};
+    ///
+    /// let err = decode("10").unwrap_err();
+    /// assert_eq!("base58.invalid_symbol", err.message_key());
+    /// ```
+    pub fn message_key(&self) -> &'static str {
+        match self {
+            Error::InvalidBlockSize => "base58.invalid_block_size",
+            Error::InvalidSymbol => "base58.invalid_symbol",
+            #[cfg(feature = "check")]
+            Error::InvalidChecksum => "base58.invalid_checksum",
+            #[cfg(feature = "check")]
+            Error::InvalidPrefix => "base58.invalid_prefix",
+            Error::InvalidLength => "base58.invalid_length",
+            Error::Overflow => "base58.overflow",
+            Error::BufferTooSmall => "base58.buffer_too_small",
+            Error::OutOfMemory => "base58.out_of_memory",
+            #[cfg(feature = "stream")]
+            Error::Io(_) => "base58.io",
+        }
+    }
+
+    // Copy of the error, to return a stored error again, an IO error is copied with its kind and
+    // message
+    #[cfg(feature = "std")]
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Error::InvalidBlockSize => Error::InvalidBlockSize,
+            Error::InvalidSymbol => Error::InvalidSymbol,
+            #[cfg(feature = "check")]
+            Error::InvalidChecksum => Error::InvalidChecksum,
+            #[cfg(feature = "check")]
+            Error::InvalidPrefix => Error::InvalidPrefix,
+            Error::InvalidLength => Error::InvalidLength,
+            Error::Overflow => Error::Overflow,
+            Error::BufferTooSmall => Error::BufferTooSmall,
+            Error::OutOfMemory => Error::OutOfMemory,
+            #[cfg(feature = "stream")]
+            Error::Io(e) => Error::Io(io::Error::new(e.kind(), e.to_string())),
+        }
     }
 }
 
@@ -158,7 +449,12 @@ impl PartialEq for Error {
             Error::InvalidSymbol => matches!(other, Error::InvalidSymbol),
             #[cfg(feature = "check")]
             Error::InvalidChecksum => matches!(other, Error::InvalidChecksum),
+            #[cfg(feature = "check")]
+            Error::InvalidPrefix => matches!(other, Error::InvalidPrefix),
+            Error::InvalidLength => matches!(other, Error::InvalidLength),
             Error::Overflow => matches!(other, Error::Overflow),
+            Error::BufferTooSmall => matches!(other, Error::BufferTooSmall),
+            Error::OutOfMemory => matches!(other, Error::OutOfMemory),
             #[cfg(feature = "stream")]
             // Ignore what Io error is wrapped
             Error::Io(_) => matches!(other, Error::Io(_)),
@@ -166,678 +462,5723 @@ impl PartialEq for Error {
     }
 }
 
-/// Utility type for handling results with base58 error type
-pub type Result<T> = core::result::Result<T, Error>;
-
-fn u8be_to_u64(data: &[u8]) -> u64 {
-    let mut res = 0u64;
-    for b in data {
-        res = res << 8 | *b as u64;
-    }
-    res
+/// Decoding error located in the input, returned by [`decode_verbose`]
+///
+/// Same failures as the decoding variants of [`Error`], with the position of the failure in the
+/// input. Offsets are in bytes from the start of the input. Convert into [`Error`] with `From` to
+/// drop the position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input length is invalid, its last block has 1, 4 or 8 characters
+    InvalidBlockSize {
+        /// Length of the input
+        len: usize,
+    },
+    /// Symbol not in base58 alphabet
+    InvalidSymbol {
+        /// Offset of the symbol, the start of the character for non-ASCII characters
+        offset: usize,
+        /// Character at the offset, `U+FFFD` if it is not valid UTF-8, e.g. when a stream block
+        /// ends in the middle of the character
+        symbol: char,
+    },
+    /// A block decodes to a value larger than its decoded size
+    Overflow {
+        /// Index of the block, from 0
+        block: usize,
+        /// Offset of the first character of the block
+        offset: usize,
+    },
+    /// Invalid 4-bytes checksum
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    InvalidChecksum,
 }
 
-fn encode_block(data: &[u8]) -> Result<[char; FULL_ENCODED_BLOCK_SIZE]> {
-    if data.is_empty() || data.len() > FULL_BLOCK_SIZE {
-        return Err(Error::InvalidBlockSize);
+impl DecodeError {
+    // Locate the error of decoding the block starting at byte `offset`, `rest` is the input from
+    // the start of the block and `len` the length of the whole input
+    #[cfg(feature = "alloc")]
+    fn locate(error: &Error, rest: &[u8], offset: usize, len: usize) -> Self {
+        match error {
+            Error::InvalidSymbol => {
+                let block = &rest[..rest.len().min(FULL_ENCODED_BLOCK_SIZE)];
+                let i = block
+                    .iter()
+                    .position(|&c| decode_digit(c) == INVALID_DIGIT)
+                    .unwrap_or(0);
+                DecodeError::InvalidSymbol {
+                    offset: offset + i,
+                    symbol: first_char(&rest[i..]),
+                }
+            }
+            Error::Overflow => DecodeError::Overflow {
+                block: offset / FULL_ENCODED_BLOCK_SIZE,
+                offset,
+            },
+            // The other errors are not returned by `decode_block`
+            _ => DecodeError::InvalidBlockSize { len },
+        }
     }
-    let mut res = ['1'; FULL_ENCODED_BLOCK_SIZE];
-    let mut num = u8be_to_u64(data);
-    let mut i = ENCODED_BLOCK_SIZES[data.len()];
-    while i > 0 {
-        let remainder: usize = (num % BASE58_CHARS.len() as u64) as usize;
-        num /= BASE58_CHARS.len() as u64;
-        i -= 1;
-        res[i] = BASE58_CHARS[remainder] as char;
+
+    /// Return the stable identifier of the error, same as [`Error::message_key`]
+    pub fn message_key(&self) -> &'static str {
+        Error::from(*self).message_key()
     }
-    Ok(res)
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct DecodedBlock {
-    data: [u8; FULL_BLOCK_SIZE],
-    size: usize,
+// First character of the bytes, the replacement character if they do not start with valid UTF-8
+#[cfg(feature = "alloc")]
+fn first_char(bytes: &[u8]) -> char {
+    (1..=bytes.len().min(4))
+        .find_map(|n| core::str::from_utf8(&bytes[..n]).ok())
+        .and_then(|s| s.chars().next())
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
 }
 
-fn decode_block(data: &[u8]) -> Result<DecodedBlock> {
-    if data.len() > FULL_ENCODED_BLOCK_SIZE {
-        return Err(Error::InvalidBlockSize);
+impl From<DecodeError> for Error {
+    fn from(v: DecodeError) -> Self {
+        match v {
+            DecodeError::InvalidBlockSize { .. } => Error::InvalidBlockSize,
+            DecodeError::InvalidSymbol { .. } => Error::InvalidSymbol,
+            DecodeError::Overflow { .. } => Error::Overflow,
+            #[cfg(feature = "check")]
+            DecodeError::InvalidChecksum => Error::InvalidChecksum,
+        }
     }
-    let res_size = match ENCODED_BLOCK_SIZES.iter().position(|&x| x == data.len()) {
-        Some(size) => size,
-        None => return Err(Error::InvalidBlockSize),
-    };
+}
 
-    let alpha: Vec<_> = Vec::from(BASE58_CHARS);
-    let mut res: u128 = 0;
-    let mut order = Wrapping(1);
-    data.iter()
-        .rev()
-        .try_for_each(|&c| match alpha.iter().position(|&x| x == c) {
-            Some(digit) => {
-                res += order.0 * digit as u128;
-                order *= Wrapping(58);
-                Ok(())
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidBlockSize { len } => write!(
+                f,
+                "Invalid block size error: input of {} characters, last block of {}",
+                len,
+                len % FULL_ENCODED_BLOCK_SIZE
+            ),
+            DecodeError::InvalidSymbol { offset, symbol } => {
+                write!(f, "Invalid symbol error: {:?} at offset {}", symbol, offset)
+            }
+            DecodeError::Overflow { block, offset } => {
+                write!(f, "Overflow error: block {} at offset {}", block, offset)
             }
-            None => Err(Error::InvalidSymbol),
-        })?;
+            #[cfg(feature = "check")]
+            DecodeError::InvalidChecksum => f.write_str("Invalid checksum error"),
+        }
+    }
+}
 
-    let max: u128 = match res_size {
-        8 => core::u64::MAX as u128 + 1,
-        0..=7 => 1 << (res_size * 8),
-        _ => unreachable!(),
-    };
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
 
-    let data = if (res) < max {
-        (res as u64).to_be_bytes()
-    } else {
-        return Err(Error::Overflow);
-    };
+/// Error of the verbose stream decoders, returned by [`decode_stream_verbose`]
+///
+/// Decoding failures are located in the input as with [`decode_verbose`], offsets are in bytes
+/// from the start of the stream.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StreamDecodeError {
+    /// Decoding error located in the input
+    Decode(DecodeError),
+    /// IO error on stream
+    ///
+    /// [PartialEq] implementation return true if the other error is also and IO error but do NOT
+    /// test the wrapped errors.
+    Io(io::Error),
+}
 
-    Ok(DecodedBlock {
-        data,
-        size: res_size,
-    })
+#[cfg(feature = "stream")]
+impl From<DecodeError> for StreamDecodeError {
+    fn from(v: DecodeError) -> Self {
+        Self::Decode(v)
+    }
 }
 
-/// Encode a byte vector into a base58-encoded string
-pub fn encode(data: &[u8]) -> Result<String> {
-    let last_block_size = ENCODED_BLOCK_SIZES[data.len() % FULL_BLOCK_SIZE];
-    let full_block_count = data.len() / FULL_BLOCK_SIZE;
-    let data: Result<Vec<[char; FULL_ENCODED_BLOCK_SIZE]>> =
-        data.chunks(FULL_BLOCK_SIZE).map(encode_block).collect();
+#[cfg(feature = "stream")]
+impl From<io::Error> for StreamDecodeError {
+    fn from(v: io::Error) -> Self {
+        Self::Io(v)
+    }
+}
 
-    let mut i = 0;
-    let mut res: Vec<char> = Vec::new();
-    data?.into_iter().for_each(|v| {
-        if i == full_block_count {
-            res.extend_from_slice(&v[..last_block_size]);
-        } else {
-            res.extend_from_slice(&v);
+#[cfg(feature = "stream")]
+impl From<StreamDecodeError> for Error {
+    fn from(v: StreamDecodeError) -> Self {
+        match v {
+            StreamDecodeError::Decode(e) => e.into(),
+            StreamDecodeError::Io(e) => Error::Io(e),
         }
-        i += 1;
-    });
-
-    let s: String = res.into_iter().collect();
-    Ok(s)
+    }
 }
 
-/// Encdoe a byte stream in a base58 stream of characters
 #[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-pub fn encode_stream<T>(mut data: T) -> impl Stream<Item = Result<char>>
-where
-    T: AsyncReadExt + Unpin,
-{
-    try_stream! {
-        let mut clen = 0;
-        let mut buf = [0; FULL_BLOCK_SIZE];
-
-        loop {
-            let len = data.read(&mut buf[clen..]).await?;
-            clen += len;
-
-            if len == 0 {
-                // EOF reached, final block is created
-                if clen > 0 {
-                    let block_size = ENCODED_BLOCK_SIZES[clen];
-                    for c in &encode_block(&buf[..clen])?[..block_size] {
-                        yield *c;
-                    }
-                }
-
-                break;
-            }
+impl PartialEq for StreamDecodeError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (StreamDecodeError::Decode(a), StreamDecodeError::Decode(b)) => a == b,
+            // Ignore what Io error is wrapped
+            (StreamDecodeError::Io(_), StreamDecodeError::Io(_)) => true,
+            _ => false,
+        }
+    }
+}
 
-            if clen == FULL_BLOCK_SIZE {
-                // Buffer is full, yield a full block
-                for c in &encode_block(&buf)?[..] {
-                    yield *c;
-                }
+#[cfg(feature = "stream")]
+impl core::fmt::Display for StreamDecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            StreamDecodeError::Decode(e) => core::fmt::Display::fmt(e, f),
+            StreamDecodeError::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
 
-                clen = 0;
-            }
+#[cfg(feature = "stream")]
+impl std::error::Error for StreamDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamDecodeError::Decode(e) => Some(e),
+            StreamDecodeError::Io(e) => Some(e),
         }
     }
 }
 
-/// Encode a byte vector into a base58-check string, adds 4 bytes checksum
-#[cfg(feature = "check")]
-#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
-pub fn encode_check(data: &[u8]) -> Result<String> {
-    let mut bytes = Vec::from(data);
-    let mut checksum = [0u8; 32];
-    let mut hasher = Keccak::v256();
-    hasher.update(&bytes[..]);
-    hasher.finalize(&mut checksum);
-    bytes.extend_from_slice(&checksum[..CHECKSUM_SIZE]);
-    encode(&bytes[..])
+// Spans and events emitted by the stream functions, compiled to no-ops without the `tracing`
+// feature
+#[cfg(feature = "stream")]
+struct StreamTrace {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    bytes: u64,
+    #[cfg(feature = "tracing")]
+    blocks: u64,
 }
 
-/// Encode a byte stream in a base58 stream of characters with a 4 bytes checksum
-#[cfg(all(feature = "check", feature = "stream"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
-pub fn encode_stream_check<T>(mut data: T) -> impl Stream<Item = Result<char>>
-where
-    T: AsyncReadExt + Unpin,
-{
-    try_stream! {
-        let mut clen = 0;
-        let mut buf = [0; FULL_BLOCK_SIZE];
-        let mut checksum = [0u8; 32];
-        let mut hasher = Keccak::v256();
+#[cfg(feature = "stream")]
+impl StreamTrace {
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn new(operation: &'static str) -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::debug_span!("base58_stream", operation),
+            #[cfg(feature = "tracing")]
+            bytes: 0,
+            #[cfg(feature = "tracing")]
+            blocks: 0,
+        }
+    }
 
-        loop {
-            let len = data.read(&mut buf[clen..]).await?;
-            clen += len;
+    // Record a block of `len` bytes or characters read from the input
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn block(&mut self, len: usize) {
+        #[cfg(feature = "tracing")]
+        {
+            self.bytes += len as u64;
+            self.blocks += 1;
+            tracing::trace!(parent: &self.span, len, "block processed");
+        }
+    }
 
-            if len == 0 {
-                // EOF reached, final block is created
-                hasher.update(&buf[..clen]);
-                hasher.finalize(&mut checksum);
+    #[cfg(feature = "check")]
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn checksum(&self, valid: bool) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(parent: &self.span, valid, "checksum verified");
+    }
 
-                if clen + CHECKSUM_SIZE > FULL_BLOCK_SIZE {
-                    // Extend and encode the first bytes of checksum with the last block
-                    let sum_size = FULL_BLOCK_SIZE - clen;
-                    buf[clen..].copy_from_slice(&checksum[..sum_size]);
+    // Record the position of a decoding error, `rest` is the input read from the start of the
+    // failed block at `offset` and `len` the length of the input read so far
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn decode_error(&self, error: Error, rest: &[u8], offset: usize, len: usize) -> Error {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            parent: &self.span,
+            error = %DecodeError::locate(&error, rest, offset, len),
+            "decoding failed"
+        );
+        error
+    }
 
-                    for c in &encode_block(&buf)?[..] {
-                        yield *c;
-                    }
+    fn finish(&self) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            parent: &self.span,
+            bytes = self.bytes,
+            blocks = self.blocks,
+            "stream completed"
+        );
+    }
+}
 
-                    // Return last encoded checksum bytes
-                    let block_size = ENCODED_BLOCK_SIZES[CHECKSUM_SIZE - sum_size];
-                    for c in &encode_block(&checksum[sum_size..CHECKSUM_SIZE])?[..block_size] {
-                        yield *c;
-                    }
-                } else {
-                    let start = clen;
-                    clen += CHECKSUM_SIZE;
-                    buf[start..clen].copy_from_slice(&checksum[..CHECKSUM_SIZE]);
-
-                    let block_size = ENCODED_BLOCK_SIZES[clen];
-                    for c in &encode_block(&buf[..clen])?[..block_size] {
-                        yield *c;
-                    }
-                }
+/// Utility type for handling results with base58 error type
+pub type Result<T> = core::result::Result<T, Error>;
 
-                break;
-            }
+#[cfg(feature = "scratch")]
+std::thread_local! {
+    // Scratch buffer reused by the one-shot functions of the thread
+    static SCRATCH: core::cell::RefCell<Vec<u8>> = const { core::cell::RefCell::new(Vec::new()) };
+}
 
-            if clen == FULL_BLOCK_SIZE {
-                // Buffer is full, yield a full encoded block
-                hasher.update(&buf);
+#[cfg(all(feature = "scratch", feature = "check"))]
+std::thread_local! {
+    // Initialized hasher state cloned for each checksum
+    static HASHER: Keccak = Keccak::v256();
+}
 
-                for c in &encode_block(&buf)?[..] {
-                    yield *c;
-                }
+// Capacity above which the thread scratch buffer is released after use
+#[cfg(feature = "scratch")]
+const MAX_SCRATCH_CAPACITY: usize = 64 * 1024;
 
-                clen = 0;
-            }
-        }
+// Assert that `encoded` is the encoding of `bytes` with both the encoder and the decoder, in debug
+// builds with the `self-check` feature. The bytes are not printed as they may be secret.
+#[inline]
+#[cfg(feature = "alloc")]
+fn self_check(bytes: &[u8], encoded: &[u8]) {
+    #[cfg(all(feature = "self-check", debug_assertions))]
+    {
+        let mut decoded = Vec::new();
+        assert!(
+            decode_append(encoded, &mut decoded).is_ok() && decoded == bytes,
+            "self-check: base58 string does not decode to its bytes"
+        );
+        assert!(
+            encode_raw(bytes).is_ok_and(|s| s.as_bytes() == encoded),
+            "self-check: bytes do not encode to their base58 string"
+        );
     }
+    #[cfg(not(all(feature = "self-check", debug_assertions)))]
+    let _ = (bytes, encoded);
 }
 
-/// Decode base58-encoded string into a byte vector
-pub fn decode(data: &str) -> Result<Vec<u8>> {
-    let data: Result<Vec<DecodedBlock>> = data
-        .as_bytes()
-        .chunks(FULL_ENCODED_BLOCK_SIZE)
-        .map(decode_block)
-        .collect();
-    let mut res = Vec::new();
-    data?.into_iter().for_each(|c| {
-        let bytes = &c.data[FULL_BLOCK_SIZE - c.size..];
-        res.extend_from_slice(bytes);
-    });
+// Report the failed validations of a one-shot decoding function to the `telemetry` module
+#[inline]
+fn observe<T>(res: Result<T>, check: bool, input_len: usize) -> Result<T> {
+    #[cfg(feature = "telemetry")]
+    if let Err(e) = &res {
+        crate::telemetry::record(e, check, input_len);
+    }
+    #[cfg(not(feature = "telemetry"))]
+    let _ = (check, input_len);
+    res
+}
+
+// Call `f` with an empty scratch buffer, the buffer of the thread is reused with the `scratch`
+// feature and a new buffer is used when it is already borrowed, e.g. by a nested call
+#[cfg_attr(not(feature = "check"), allow(dead_code))]
+#[cfg(feature = "alloc")]
+fn with_scratch<T>(f: impl FnOnce(&mut Vec<u8>) -> T) -> T {
+    #[cfg(feature = "scratch")]
+    {
+        SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+            Ok(mut buf) => {
+                buf.clear();
+                let res = f(&mut buf);
+                if buf.capacity() > MAX_SCRATCH_CAPACITY {
+                    *buf = Vec::new();
+                }
+                res
+            }
+            Err(_) => f(&mut Vec::new()),
+        })
+    }
+    #[cfg(not(feature = "scratch"))]
+    f(&mut Vec::new())
+}
+
+/// 4 bytes checksum appended to the payload in check mode
+///
+/// Comparisons run in constant time with `subtle`, whether against another checksum or a byte
+/// slice, so verifying an integrity tag does not leak the position of the first differing byte.
+/// Displayed as lowercase hex.
+///
+/// ```rust
+/// use base58_monero::{encode_check_full, Checksum};
+///
+/// let (_, checksum) = encode_check_full(b"Hello World")?;
+/// assert_eq!(8, checksum.to_string().len());
+/// assert_eq!(checksum, Checksum::from(*checksum.as_bytes()));
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+#[derive(Debug, Clone, Copy)]
+pub struct Checksum([u8; CHECKSUM_SIZE]);
+
+#[cfg(feature = "check")]
+impl Checksum {
+    /// Return the bytes of the checksum
+    pub const fn as_bytes(&self) -> &[u8; CHECKSUM_SIZE] {
+        &self.0
+    }
+
+    // Truncate a Keccak256 hash to the checksum size
+    pub(crate) fn from_hash(hash: &[u8; 32]) -> Self {
+        let mut res = [0u8; CHECKSUM_SIZE];
+        res.copy_from_slice(&hash[..CHECKSUM_SIZE]);
+        Self(res)
+    }
+}
+
+#[cfg(feature = "check")]
+impl From<[u8; CHECKSUM_SIZE]> for Checksum {
+    fn from(bytes: [u8; CHECKSUM_SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "check")]
+impl From<Checksum> for [u8; CHECKSUM_SIZE] {
+    fn from(checksum: Checksum) -> Self {
+        checksum.0
+    }
+}
+
+#[cfg(feature = "check")]
+impl AsRef<[u8]> for Checksum {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "check")]
+impl ConstantTimeEq for Checksum {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "check")]
+impl PartialEq for Checksum {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+#[cfg(feature = "check")]
+impl Eq for Checksum {}
+
+#[cfg(feature = "check")]
+impl core::hash::Hash for Checksum {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+// Slices of another length are never equal
+#[cfg(feature = "check")]
+impl PartialEq<[u8]> for Checksum {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0[..].ct_eq(other).into()
+    }
+}
+
+#[cfg(feature = "check")]
+impl core::fmt::Display for Checksum {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+/// Incremental checksum appended to the payload in check mode
+///
+/// Implemented by [`MoneroChecksum`], the checksum of [`encode_check`] and [`decode_check`], and
+/// [`KeyedChecksum`]. Other checksum schemes, e.g. longer tags for non-Monero payloads, implement
+/// this trait to be used with [`encode_check_with`], [`decode_check_with`] and
+/// [`transcode_check`].
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub trait ChecksumAlgorithm {
+    /// Return the size of the checksum in bytes
+    fn size(&self) -> usize;
+
+    /// Feed the next bytes of the payload
+    fn update(&mut self, data: &[u8]);
+
+    /// Return the checksum of the bytes fed, `size()` bytes long
+    fn finalize(self) -> Vec<u8>;
+
+    /// Return true if `checksum` is the checksum of the bytes fed
+    ///
+    /// The default implementation compares the result of [`finalize`](Self::finalize) in
+    /// constant time.
+    fn verify(self, checksum: &[u8]) -> bool
+    where
+        Self: Sized,
+    {
+        self.finalize()[..].ct_eq(checksum).into()
+    }
+}
+
+/// Monero checksum, the first 4 bytes of the `Keccak256` hash of the payload
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+#[derive(Clone)]
+pub struct MoneroChecksum(Keccak);
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl MoneroChecksum {
+    /// Create the checksum state of an empty payload
+    pub fn new() -> Self {
+        Self(Keccak::v256())
+    }
+}
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl Default for MoneroChecksum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl core::fmt::Debug for MoneroChecksum {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("MoneroChecksum")
+    }
+}
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl ChecksumAlgorithm for MoneroChecksum {
+    fn size(&self) -> usize {
+        CHECKSUM_SIZE
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        let mut hash = [0u8; 32];
+        self.0.finalize(&mut hash);
+        Vec::from(&hash[..CHECKSUM_SIZE])
+    }
+}
+
+/// Keyed checksum, the 4 bytes `KMAC256` tag of the payload under a secret key
+///
+/// Only holders of the key can produce a string passing [`decode_check_keyed`], e.g. for internal
+/// tokens that third parties must not forge. The tag is only 32 bits long: a forger succeeds with
+/// probability `2^-32` per attempt, rate-limit verification where that matters.
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+#[derive(Clone)]
+pub struct KeyedChecksum(Kmac);
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl KeyedChecksum {
+    /// Create the checksum state of an empty payload under `key`
+    pub fn new(key: &[u8]) -> Self {
+        Self(Kmac::v256(key, b"base58-monero"))
+    }
+
+    // Return the tag of the bytes fed
+    fn tag(self) -> Checksum {
+        let mut res = [0u8; CHECKSUM_SIZE];
+        self.0.finalize(&mut res);
+        Checksum::from(res)
+    }
+}
+
+// The key is not printed
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl core::fmt::Debug for KeyedChecksum {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("KeyedChecksum")
+    }
+}
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl ChecksumAlgorithm for KeyedChecksum {
+    fn size(&self) -> usize {
+        CHECKSUM_SIZE
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        Vec::from(&self.tag().as_bytes()[..])
+    }
+}
+
+// Compute the 4 bytes checksum of `data`
+#[cfg(feature = "check")]
+fn checksum(data: &[u8]) -> Checksum {
+    #[cfg(feature = "scratch")]
+    let mut hasher = HASHER.with(Keccak::clone);
+    #[cfg(not(feature = "scratch"))]
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    Checksum::from_hash(&hash)
+}
+
+fn u8be_to_u64(data: &[u8]) -> u64 {
+    let mut res = 0u64;
+    for b in data {
+        res = res << 8 | *b as u64;
+    }
+    res
+}
+
+// Encode a `1..=8` bytes block into its base58 digits, i.e. the indexes of its symbols in the
+// alphabet
+fn encode_block_digits(data: &[u8]) -> Result<[u8; FULL_ENCODED_BLOCK_SIZE]> {
+    if data.is_empty() || data.len() > FULL_BLOCK_SIZE {
+        return Err(Error::InvalidBlockSize);
+    }
+    let mut res = [0; FULL_ENCODED_BLOCK_SIZE];
+    let mut num = u8be_to_u64(data);
+    let mut i = ENCODED_BLOCK_SIZES[data.len()];
+    while i > 0 {
+        let remainder = (num % BASE58_CHARS.len() as u64) as u8;
+        num /= BASE58_CHARS.len() as u64;
+        i -= 1;
+        res[i] = remainder;
+    }
     Ok(res)
 }
 
-/// Decode base58-encoded stream in a byte stream
-#[cfg(feature = "stream")]
-#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
-pub fn decode_stream<T>(mut data: T) -> impl Stream<Item = Result<u8>>
+fn encode_block(data: &[u8]) -> Result<[u8; FULL_ENCODED_BLOCK_SIZE]> {
+    Ok(encode_block_digits(data)?.map(|digit| BASE58_CHARS[digit as usize]))
+}
+
+/// Encoded block of `0..=11` base58 characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncodedBlock {
+    data: [u8; FULL_ENCODED_BLOCK_SIZE],
+    size: usize,
+}
+
+impl EncodedBlock {
+    /// Return the base58 characters of the block
+    pub fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.data[..self.size]) {
+            Ok(s) => s,
+            // Base58 alphabet is ASCII only
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Return the number of characters, `11` for all blocks but the last one
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+// Encode a `1..=8` bytes block into an `EncodedBlock`
+pub(crate) fn encode_block_chars(data: &[u8]) -> Result<EncodedBlock> {
+    Ok(EncodedBlock {
+        data: encode_block(data)?,
+        size: ENCODED_BLOCK_SIZES[data.len()],
+    })
+}
+
+/// Decoded block of `0..=8` bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodedBlock {
+    // Decoded bytes are right-aligned
+    data: [u8; FULL_BLOCK_SIZE],
+    size: usize,
+}
+
+impl DecodedBlock {
+    /// Return the decoded bytes of the block
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[FULL_BLOCK_SIZE - self.size..]
+    }
+
+    /// Return the number of decoded bytes, `8` for all blocks but the last one
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+pub(crate) fn decode_block(data: &[u8]) -> Result<DecodedBlock> {
+    decode_block_with(data, |c| match decode_digit(c) {
+        INVALID_DIGIT => None,
+        digit => Some(digit as usize),
+    })
+}
+
+// Decode a block given the function converting its symbols into digits
+fn decode_block_with<F>(data: &[u8], digit: F) -> Result<DecodedBlock>
 where
-    T: AsyncReadExt + Unpin,
+    F: Fn(u8) -> Option<usize>,
 {
-    try_stream! {
-        let mut clen = 0;
-        let mut buf = [0; FULL_ENCODED_BLOCK_SIZE];
+    let res_size = match decoded_block_size(data.len()) {
+        Some(size) => size,
+        None => return Err(Error::InvalidBlockSize),
+    };
 
-        loop {
-            let len = data.read(&mut buf[clen..]).await?;
-            clen += len;
+    // Horner's method, 11 digits are below 2^65 and never overflow
+    let mut res: u128 = 0;
+    for &c in data {
+        match digit(c) {
+            Some(digit) => res = res * 58 + digit as u128,
+            None => return Err(Error::InvalidSymbol),
+        }
+    }
 
-            if len == 0 {
-                // EOF reached
-                let block = decode_block(&buf[..clen])?;
-                for c in &block.data[FULL_BLOCK_SIZE - block.size..] {
-                    yield *c;
-                }
-                break;
-            }
+    let max: u128 = match res_size {
+        8 => u64::MAX as u128 + 1,
+        0..=7 => 1 << (res_size * 8),
+        _ => unreachable!(),
+    };
 
-            if clen == FULL_ENCODED_BLOCK_SIZE {
-                let block = decode_block(&buf)?;
-                for c in &block.data[FULL_BLOCK_SIZE - block.size..] {
-                    yield *c;
-                }
-                clen = 0;
+    let data = if (res) < max {
+        (res as u64).to_be_bytes()
+    } else {
+        return Err(Error::Overflow);
+    };
+
+    Ok(DecodedBlock {
+        data,
+        size: res_size,
+    })
+}
+
+/// Encode a byte vector into a base58-encoded string
+///
+/// Any byte container can be encoded, e.g. `&[u8]`, `Vec<u8>` or `[u8; N]`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode(data: impl AsRef<[u8]>) -> Result<String> {
+    let data = data.as_ref();
+    let encoded = encode_raw(data)?;
+    self_check(data, encoded.as_bytes());
+    Ok(encoded)
+}
+
+// Encode bytes into a base58 string, without the verification of the `self-check` feature
+#[cfg(feature = "alloc")]
+fn encode_raw(data: &[u8]) -> Result<String> {
+    let mut res = String::with_capacity(encoded_len(data.len()));
+    push_encoded_blocks(data, &mut res)?;
+    Ok(res)
+}
+
+// Encode the blocks of `data` and push the symbols to `res`
+#[cfg(feature = "alloc")]
+fn push_encoded_blocks(data: &[u8], res: &mut String) -> Result<()> {
+    for block in data.chunks(FULL_BLOCK_SIZE) {
+        let block = encode_block_chars(block)?;
+        res.push_str(block.as_str());
+    }
+    Ok(())
+}
+
+// Encode the blocks of `data` into `out` from `pos`, return the position after the last symbol
+fn encode_blocks_into(data: &[u8], out: &mut [u8], mut pos: usize) -> Result<usize> {
+    for block in data.chunks(FULL_BLOCK_SIZE) {
+        let block = encode_block_chars(block)?;
+        out[pos..pos + block.size].copy_from_slice(&block.data[..block.size]);
+        pos += block.size;
+    }
+    Ok(pos)
+}
+
+/// Encode a byte vector into a base58 string written to `out`, return the number of bytes written
+///
+/// Nothing is allocated. Buffers shorter than the [`encoded_len`] of the data return
+/// [`Error::BufferTooSmall`] before anything is written.
+///
+/// ```rust
+/// use base58_monero::encode_into;
+///
+/// let mut buf = [0u8; 32];
+/// let len = encode_into(b"Hello World", &mut buf)?;
+/// assert_eq!(b"D7LMXYjUbXc1fS9Z", &buf[..len]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn encode_into(data: impl AsRef<[u8]>, out: &mut [u8]) -> Result<usize> {
+    let data = data.as_ref();
+    if out.len() < encoded_len(data.len()) {
+        return Err(Error::BufferTooSmall);
+    }
+    encode_blocks_into(data, out, 0)
+}
+
+/// Encode a byte vector into a base58 string held in an array of `N` bytes, return the array and
+/// the length of the string
+///
+/// Nothing is allocated, `N` bounds the length of the string at compile time. Data whose
+/// [`encoded_len`] exceeds `N` returns [`Error::BufferTooSmall`].
+///
+/// ```rust
+/// use base58_monero::encode_to_array;
+///
+/// let (buf, len) = encode_to_array::<16>(b"Hello World")?;
+/// assert_eq!(b"D7LMXYjUbXc1fS9Z", &buf[..len]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn encode_to_array<const N: usize>(data: impl AsRef<[u8]>) -> Result<([u8; N], usize)> {
+    let mut res = [0u8; N];
+    let len = encode_into(data, &mut res)?;
+    Ok((res, len))
+}
+
+/// Encode a byte vector into a base58 `heapless::String` of at most `N` bytes
+///
+/// Data whose [`encoded_len`] exceeds `N` returns [`Error::BufferTooSmall`].
+///
+/// ```rust
+/// use base58_monero::encode_heapless;
+///
+/// let encoded = encode_heapless::<16>(b"Hello World")?;
+/// assert_eq!("D7LMXYjUbXc1fS9Z", encoded.as_str());
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub fn encode_heapless<const N: usize>(data: impl AsRef<[u8]>) -> Result<heapless::String<N>> {
+    let mut res = heapless::Vec::new();
+    // Resizing up to the capacity never fails
+    let _ = res.resize_default(N);
+    let len = encode_into(data, &mut res)?;
+    res.truncate(len);
+    match heapless::String::from_utf8(res) {
+        Ok(encoded) => Ok(encoded),
+        // Base58 symbols are ASCII
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Lazily display a byte slice as a base58-encoded string
+///
+/// The data is encoded block by block while formatted, without allocating the encoded string.
+///
+/// ```rust
+/// use base58_monero::base58::display;
+///
+/// assert_eq!("D7LMXYjUbXc1fS9Z", format!("{}", display(b"Hello World")));
+/// ```
+pub fn display(data: &[u8]) -> Base58Display<'_> {
+    Base58Display(data)
+}
+
+/// Lazy base58 display adapter returned by [`display`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base58Display<'a>(&'a [u8]);
+
+impl Base58Display<'_> {
+    // Call `f` with the encoded string of each block
+    fn try_for_each_block<E>(
+        &self,
+        mut f: impl FnMut(&str) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        for block in self.0.chunks(FULL_BLOCK_SIZE) {
+            match encode_block_chars(block) {
+                Ok(block) => f(block.as_str())?,
+                // Chunks are always valid block sizes
+                Err(_) => unreachable!(),
             }
         }
+        Ok(())
     }
 }
 
-/// Decode base58-encoded with 4 bytes checksum string into a byte vector
-#[cfg(feature = "check")]
-#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
-pub fn decode_check(data: &str) -> Result<Vec<u8>> {
-    let bytes = decode(data)?;
-    let (bytes, checksum) = {
-        let len = bytes.len();
-        (
-            &bytes[..len - CHECKSUM_SIZE],
-            &bytes[len - CHECKSUM_SIZE..len],
-        )
-    };
-    let mut check = [0u8; 32];
-    let mut hasher = Keccak::v256();
-    hasher.update(bytes);
-    hasher.finalize(&mut check);
+impl core::fmt::Display for Base58Display<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.try_for_each_block(|s| f.write_str(s))
+    }
+}
 
-    if &check[..CHECKSUM_SIZE] == checksum {
-        Ok(Vec::from(bytes))
-    } else {
-        Err(Error::InvalidChecksum)
+// Write the base58 string to a `ufmt` writer, without the `core::fmt` machinery
+#[cfg(feature = "ufmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+impl ufmt::uDisplay for Base58Display<'_> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        self.try_for_each_block(|s| f.write_str(s))
     }
 }
 
-/// Decode base58-encoded stream with a 4 bytes checksum in a decoded byte stream
-#[cfg(all(feature = "check", feature = "stream"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
-pub fn decode_stream_check<T>(data: T) -> impl Stream<Item = Result<u8>>
-where
-    T: AsyncReadExt + Unpin,
-{
-    try_stream! {
-        let len = CHECKSUM_SIZE + 1;
-        let mut clen = 0;
-        let mut check = [0; CHECKSUM_SIZE];
-        let mut buf = [0; CHECKSUM_SIZE + 1];
+/// Encode a byte vector into a base58-encoded string using a custom alphabet
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_with_alphabet(data: impl AsRef<[u8]>, alphabet: &Alphabet) -> Result<String> {
+    let mut res = String::new();
+    for block in data.as_ref().chunks(FULL_BLOCK_SIZE) {
+        let digits = encode_block_digits(block)?;
+        for &digit in &digits[..ENCODED_BLOCK_SIZES[block.len()]] {
+            res.push(alphabet.chars[digit as usize] as char);
+        }
+    }
+    Ok(res)
+}
 
-        let mut checksum = [0u8; 32];
-        let mut hasher = Keccak::v256();
+/// Encode the bytes of an iterator into a base58-encoded string
+///
+/// Bytes are buffered into blocks internally, so non-contiguous data, e.g. chained slices, can be
+/// encoded without collecting it first.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_iter(data: impl IntoIterator<Item = u8>) -> Result<String> {
+    let mut encoder = Encoder::new();
+    encoder.extend(data);
+    Ok(encoder.finish())
+}
 
-        let data = decode_stream(data);
-        pin_mut!(data);
+/// Push-based base58 encoder
+///
+/// Bytes are pushed in any number of calls and buffered into blocks, the base58-encoded string is
+/// returned by [`Encoder::finish`]. The encoder can be fed with [`Extend`], [`core::fmt::Write`]
+/// and, with the `std` feature, [`std::io::Write`].
+///
+/// ```rust
+/// use base58_monero::base58::Encoder;
+/// use core::fmt::Write;
+///
+/// let mut encoder = Encoder::new();
+/// write!(encoder, "Hello {}", "World").unwrap();
+/// assert_eq!("D7LMXYjUbXc1fS9Z", encoder.finish());
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Encoder {
+    buf: [u8; FULL_BLOCK_SIZE],
+    len: usize,
+    res: String,
+}
 
-        while let Some(value) = data.next().await {
-            buf[clen % len] = value?;
-            if clen >= CHECKSUM_SIZE {
-                check[0] = buf[(clen - CHECKSUM_SIZE) % len];
-                hasher.update(&check[0..1]);
-                yield check[0];
+#[cfg(feature = "alloc")]
+impl Encoder {
+    /// Create an empty encoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a single byte into the encoder
+    pub fn push(&mut self, byte: u8) {
+        self.buf[self.len] = byte;
+        self.len += 1;
+        if self.len == FULL_BLOCK_SIZE {
+            match encode_block_chars(&self.buf) {
+                Ok(block) => self.res.push_str(block.as_str()),
+                // A full block is always a valid block size
+                Err(_) => unreachable!(),
             }
-            clen += 1;
+            self.len = 0;
         }
+    }
 
-        hasher.finalize(&mut checksum);
-        for i in 0..CHECKSUM_SIZE {
-            check[i] = buf[(clen - CHECKSUM_SIZE + i) % len];
+    /// Push a slice of bytes into the encoder
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        data.as_ref().iter().for_each(|&b| self.push(b));
+    }
+
+    /// Encode the remaining buffered bytes and return the base58-encoded string
+    pub fn finish(mut self) -> String {
+        if self.len > 0 {
+            match encode_block_chars(&self.buf[..self.len]) {
+                Ok(block) => self.res.push_str(block.as_str()),
+                // A partial block of 1 to 7 bytes is always a valid block size
+                Err(_) => unreachable!(),
+            }
+        }
+        self.res
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Extend<u8> for Encoder {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|b| self.push(b));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Extend<&'a u8> for Encoder {
+    fn extend<T: IntoIterator<Item = &'a u8>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|&b| self.push(b));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Write for Encoder {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.update(s);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::io::Write for Encoder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Poll-based block encoding state
+///
+/// The state does not own any IO, input bytes are fed to [`EncodeState::poll_encode`] as they
+/// become available and encoded blocks are returned as soon as they are complete. It only depends
+/// on `core`, so `no_std` async runtimes and hand-written futures can drive the encoding without
+/// `async-stream` or `futures-util`.
+///
+/// ```rust
+/// use base58_monero::base58::EncodeState;
+/// use core::task::Poll;
+///
+/// let mut state = EncodeState::new();
+/// let mut res = String::new();
+/// for mut chunk in [&b"Hello"[..], &b" World"[..]] {
+///     while let Poll::Ready(block) = state.poll_encode(&mut chunk) {
+///         res.push_str(block.as_str());
+///     }
+/// }
+/// if let Some(block) = state.finish() {
+///     res.push_str(block.as_str());
+/// }
+/// assert_eq!("D7LMXYjUbXc1fS9Z", res);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct EncodeState {
+    buf: [u8; FULL_BLOCK_SIZE],
+    len: usize,
+}
+
+impl EncodeState {
+    /// Create an empty encoding state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume bytes from `input` until a block is complete
+    ///
+    /// Returns `Poll::Ready` with the encoded block when 8 bytes are buffered, the remaining input
+    /// is left in `input`. Returns `Poll::Pending` when `input` is exhausted before a block is
+    /// complete, more input is needed.
+    pub fn poll_encode(&mut self, input: &mut &[u8]) -> Poll<EncodedBlock> {
+        let len = input.len().min(FULL_BLOCK_SIZE - self.len);
+        self.buf[self.len..self.len + len].copy_from_slice(&input[..len]);
+        self.len += len;
+        *input = &input[len..];
+        if self.len < FULL_BLOCK_SIZE {
+            return Poll::Pending;
+        }
+        self.len = 0;
+        match encode_block_chars(&self.buf) {
+            Ok(block) => Poll::Ready(block),
+            // A full block is always a valid block size
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Encode the remaining buffered bytes at the end of the input, if any
+    pub fn finish(self) -> Option<EncodedBlock> {
+        if self.len == 0 {
+            return None;
+        }
+        match encode_block_chars(&self.buf[..self.len]) {
+            Ok(block) => Some(block),
+            // A partial block of 1 to 7 bytes is always a valid block size
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// Poll-based block decoding state
+///
+/// Counterpart of [`EncodeState`], base58 symbols are fed to [`DecodeState::poll_decode`] and
+/// decoded blocks are returned as soon as 11 symbols are buffered.
+///
+/// ```rust
+/// use base58_monero::base58::DecodeState;
+/// use core::task::Poll;
+///
+/// let mut state = DecodeState::new();
+/// let mut res = Vec::new();
+/// for mut chunk in [&b"D7LMXYj"[..], &b"UbXc1fS9Z"[..]] {
+///     while let Poll::Ready(block) = state.poll_decode(&mut chunk) {
+///         res.extend_from_slice(block?.as_bytes());
+///     }
+/// }
+/// if let Some(block) = state.finish()? {
+///     res.extend_from_slice(block.as_bytes());
+/// }
+/// assert_eq!(b"Hello World", &res[..]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DecodeState {
+    buf: [u8; FULL_ENCODED_BLOCK_SIZE],
+    len: usize,
+}
+
+impl DecodeState {
+    /// Create an empty decoding state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume symbols from `input` until a block is complete
+    ///
+    /// Returns `Poll::Ready` with the decoded block, or the decoding error, when 11 symbols are
+    /// buffered, the remaining input is left in `input`. Returns `Poll::Pending` when `input` is
+    /// exhausted before a block is complete, more input is needed.
+    pub fn poll_decode(&mut self, input: &mut &[u8]) -> Poll<Result<DecodedBlock>> {
+        let len = input.len().min(FULL_ENCODED_BLOCK_SIZE - self.len);
+        self.buf[self.len..self.len + len].copy_from_slice(&input[..len]);
+        self.len += len;
+        *input = &input[len..];
+        if self.len < FULL_ENCODED_BLOCK_SIZE {
+            return Poll::Pending;
+        }
+        self.len = 0;
+        Poll::Ready(decode_block(&self.buf))
+    }
+
+    /// Decode the remaining buffered symbols at the end of the input, if any
+    pub fn finish(self) -> Result<Option<DecodedBlock>> {
+        if self.len == 0 {
+            return Ok(None);
+        }
+        decode_block(&self.buf[..self.len]).map(Some)
+    }
+}
+
+/// Encode a byte vector into a base58-encoded string, reporting progress to a callback
+///
+/// The callback receives the number of blocks processed and the total number of blocks, it is
+/// called after every `every_n_blocks` blocks and once the last block is encoded. An
+/// `every_n_blocks` of `0` is treated as `1`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_with_progress<F>(
+    data: impl AsRef<[u8]>,
+    every_n_blocks: usize,
+    mut progress: F,
+) -> Result<String>
+where
+    F: FnMut(usize, usize),
+{
+    let data = data.as_ref();
+    let every_n_blocks = every_n_blocks.max(1);
+    let total = data.len().div_ceil(FULL_BLOCK_SIZE);
+    let mut res = String::new();
+    for (i, block) in data.chunks(FULL_BLOCK_SIZE).enumerate() {
+        res.push_str(encode_block_chars(block)?.as_str());
+        let done = i + 1;
+        if done % every_n_blocks == 0 || done == total {
+            progress(done, total);
+        }
+    }
+    Ok(res)
+}
+
+/// Encode a list of byte payloads into base58-encoded strings joined with a delimiter
+///
+/// Each payload is encoded independently, the result can be split back with [`decode_split`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn encode_join<I>(payloads: I, delimiter: &str) -> Result<String>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let mut res = String::new();
+    for (i, payload) in payloads.into_iter().enumerate() {
+        if i > 0 {
+            res.push_str(delimiter);
+        }
+        res.push_str(&encode(payload)?);
+    }
+    Ok(res)
+}
+
+/// Encode a byte vector into a base58-encoded string without aborting on allocation failure
+///
+/// The output is allocated with [`String::try_reserve_exact`], an allocation failure returns
+/// [`Error::OutOfMemory`] instead of aborting the process.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn try_encode(data: impl AsRef<[u8]>) -> Result<String> {
+    let data = data.as_ref();
+    let len = encoded_len(data.len());
+    let mut res = String::new();
+    res.try_reserve_exact(len).map_err(|_| Error::OutOfMemory)?;
+    for chunk in data.chunks(FULL_BLOCK_SIZE) {
+        res.push_str(encode_block_chars(chunk)?.as_str());
+    }
+    Ok(res)
+}
+
+// Encode a `1..=8` bytes block and write its ASCII symbols
+#[cfg(feature = "std")]
+pub(crate) fn write_encoded_block(
+    block: &[u8],
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let res =
+        encode_block(block).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    writer.write_all(&res[..ENCODED_BLOCK_SIZES[block.len()]])
+}
+
+/// Encode a byte vector and write the base58 symbols to a writer
+///
+/// The output is written block by block without building an intermediate string.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer(
+    data: impl AsRef<[u8]>,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    data.as_ref()
+        .chunks(FULL_BLOCK_SIZE)
+        .try_for_each(|block| write_encoded_block(block, &mut writer))
+}
+
+// Fill `buf` with the `read` function, returns less than `buf.len()` bytes only when `read`
+// returns `0`, shared by the `std::io` and `embedded-io` readers
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub(crate) fn read_block_with<E, R>(buf: &mut [u8], mut read: R) -> core::result::Result<usize, E>
+where
+    R: FnMut(&mut [u8]) -> core::result::Result<usize, E>,
+{
+    let mut len = 0;
+    while len < buf.len() {
+        match read(&mut buf[len..])? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    Ok(len)
+}
+
+// Fill `buf` from `reader`, returns less than `buf.len()` bytes only at EOF
+#[cfg(feature = "std")]
+pub(crate) fn read_block(
+    reader: &mut impl std::io::Read,
+    buf: &mut [u8],
+) -> std::io::Result<usize> {
+    read_block_with(buf, |buf| read_uninterrupted(reader, buf))
+}
+
+// Read from `reader` into `buf`, retrying reads interrupted by a signal
+#[cfg(feature = "std")]
+fn read_uninterrupted(reader: &mut impl std::io::Read, buf: &mut [u8]) -> std::io::Result<usize> {
+    loop {
+        match reader.read(buf) {
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            res => return res,
+        }
+    }
+}
+
+/// Encode the bytes of a reader and write the base58 symbols to a writer
+///
+/// The reader is consumed in 8 bytes blocks, memory usage is constant whatever the input size.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_from_reader(
+    mut reader: impl std::io::Read,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    let mut buf = [0u8; FULL_BLOCK_SIZE];
+    loop {
+        let len = read_block(&mut reader, &mut buf)?;
+        if len > 0 {
+            write_encoded_block(&buf[..len], &mut writer)?;
+        }
+        if len < FULL_BLOCK_SIZE {
+            return Ok(());
+        }
+    }
+}
+
+/// Encdoe a byte stream in a base58 stream of characters
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn encode_stream<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        let blocks = encode_stream_blocks(data);
+        pin_mut!(blocks);
+
+        while let Some(block) = blocks.next().await {
+            for c in block?.as_str().chars() {
+                yield c;
+            }
+        }
+    })
+}
+
+/// Encode an owned byte stream in a `'static` base58 stream of characters
+///
+/// Same as [`encode_stream`] with the reader taken by value, the returned stream is `Send` and
+/// `'static` and can be spawned on a task, e.g. with `tokio::spawn`.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn encode_stream_owned<T>(
+    data: T,
+) -> impl FusedStream<Item = Result<char>> + Unpin + Send + 'static
+where
+    T: AsyncRead + Send + 'static,
+{
+    encode_stream(data)
+}
+
+/// Encode a byte stream in a stream of encoded blocks
+///
+/// Each item is a whole block of 11 characters, except the last one, giving natural chunk
+/// boundaries to framing layers.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn encode_stream_blocks<T>(data: T) -> impl FusedStream<Item = Result<EncodedBlock>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut trace = StreamTrace::new("encode_stream");
+        let mut clen = 0;
+        let mut buf = [0; FULL_BLOCK_SIZE];
+
+        loop {
+            let len = data.read(&mut buf[clen..]).await?;
+            clen += len;
+
+            if len == 0 {
+                // EOF reached, final block is created
+                if clen > 0 {
+                    trace.block(clen);
+                    yield encode_block_chars(&buf[..clen])?;
+                }
+                trace.finish();
+                break;
+            }
+
+            if clen == FULL_BLOCK_SIZE {
+                // Buffer is full, yield a full block
+                trace.block(clen);
+                yield encode_block_chars(&buf)?;
+                clen = 0;
+            }
+        }
+    })
+}
+
+// Size of the read buffer of the `*_stream_chunks` functions, in blocks
+#[cfg(feature = "stream")]
+const STREAM_CHUNK_BLOCKS: usize = 1024;
+
+/// Encode a byte stream in a stream of base58 strings
+///
+/// The whole blocks read from the input are encoded together and yielded as one string, of at
+/// most 1024 blocks, the trailing partial block is encoded at the end of the input. Concatenating
+/// the items gives the same string as [`encode`], with the per-item overhead of the stream
+/// amortized over many blocks.
+///
+/// ```rust
+/// # #[tokio::main]
+/// # async fn main() {
+/// use base58_monero::encode_stream_chunks;
+/// use futures_util::{pin_mut, stream::StreamExt};
+///
+/// let stream = encode_stream_chunks(&b"Hello World"[..]);
+/// pin_mut!(stream);
+/// let mut res = String::new();
+/// while let Some(chunk) = stream.next().await {
+///     res.push_str(&chunk.unwrap());
+/// }
+/// assert_eq!("D7LMXYjUbXc1fS9Z", res);
+/// # }
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn encode_stream_chunks<T>(data: T) -> impl FusedStream<Item = Result<String>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut trace = StreamTrace::new("encode_stream");
+        let mut clen = 0;
+        let mut buf = vec![0; STREAM_CHUNK_BLOCKS * FULL_BLOCK_SIZE];
+
+        loop {
+            let len = data.read(&mut buf[clen..]).await?;
+            clen += len;
+
+            // Whole blocks are encoded as soon as read, the trailing partial block at EOF
+            let end = if len == 0 { clen } else { clen - clen % FULL_BLOCK_SIZE };
+            if end > 0 {
+                let mut chunk = String::with_capacity(encoded_len(end));
+                for block in buf[..end].chunks(FULL_BLOCK_SIZE) {
+                    trace.block(block.len());
+                }
+                push_encoded_blocks(&buf[..end], &mut chunk)?;
+                buf.copy_within(end..clen, 0);
+                clen -= end;
+                yield chunk;
+            }
+
+            if len == 0 {
+                trace.finish();
+                break;
+            }
+        }
+    })
+}
+
+// Split the data into its full blocks and its trailing partial block followed by the checksum,
+// returned in a stack buffer with its length, so the checksum is encoded without copying the data
+#[cfg(feature = "check")]
+fn split_check_tail<'a>(
+    data: &'a [u8],
+    checksum: &Checksum,
+) -> (&'a [u8], [u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE], usize) {
+    let (full, rest) = data.split_at(data.len() - data.len() % FULL_BLOCK_SIZE);
+    let mut tail = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+    tail[..rest.len()].copy_from_slice(rest);
+    tail[rest.len()..rest.len() + CHECKSUM_SIZE].copy_from_slice(checksum.as_bytes());
+    (full, tail, rest.len() + CHECKSUM_SIZE)
+}
+
+/// Encode a byte vector into a base58-check string, adds 4 bytes checksum
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn encode_check(data: impl AsRef<[u8]>) -> Result<String> {
+    encode_check_full(data).map(|(encoded, _)| encoded)
+}
+
+/// Encode a byte vector into a base58-check string, returns the string and its 4 bytes checksum
+///
+/// ```rust
+/// use base58_monero::{encode_check, encode_check_full};
+///
+/// let (encoded, checksum) = encode_check_full(b"Hello World")?;
+/// assert_eq!(encode_check(b"Hello World")?, encoded);
+/// assert_eq!("592fa743", checksum.to_string());
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn encode_check_full(data: impl AsRef<[u8]>) -> Result<(String, Checksum)> {
+    let data = data.as_ref();
+    let checksum = checksum(data);
+    let (full, tail, tail_len) = split_check_tail(data, &checksum);
+    let mut encoded = String::with_capacity(encoded_len(data.len() + CHECKSUM_SIZE));
+    push_encoded_blocks(full, &mut encoded)?;
+    push_encoded_blocks(&tail[..tail_len], &mut encoded)?;
+    #[cfg(all(feature = "self-check", debug_assertions))]
+    assert!(
+        decode_check(&encoded).is_ok_and(|decoded| decoded == data),
+        "self-check: base58-check string does not decode to its bytes"
+    );
+    Ok((encoded, checksum))
+}
+
+/// Encode a byte vector into a base58-check string written to `out`, return the number of bytes
+/// written
+///
+/// Nothing is allocated. Buffers shorter than the [`encoded_len`] of the data and its checksum
+/// return [`Error::BufferTooSmall`] before anything is written.
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub fn encode_check_into(data: impl AsRef<[u8]>, out: &mut [u8]) -> Result<usize> {
+    let data = data.as_ref();
+    if out.len() < encoded_len(data.len() + CHECKSUM_SIZE) {
+        return Err(Error::BufferTooSmall);
+    }
+    let (full, tail, tail_len) = split_check_tail(data, &checksum(data));
+    let pos = encode_blocks_into(full, out, 0)?;
+    encode_blocks_into(&tail[..tail_len], out, pos)
+}
+
+/// Encode a byte vector into a base58-check string held in an array of `N` bytes, return the
+/// array and the length of the string
+///
+/// Nothing is allocated. Data whose [`encoded_len`] with the checksum exceeds `N` returns
+/// [`Error::BufferTooSmall`].
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub fn encode_check_to_array<const N: usize>(data: impl AsRef<[u8]>) -> Result<([u8; N], usize)> {
+    let mut res = [0u8; N];
+    let len = encode_check_into(data, &mut res)?;
+    Ok((res, len))
+}
+
+/// Encode a byte vector into a base58-check `heapless::String` of at most `N` bytes
+///
+/// Data whose [`encoded_len`] with the checksum exceeds `N` returns [`Error::BufferTooSmall`].
+#[cfg(all(feature = "check", feature = "heapless"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "heapless"))))]
+pub fn encode_check_heapless<const N: usize>(
+    data: impl AsRef<[u8]>,
+) -> Result<heapless::String<N>> {
+    let mut res = heapless::Vec::new();
+    // Resizing up to the capacity never fails
+    let _ = res.resize_default(N);
+    let len = encode_check_into(data, &mut res)?;
+    res.truncate(len);
+    match heapless::String::from_utf8(res) {
+        Ok(encoded) => Ok(encoded),
+        // Base58 symbols are ASCII
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Encode a byte vector with a 4 bytes checksum and write the base58 symbols to a writer
+///
+/// The output is written block by block without building an intermediate string.
+#[cfg(all(feature = "check", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "std"))))]
+pub fn encode_check_to_writer(
+    data: impl AsRef<[u8]>,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    let data = data.as_ref();
+    let (full, tail, len) = split_check_tail(data, &checksum(data));
+    encode_to_writer(full, &mut writer)?;
+    encode_to_writer(&tail[..len], writer)
+}
+
+/// Encode the bytes of a reader with a 4 bytes checksum and write the base58 symbols to a writer
+///
+/// The checksum is computed while the reader is consumed in 8 bytes blocks, the input is read only
+/// once and memory usage is constant whatever the input size.
+///
+/// ```rust
+/// use base58_monero::{encode_check, encode_check_from_reader};
+///
+/// let mut out = Vec::new();
+/// encode_check_from_reader(&b"Hello World"[..], &mut out)?;
+/// assert_eq!(encode_check(b"Hello World").unwrap().as_bytes(), &out[..]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "std"))))]
+pub fn encode_check_from_reader(
+    mut reader: impl std::io::Read,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    let mut hasher = Keccak::v256();
+    let mut tail = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+    loop {
+        let len = read_block(&mut reader, &mut tail[..FULL_BLOCK_SIZE])?;
+        hasher.update(&tail[..len]);
+        if len < FULL_BLOCK_SIZE {
+            // EOF reached, the remaining bytes are encoded with the checksum
+            let mut checksum = [0u8; 32];
+            hasher.finalize(&mut checksum);
+            tail[len..len + CHECKSUM_SIZE].copy_from_slice(&checksum[..CHECKSUM_SIZE]);
+            return encode_to_writer(&tail[..len + CHECKSUM_SIZE], writer);
+        }
+        write_encoded_block(&tail[..FULL_BLOCK_SIZE], &mut writer)?;
+    }
+}
+
+/// Encode a byte stream in a base58 stream of characters with a 4 bytes checksum
+#[cfg(all(feature = "check", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+pub fn encode_stream_check<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut trace = StreamTrace::new("encode_stream_check");
+        let mut clen = 0;
+        let mut buf = [0; FULL_BLOCK_SIZE];
+        let mut checksum = [0u8; 32];
+        let mut hasher = Keccak::v256();
+
+        loop {
+            let len = data.read(&mut buf[clen..]).await?;
+            clen += len;
+
+            if len == 0 {
+                // EOF reached, final block is created
+                if clen > 0 {
+                    trace.block(clen);
+                }
+                hasher.update(&buf[..clen]);
+                hasher.finalize(&mut checksum);
+
+                // The remaining bytes are encoded with the checksum, as in `encode_check`, the
+                // empty input encodes the checksum alone
+                let mut tail = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+                tail[..clen].copy_from_slice(&buf[..clen]);
+                tail[clen..clen + CHECKSUM_SIZE].copy_from_slice(&checksum[..CHECKSUM_SIZE]);
+                for block in tail[..clen + CHECKSUM_SIZE].chunks(FULL_BLOCK_SIZE) {
+                    for c in encode_block_chars(block)?.as_str().chars() {
+                        yield c;
+                    }
+                }
+
+                trace.finish();
+                break;
+            }
+
+            if clen == FULL_BLOCK_SIZE {
+                // Buffer is full, yield a full encoded block
+                hasher.update(&buf);
+                trace.block(clen);
+
+                for c in encode_block_chars(&buf)?.as_str().chars() {
+                    yield c;
+                }
+
+                clen = 0;
+            }
+        }
+    })
+}
+
+/// Decode base58-encoded string into a byte vector
+///
+/// Any string container can be decoded, e.g. `&str`, `String` or `Cow<str>`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    decode_bytes(data.as_ref().as_bytes())
+}
+
+/// Decode base58-encoded ASCII bytes into a byte vector
+///
+/// Works as [`decode`] without requiring valid UTF-8 input, bytes outside of the base58 alphabet
+/// are rejected with [`Error::InvalidSymbol`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_bytes(data: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    let data = data.as_ref();
+    let mut res = Vec::new();
+    observe(decode_append(data, &mut res), false, data.len())?;
+    self_check(&res, data);
+    Ok(res)
+}
+
+// Decode base58-encoded ASCII bytes, appending the decoded bytes to `res`
+#[cfg(feature = "alloc")]
+fn decode_append(data: &[u8], res: &mut Vec<u8>) -> Result<()> {
+    decode_blocks(data, |bytes| res.extend_from_slice(bytes))
+}
+
+// Number of blocks converted at once by the `simd` feature
+const SIMD_BATCH_BLOCKS: usize = 16;
+
+// Decode base58-encoded ASCII bytes, passing the decoded bytes of each block to `f`
+//
+// With the `simd` feature the symbols of a batch of blocks are converted into digits with SIMD
+// instructions, a batch with an invalid symbol falls back on the scalar path to return the same
+// error. The `small` feature keeps only the scalar path.
+fn decode_blocks(data: &[u8], mut f: impl FnMut(&[u8])) -> Result<()> {
+    for batch in data.chunks(SIMD_BATCH_BLOCKS * FULL_ENCODED_BLOCK_SIZE) {
+        #[cfg(all(feature = "simd", not(feature = "small")))]
+        {
+            let mut digits = [0u8; SIMD_BATCH_BLOCKS * FULL_ENCODED_BLOCK_SIZE];
+            let digits = &mut digits[..batch.len()];
+            if crate::simd::symbols_to_digits(batch, digits) {
+                for block in digits.chunks(FULL_ENCODED_BLOCK_SIZE) {
+                    f(decode_block_with(block, |d| Some(d as usize))?.as_bytes());
+                }
+                continue;
+            }
+        }
+        for block in batch.chunks(FULL_ENCODED_BLOCK_SIZE) {
+            f(decode_block(block)?.as_bytes());
+        }
+    }
+    Ok(())
+}
+
+// Decode base58-encoded ASCII bytes, writing the first decoded bytes to `out` and the following
+// ones to `rest`, the lengths of both buffers must add up to the decoded length
+fn decode_blocks_into(data: &[u8], out: &mut [u8], rest: &mut [u8]) -> Result<()> {
+    let out_len = out.len();
+    let mut pos = 0;
+    decode_blocks(data, |bytes| {
+        let (head, tail) = bytes.split_at(bytes.len().min(out_len.saturating_sub(pos)));
+        out[pos.min(out_len)..][..head.len()].copy_from_slice(head);
+        rest[(pos + head.len()).saturating_sub(out_len)..][..tail.len()].copy_from_slice(tail);
+        pos += bytes.len();
+    })
+}
+
+/// Decode base58-encoded string into `out`, return the number of bytes written
+///
+/// Nothing is allocated. Inputs of invalid length return [`Error::InvalidBlockSize`] and buffers
+/// shorter than the [`decoded_len`] of the input return [`Error::BufferTooSmall`], both before
+/// any block is decoded. The content of `out` is unspecified on error.
+///
+/// ```rust
+/// use base58_monero::decode_into;
+///
+/// let mut buf = [0u8; 32];
+/// let len = decode_into("D7LMXYjUbXc1fS9Z", &mut buf)?;
+/// assert_eq!(b"Hello World", &buf[..len]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn decode_into(data: impl AsRef<str>, out: &mut [u8]) -> Result<usize> {
+    let data = data.as_ref().as_bytes();
+    let res = decoded_len(data.len())
+        .ok_or(Error::InvalidBlockSize)
+        .and_then(|len| {
+            let out = out.get_mut(..len).ok_or(Error::BufferTooSmall)?;
+            decode_blocks_into(data, out, &mut [])?;
+            Ok(len)
+        });
+    observe(res, false, data.len())
+}
+
+/// Decode base58-encoded string into an array of `N` bytes, return the array and the number of
+/// decoded bytes
+///
+/// Nothing is allocated, `N` bounds the decoded length at compile time. Inputs decoding to more
+/// than `N` bytes return [`Error::BufferTooSmall`] before any block is decoded.
+///
+/// ```rust
+/// use base58_monero::decode_to_array;
+///
+/// let (buf, len) = decode_to_array::<32>("D7LMXYjUbXc1fS9Z")?;
+/// assert_eq!(b"Hello World", &buf[..len]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn decode_to_array<const N: usize>(data: impl AsRef<str>) -> Result<([u8; N], usize)> {
+    let mut res = [0u8; N];
+    let len = decode_into(data, &mut res)?;
+    Ok((res, len))
+}
+
+/// Decode base58-encoded string into a `heapless::Vec` of at most `N` bytes
+///
+/// Inputs decoding to more than `N` bytes return [`Error::BufferTooSmall`] before any block is
+/// decoded.
+///
+/// ```rust
+/// use base58_monero::decode_heapless;
+///
+/// let bytes = decode_heapless::<32>("D7LMXYjUbXc1fS9Z")?;
+/// assert_eq!(b"Hello World", bytes.as_slice());
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub fn decode_heapless<const N: usize>(data: impl AsRef<str>) -> Result<heapless::Vec<u8, N>> {
+    let mut res = heapless::Vec::new();
+    // Resizing up to the capacity never fails
+    let _ = res.resize_default(N);
+    let len = decode_into(data, &mut res)?;
+    res.truncate(len);
+    Ok(res)
+}
+
+/// Decode base58-encoded string and write the bytes to a writer
+///
+/// The output is written block by block without building an intermediate vector, the bytes of
+/// the blocks preceding an invalid block are already written when the error is returned. Decoding
+/// errors are returned as [`std::io::ErrorKind::InvalidData`] errors wrapping [`Error`](enum@Error).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn decode_to_writer(
+    data: impl AsRef<str>,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    data.as_ref()
+        .as_bytes()
+        .chunks(FULL_ENCODED_BLOCK_SIZE)
+        .try_for_each(|block| {
+            let block = decode_block(block)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+            writer.write_all(&block.data[FULL_BLOCK_SIZE - block.size..])
+        })
+}
+
+// Decode the symbols of the `read` function in 11 characters blocks, passing the decoded bytes of
+// each block to `f`, decoding errors are converted with `invalid`
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+pub(crate) fn decode_reader_blocks_with<E, R, F>(
+    mut read: R,
+    invalid: impl Fn(Error) -> E,
+    mut f: F,
+) -> core::result::Result<(), E>
+where
+    R: FnMut(&mut [u8]) -> core::result::Result<usize, E>,
+    F: FnMut(&[u8]) -> core::result::Result<(), E>,
+{
+    let mut buf = [0u8; FULL_ENCODED_BLOCK_SIZE];
+    loop {
+        let len = read_block_with(&mut buf, &mut read)?;
+        if len > 0 {
+            let block = decode_block(&buf[..len]).map_err(&invalid)?;
+            f(block.as_bytes())?;
+        }
+        if len < FULL_ENCODED_BLOCK_SIZE {
+            return Ok(());
+        }
+    }
+}
+
+// Decode the symbols of the `read` function with a 4 bytes checksum, passing the payload to `f` as
+// it is decoded, the trailing 4 bytes are held back and verified against the checksum at EOF
+#[cfg(all(feature = "check", any(feature = "std", feature = "embedded-io")))]
+pub(crate) fn decode_check_reader_blocks_with<E, R, F>(
+    read: R,
+    invalid: impl Fn(Error) -> E,
+    mut f: F,
+) -> core::result::Result<(), E>
+where
+    R: FnMut(&mut [u8]) -> core::result::Result<usize, E>,
+    F: FnMut(&[u8]) -> core::result::Result<(), E>,
+{
+    let mut hasher = Keccak::v256();
+    // Last decoded bytes are held until EOF as they may be the checksum
+    let mut held = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+    let mut held_len = 0;
+    decode_reader_blocks_with(read, &invalid, |bytes| {
+        held[held_len..held_len + bytes.len()].copy_from_slice(bytes);
+        held_len += bytes.len();
+        if held_len > CHECKSUM_SIZE {
+            let len = held_len - CHECKSUM_SIZE;
+            hasher.update(&held[..len]);
+            f(&held[..len])?;
+            held.copy_within(len..held_len, 0);
+            held_len = CHECKSUM_SIZE;
+        }
+        Ok(())
+    })?;
+
+    let mut checksum = [0u8; 32];
+    hasher.finalize(&mut checksum);
+    if held_len < CHECKSUM_SIZE || Checksum::from_hash(&checksum) != held[..CHECKSUM_SIZE] {
+        return Err(invalid(Error::InvalidChecksum));
+    }
+    Ok(())
+}
+
+// Decode the reader in 11 characters blocks, passing the decoded bytes of each block to `f`
+#[cfg(feature = "std")]
+fn decode_reader_blocks<F>(mut reader: impl std::io::Read, f: F) -> std::io::Result<()>
+where
+    F: FnMut(&[u8]) -> std::io::Result<()>,
+{
+    decode_reader_blocks_with(|buf| read_uninterrupted(&mut reader, buf), invalid_data, f)
+}
+
+// Convert a decoding error into a `std::io::ErrorKind::InvalidData` error
+#[cfg(feature = "std")]
+fn invalid_data(e: Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+}
+
+/// Decode the base58 symbols of a reader and write the bytes to a writer
+///
+/// The reader is consumed in 11 characters blocks, memory usage is constant whatever the input
+/// size. Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors wrapping
+/// [`Error`](enum@Error).
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn decode_from_reader(
+    reader: impl std::io::Read,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    decode_reader_blocks(reader, |bytes| writer.write_all(bytes))
+}
+
+/// Decode the base58 symbols of a buffered reader up to a delimiter
+///
+/// The symbols are decoded straight from the reader buffer, in 11 characters blocks, until `delim`
+/// or EOF. The delimiter is consumed but not decoded, the reader is left at the start of the next
+/// record. Returns `None` at EOF when no symbol and no delimiter were read.
+///
+/// Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors wrapping
+/// [`Error`](enum@Error), the remaining symbols of the record are consumed so the caller can skip
+/// to the next record.
+///
+/// ```rust
+/// use base58_monero::decode_until;
+///
+/// let mut records = &b"D7LMXYjUbXc1fS9Z,10,"[..];
+/// assert_eq!(b"Hello World", &decode_until(&mut records, b',')?.unwrap()[..]);
+/// assert!(decode_until(&mut records, b',').is_err());
+/// assert_eq!(None, decode_until(&mut records, b',')?);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn decode_until(
+    mut reader: impl std::io::BufRead,
+    delim: u8,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut res = Vec::new();
+    let mut err = None;
+    let mut block = [0u8; FULL_ENCODED_BLOCK_SIZE];
+    let mut len = 0;
+    let mut read = false;
+    let mut decode = |symbols: &[u8], err: &mut Option<Error>| {
+        if err.is_none() {
+            match decode_block(symbols) {
+                Ok(block) => res.extend_from_slice(block.as_bytes()),
+                Err(e) => *err = Some(e),
+            }
+        }
+    };
+
+    loop {
+        let buf = match reader.fill_buf() {
+            Ok(buf) => buf,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if buf.is_empty() {
+            break;
+        }
+        read = true;
+        let (symbols, found) = match buf.iter().position(|&b| b == delim) {
+            Some(i) => (&buf[..i], true),
+            None => (buf, false),
+        };
+        for &c in symbols {
+            block[len] = c;
+            len += 1;
+            if len == FULL_ENCODED_BLOCK_SIZE {
+                decode(&block, &mut err);
+                len = 0;
+            }
+        }
+        let consumed = symbols.len() + usize::from(found);
+        reader.consume(consumed);
+        if found {
+            break;
+        }
+    }
+
+    if len > 0 {
+        decode(&block[..len], &mut err);
+    }
+    match err {
+        Some(e) => Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e)),
+        None if read => Ok(Some(res)),
+        None => Ok(None),
+    }
+}
+
+/// Decode the base58 symbols of a reader with a 4 bytes checksum and write the bytes to a writer
+///
+/// The reader is consumed in 11 characters blocks and only the trailing 4 bytes are held back
+/// until the checksum is verified at EOF: the payload is written as it is decoded, before the
+/// checksum is verified, and the writer is flushed only once the checksum is valid. When
+/// [`Error::InvalidChecksum`] is returned the bytes already written are unverified, write to a
+/// temporary file moved to its destination on success, or use [`decode_check_seekable`] to verify
+/// the checksum before writing any byte. Input shorter than the checksum is rejected with
+/// [`Error::InvalidChecksum`].
+#[cfg(all(feature = "check", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "std"))))]
+pub fn decode_check_from_reader(
+    mut reader: impl std::io::Read,
+    mut writer: impl std::io::Write,
+) -> std::io::Result<()> {
+    decode_check_reader_blocks_with(
+        |buf| read_uninterrupted(&mut reader, buf),
+        invalid_data,
+        |bytes| writer.write_all(bytes),
+    )?;
+    writer.flush()
+}
+
+/// Decode the base58 symbols of a seekable reader with a 4 bytes checksum, verifying the checksum
+/// before writing any byte
+///
+/// The reader is read twice from its current position: a first pass decodes and hashes the
+/// payload without storing it to verify the checksum, then the reader is rewound and the payload
+/// is written to the writer, see [`decode_check_from_reader`]. Memory stays constant whatever the
+/// size of the input, e.g. huge checksummed files, and nothing reaches the writer when
+/// [`Error::InvalidChecksum`] is returned. The checksum is verified again in the second pass in
+/// case the input changed in between.
+#[cfg(all(feature = "check", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "std"))))]
+pub fn decode_check_seekable<R>(mut reader: R, writer: impl std::io::Write) -> std::io::Result<()>
+where
+    R: std::io::Read + std::io::Seek,
+{
+    let start = reader.stream_position()?;
+    decode_check_from_reader(&mut reader, std::io::sink())?;
+    reader.seek(std::io::SeekFrom::Start(start))?;
+    decode_check_from_reader(reader, writer)
+}
+
+/// Decode base58-encoded string into a byte vector, reporting progress to a callback
+///
+/// The callback receives the number of blocks processed and the total number of blocks, it is
+/// called after every `every_n_blocks` blocks and once the last block is decoded. An
+/// `every_n_blocks` of `0` is treated as `1`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_with_progress<F>(
+    data: impl AsRef<str>,
+    every_n_blocks: usize,
+    mut progress: F,
+) -> Result<Vec<u8>>
+where
+    F: FnMut(usize, usize),
+{
+    let data = data.as_ref().as_bytes();
+    let every_n_blocks = every_n_blocks.max(1);
+    let total = data.len().div_ceil(FULL_ENCODED_BLOCK_SIZE);
+    let mut res = Vec::new();
+    for (i, block) in data.chunks(FULL_ENCODED_BLOCK_SIZE).enumerate() {
+        let block = decode_block(block)?;
+        res.extend_from_slice(&block.data[FULL_BLOCK_SIZE - block.size..]);
+        let done = i + 1;
+        if done % every_n_blocks == 0 || done == total {
+            progress(done, total);
+        }
+    }
+    Ok(res)
+}
+
+/// Decode base58-encoded string into a byte vector, feeding the decoded bytes to a hasher
+///
+/// Each decoded block is passed to `hasher`, e.g. a `sha2::Sha256` or `blake2::Blake2b512`, so
+/// the digest of the payload is computed without a second pass over the bytes. On error the
+/// hasher holds the bytes of the blocks preceding the invalid block and should be discarded.
+///
+/// ```rust
+/// use base58_monero::decode_with_digest;
+/// use sha2::{Digest, Sha256};
+///
+/// let mut hasher = Sha256::new();
+/// let bytes = decode_with_digest("D7LMXYjUbXc1fS9Z", &mut hasher)?;
+/// assert_eq!(Sha256::digest(&bytes), hasher.finalize());
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub fn decode_with_digest<D>(data: impl AsRef<str>, hasher: &mut D) -> Result<Vec<u8>>
+where
+    D: digest::Update + ?Sized,
+{
+    let mut res = Vec::new();
+    for block in data.as_ref().as_bytes().chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block(block)?;
+        let bytes = &block.data[FULL_BLOCK_SIZE - block.size..];
+        hasher.update(bytes);
+        res.extend_from_slice(bytes);
+    }
+    Ok(res)
+}
+
+/// Details of the decoding of a single block, part of a [`DecodeReport`]
+#[derive(Debug, PartialEq)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct BlockReport {
+    /// Offset of the block in the input, in bytes
+    pub offset: usize,
+    /// Symbols of the block, invalid UTF-8 sequences are replaced by `U+FFFD`
+    pub encoded: String,
+    /// Number of leading `1` symbols, i.e. zero digits padding the block
+    pub padding: usize,
+    /// Decoded bytes of the block or the error returned when decoding it
+    pub decoded: Result<DecodedBlock>,
+}
+
+/// Status of the 4 bytes checksum in a [`DecodeReport`]
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumStatus {
+    /// The last 4 decoded bytes are the checksum of the preceding bytes
+    Valid,
+    /// The last 4 decoded bytes do not match the checksum of the preceding bytes
+    Invalid,
+    /// A block failed to decode or less than 4 bytes were decoded
+    Unavailable,
+}
+
+/// Per-block report of the decoding of a base58 string, returned by [`decode_report`]
+#[derive(Debug, PartialEq)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct DecodeReport {
+    /// Report of each block of the input, in order
+    pub blocks: Vec<BlockReport>,
+    /// Status of the checksum, assuming the input was encoded with [`encode_check`]
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    pub checksum: ChecksumStatus,
+}
+
+#[cfg(feature = "alloc")]
+impl DecodeReport {
+    /// Return the first block error, if any
+    pub fn error(&self) -> Option<&Error> {
+        self.blocks
+            .iter()
+            .find_map(|block| block.decoded.as_ref().err())
+    }
+
+    /// Return the decoded bytes if every block decoded successfully
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        let mut res = Vec::new();
+        for block in &self.blocks {
+            res.extend_from_slice(block.decoded.as_ref().ok()?.as_bytes());
+        }
+        Some(res)
+    }
+}
+
+/// Decode base58-encoded string into a per-block report
+///
+/// Unlike [`decode`] the decoding does not stop at the first error, every block is reported with
+/// its position, symbols and result, letting debugging tools show exactly how an input fails.
+///
+/// ```rust
+/// use base58_monero::{decode_report, Error};
+///
+/// let report = decode_report("D7LMXYjUbXc1fS9-");
+/// assert_eq!(2, report.blocks.len());
+/// assert_eq!(b"Hello Wo", report.blocks[0].decoded.as_ref().unwrap().as_bytes());
+/// assert_eq!(11, report.blocks[1].offset);
+/// assert_eq!(Err(Error::InvalidSymbol), report.blocks[1].decoded);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_report(data: impl AsRef<str>) -> DecodeReport {
+    let data = data.as_ref().as_bytes();
+    let blocks: Vec<BlockReport> = data
+        .chunks(FULL_ENCODED_BLOCK_SIZE)
+        .enumerate()
+        .map(|(i, chunk)| BlockReport {
+            offset: i * FULL_ENCODED_BLOCK_SIZE,
+            encoded: String::from_utf8_lossy(chunk).into_owned(),
+            padding: chunk.iter().take_while(|&&c| c == b'1').count(),
+            decoded: decode_block(chunk),
+        })
+        .collect();
+    DecodeReport {
+        #[cfg(feature = "check")]
+        checksum: report_checksum(&blocks),
+        blocks,
+    }
+}
+
+// Verify the checksum of the decoded blocks
+#[cfg(all(feature = "check", feature = "alloc"))]
+fn report_checksum(blocks: &[BlockReport]) -> ChecksumStatus {
+    let mut bytes = Vec::new();
+    for block in blocks {
+        match &block.decoded {
+            Ok(block) => bytes.extend_from_slice(block.as_bytes()),
+            Err(_) => return ChecksumStatus::Unavailable,
+        }
+    }
+    if bytes.len() < CHECKSUM_SIZE {
+        return ChecksumStatus::Unavailable;
+    }
+    let (bytes, check) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+    if checksum(bytes) == *check {
+        ChecksumStatus::Valid
+    } else {
+        ChecksumStatus::Invalid
+    }
+}
+
+/// Decode base58-encoded string into a byte vector, locating the error in the input
+///
+/// Same as [`decode`] with a [`DecodeError`] giving the offset and character of an invalid symbol,
+/// the length of an input with an invalid block size or the block decoding to an overflow.
+///
+/// ```rust
+/// use base58_monero::{decode_verbose, DecodeError};
+///
+/// assert_eq!(b"Hello World", &decode_verbose("D7LMXYjUbXc1fS9Z")?[..]);
+/// assert_eq!(
+///     Err(DecodeError::InvalidSymbol { offset: 4, symbol: 'l' }),
+///     decode_verbose("D7LMlYjUbXc1fS9Z")
+/// );
+/// assert_eq!(
+///     Err(DecodeError::Overflow { block: 1, offset: 11 }),
+///     decode_verbose("123456789AB5R")
+/// );
+/// # Ok::<(), DecodeError>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_verbose(data: impl AsRef<str>) -> core::result::Result<Vec<u8>, DecodeError> {
+    let data = data.as_ref().as_bytes();
+    let mut res = Vec::with_capacity(decoded_len(data.len()).unwrap_or(0));
+    for (i, chunk) in data.chunks(FULL_ENCODED_BLOCK_SIZE).enumerate() {
+        let offset = i * FULL_ENCODED_BLOCK_SIZE;
+        match decode_block(chunk) {
+            Ok(block) => res.extend_from_slice(block.as_bytes()),
+            Err(e) => return Err(DecodeError::locate(&e, &data[offset..], offset, data.len())),
+        }
+    }
+    Ok(res)
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into a byte vector, locating the error in
+/// the input
+///
+/// Same as [`decode_check`] with the errors of [`decode_verbose`], the checksum error has no
+/// position.
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check_verbose(data: impl AsRef<str>) -> core::result::Result<Vec<u8>, DecodeError> {
+    let mut bytes = decode_verbose(data)?;
+    if bytes.len() < CHECKSUM_SIZE {
+        return Err(DecodeError::InvalidChecksum);
+    }
+    let len = bytes.len() - CHECKSUM_SIZE;
+    if checksum(&bytes[..len]) != bytes[len..] {
+        return Err(DecodeError::InvalidChecksum);
+    }
+    bytes.truncate(len);
+    Ok(bytes)
+}
+
+/// Decode base58-encoded string into a byte vector, rejecting inputs longer than `max_len`
+///
+/// Inputs over `max_len` characters return [`Error::InvalidLength`] before any block is decoded,
+/// bounding the work done on untrusted input.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_bounded(data: impl AsRef<str>, max_len: usize) -> Result<Vec<u8>> {
+    let data = data.as_ref();
+    if data.len() > max_len {
+        return Err(Error::InvalidLength);
+    }
+    decode(data)
+}
+
+/// Decode base58-encoded string into a byte vector of exactly `expected_len` bytes
+///
+/// The decoded length only depends on the encoded length, inputs that cannot decode to
+/// `expected_len` bytes return [`Error::InvalidLength`] before any block is decoded. The output is
+/// allocated once with the exact size.
+///
+/// ```rust
+/// use base58_monero::{decode_expecting, Error};
+///
+/// assert_eq!(b"Hello World", &decode_expecting("D7LMXYjUbXc1fS9Z", 11)?[..]);
+/// // Truncated input
+/// assert_eq!(Err(Error::InvalidLength), decode_expecting("D7LMXYjUbXc1fS9", 11));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_expecting(data: impl AsRef<str>, expected_len: usize) -> Result<Vec<u8>> {
+    let data = data.as_ref().as_bytes();
+    if decoded_len(data.len()) != Some(expected_len) {
+        return Err(Error::InvalidLength);
+    }
+    let mut res = Vec::with_capacity(expected_len);
+    decode_append(data, &mut res)?;
+    Ok(res)
+}
+
+/// Format detected by [`decode_hex_or_base58`] and `decode_hex_or_base58_check`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DetectedFormat {
+    /// Hexadecimal, with or without `0x` prefix
+    Hex,
+    /// Monero base58
+    Base58,
+    /// Monero base58 with a valid 4 bytes checksum, removed from the decoded bytes
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    Base58Check,
+}
+
+// Decode an even number of hex digits, `None` if the input is not hex
+#[cfg(feature = "alloc")]
+fn decode_hex(data: &[u8]) -> Option<Vec<u8>> {
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+    if !data.len().is_multiple_of(2) {
+        return None;
+    }
+    data.chunks(2)
+        .map(|pair| Some(nibble(pair[0])? << 4 | nibble(pair[1])?))
+        .collect()
+}
+
+/// Decode a string that is either hex or base58, returns the detected format and the bytes
+///
+/// The format is detected in this order:
+///
+///  * a `0x` prefix is always hex,
+///  * a non-empty even number of hex digits is hex, e.g. keys and hashes,
+///  * anything else is decoded as base58, returning the base58 decoding error if invalid.
+///
+/// Checksums are never verified, see `decode_hex_or_base58_check` to detect base58-check strings.
+///
+/// ```rust
+/// use base58_monero::{decode_hex_or_base58, DetectedFormat};
+///
+/// let (format, bytes) = decode_hex_or_base58("48656c6c6f20576f726c64")?;
+/// assert_eq!((DetectedFormat::Hex, &b"Hello World"[..]), (format, &bytes[..]));
+/// let (format, bytes) = decode_hex_or_base58("D7LMXYjUbXc1fS9Z")?;
+/// assert_eq!((DetectedFormat::Base58, &b"Hello World"[..]), (format, &bytes[..]));
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_hex_or_base58(data: impl AsRef<str>) -> Result<(DetectedFormat, Vec<u8>)> {
+    let data = data.as_ref();
+    if let Some(hex) = data.strip_prefix("0x") {
+        return decode_hex(hex.as_bytes())
+            .map(|bytes| (DetectedFormat::Hex, bytes))
+            .ok_or(Error::InvalidSymbol);
+    }
+    if !data.is_empty() {
+        if let Some(bytes) = decode_hex(data.as_bytes()) {
+            return Ok((DetectedFormat::Hex, bytes));
+        }
+    }
+    decode(data).map(|bytes| (DetectedFormat::Base58, bytes))
+}
+
+/// Decode a string that is either hex, base58-check or base58, returns the detected format and
+/// the bytes
+///
+/// Same as [`decode_hex_or_base58`], a string without `0x` prefix is first tried as base58-check:
+/// if its checksum is valid the format is [`DetectedFormat::Base58Check`] and the checksum is
+/// removed. A hex string passes the checksum by chance with a probability of 2<sup>-32</sup>.
+/// Failing the checksum is not reported to the `telemetry` module, only the final decoding error.
+///
+/// ```rust
+/// use base58_monero::{decode_hex_or_base58_check, DetectedFormat};
+///
+/// let (format, bytes) = decode_hex_or_base58_check("D7LMXYjUbXc5LVkq6vWDY")?;
+/// assert_eq!((DetectedFormat::Base58Check, &b"Hello World"[..]), (format, &bytes[..]));
+/// let (format, bytes) = decode_hex_or_base58_check("D7LMXYjUbXc1fS9Z")?;
+/// assert_eq!((DetectedFormat::Base58, &b"Hello World"[..]), (format, &bytes[..]));
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_hex_or_base58_check(data: impl AsRef<str>) -> Result<(DetectedFormat, Vec<u8>)> {
+    let data = data.as_ref();
+    if !data.starts_with("0x") {
+        if let Ok(bytes) = decode_check_unobserved(data.as_bytes()) {
+            return Ok((DetectedFormat::Base58Check, bytes));
+        }
+    }
+    decode_hex_or_base58(data)
+}
+
+/// Decode the characters of an iterator into an iterator of bytes
+///
+/// Characters are assembled into 11 characters blocks internally and each block is decoded when
+/// complete, so any character source can be decoded lazily without IO or async dependencies.
+/// Characters outside of the base58 alphabet, including non-ASCII characters, yield
+/// [`Error::InvalidSymbol`]. The iterator ends after the first error.
+///
+/// ```rust
+/// use base58_monero::decode_chars;
+///
+/// let bytes: Result<Vec<u8>, _> = decode_chars("D7LMXYjUbXc1fS9Z".chars()).collect();
+/// assert_eq!(b"Hello World", &bytes?[..]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+pub fn decode_chars<I>(chars: I) -> DecodeChars<I::IntoIter>
+where
+    I: IntoIterator<Item = char>,
+{
+    DecodeChars {
+        chars: chars.into_iter(),
+        block: DecodedBlock {
+            data: [0; FULL_BLOCK_SIZE],
+            size: 0,
+        },
+        pos: 0,
+        done: false,
+    }
+}
+
+/// Iterator of decoded bytes returned by [`decode_chars`]
+#[derive(Debug, Clone)]
+pub struct DecodeChars<I> {
+    chars: I,
+    // Block being yielded and position of the next byte in the block
+    block: DecodedBlock,
+    pos: usize,
+    // Characters exhausted or error returned
+    done: bool,
+}
+
+impl<I: Iterator<Item = char>> DecodeChars<I> {
+    // Decode the next block of characters, `None` at the end of the characters
+    fn next_block(&mut self) -> Option<Result<DecodedBlock>> {
+        let mut buf = [0u8; FULL_ENCODED_BLOCK_SIZE];
+        let mut len = 0;
+        for c in self.chars.by_ref().take(FULL_ENCODED_BLOCK_SIZE) {
+            if !c.is_ascii() {
+                return Some(Err(Error::InvalidSymbol));
+            }
+            buf[len] = c as u8;
+            len += 1;
+        }
+        // Only the last block can be shorter than a full block
+        self.done = len < FULL_ENCODED_BLOCK_SIZE;
+        match len {
+            0 => None,
+            _ => Some(decode_block(&buf[..len])),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for DecodeChars<I> {
+    type Item = Result<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&b) = self.block.as_bytes().get(self.pos) {
+                self.pos += 1;
+                return Some(Ok(b));
+            }
+            if self.done {
+                return None;
+            }
+            match self.next_block()? {
+                Ok(block) => {
+                    self.block = block;
+                    self.pos = 0;
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> core::iter::FusedIterator for DecodeChars<I> {}
+
+// Decode base58-encoded data of exactly `out.len()` decoded bytes without allocating
+#[cfg(feature = "generic-array")]
+fn decode_exact(data: &[u8], out: &mut [u8]) -> Result<()> {
+    if decoded_len(data.len()) != Some(out.len()) {
+        return Err(Error::InvalidLength);
+    }
+    let mut len = 0;
+    for block in data.chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block(block)?;
+        out[len..len + block.size].copy_from_slice(block.as_bytes());
+        len += block.size;
+    }
+    Ok(())
+}
+
+/// Decode base58-encoded string into a `GenericArray` of exactly `N` bytes
+///
+/// Inputs not decoding to `N` bytes return [`Error::InvalidLength`] before any block is decoded.
+/// Nothing is allocated, the array plugs into the RustCrypto APIs parameterized over `typenum`
+/// lengths.
+///
+/// ```rust
+/// use base58_monero::decode_generic_array;
+/// use generic_array::typenum::U11;
+///
+/// let bytes = decode_generic_array::<U11>("D7LMXYjUbXc1fS9Z").unwrap();
+/// assert_eq!(b"Hello World", bytes.as_slice());
+/// ```
+#[cfg(feature = "generic-array")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic-array")))]
+#[allow(deprecated)]
+pub fn decode_generic_array<N>(data: impl AsRef<str>) -> Result<GenericArray<u8, N>>
+where
+    N: ArrayLength<u8>,
+{
+    let mut res = GenericArray::default();
+    decode_exact(data.as_ref().as_bytes(), &mut res)?;
+    Ok(res)
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into a `GenericArray` of exactly `N` bytes
+///
+/// Inputs not decoding to `N` bytes plus the checksum return [`Error::InvalidLength`] before any
+/// block is decoded.
+#[cfg(all(feature = "check", feature = "generic-array"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "generic-array"))))]
+#[allow(deprecated)]
+pub fn decode_check_generic_array<N>(data: impl AsRef<str>) -> Result<GenericArray<u8, N>>
+where
+    N: ArrayLength<u8>,
+{
+    let data = data.as_ref().as_bytes();
+    if decoded_len(data.len()) != Some(N::USIZE + CHECKSUM_SIZE) {
+        return Err(Error::InvalidLength);
+    }
+    let mut res = GenericArray::<u8, N>::default();
+    let mut check = [0u8; CHECKSUM_SIZE];
+    let mut len = 0;
+    for block in data.chunks(FULL_ENCODED_BLOCK_SIZE) {
+        for &b in decode_block(block)?.as_bytes() {
+            match res.get_mut(len) {
+                Some(r) => *r = b,
+                None => check[len - N::USIZE] = b,
+            }
+            len += 1;
+        }
+    }
+    if checksum(&res) == check[..] {
+        Ok(res)
+    } else {
+        Err(Error::InvalidChecksum)
+    }
+}
+
+/// Decode base58-encoded string using a custom alphabet into a byte vector
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_with_alphabet(data: impl AsRef<str>, alphabet: &Alphabet) -> Result<Vec<u8>> {
+    let mut res = Vec::new();
+    for block in data.as_ref().as_bytes().chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block_with(block, |c| alphabet.digit(c).map(usize::from))?;
+        res.extend_from_slice(&block.data[FULL_BLOCK_SIZE - block.size..]);
+    }
+    Ok(res)
+}
+
+/// Mapping of an unsigned integer to a base58 string, see [`encode_biguint`]
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigUintFormat {
+    /// Big-endian bytes of the integer encoded in Monero base58 blocks, zero is the single byte
+    /// `0` and leading zero bytes are ignored when decoding
+    Blocks,
+    /// Plain positional base58 number, most significant digit first, without leading zero digits
+    /// except for zero itself
+    Positional,
+}
+
+/// Encode an arbitrary-size unsigned integer into a base58 string
+///
+/// ```rust
+/// use base58_monero::{decode_biguint, encode_biguint, BigUintFormat};
+/// use num_bigint::BigUint;
+///
+/// let n = BigUint::from(u64::MAX) * 58u8;
+/// let encoded = encode_biguint(&n, BigUintFormat::Positional);
+/// assert_eq!("jpXCZedGfVQ1", encoded);
+/// assert_eq!(Ok(n), decode_biguint(encoded, BigUintFormat::Positional));
+/// ```
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+pub fn encode_biguint(n: &BigUint, format: BigUintFormat) -> String {
+    match format {
+        BigUintFormat::Blocks => {
+            let mut encoder = Encoder::new();
+            encoder.update(n.to_bytes_be());
+            encoder.finish()
+        }
+        BigUintFormat::Positional => n
+            .to_radix_be(58)
+            .into_iter()
+            .map(|d| BASE58_CHARS[d as usize] as char)
+            .collect(),
+    }
+}
+
+/// Decode a base58 string into an arbitrary-size unsigned integer
+///
+/// An empty string is rejected with [`Error::InvalidLength`] in the positional format and decodes
+/// to zero in the blocks format.
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+pub fn decode_biguint(data: impl AsRef<str>, format: BigUintFormat) -> Result<BigUint> {
+    let data = data.as_ref();
+    match format {
+        BigUintFormat::Blocks => decode(data).map(|bytes| BigUint::from_bytes_be(&bytes)),
+        BigUintFormat::Positional => {
+            if data.is_empty() {
+                return Err(Error::InvalidLength);
+            }
+            let digits = data
+                .bytes()
+                .map(|c| Alphabet::MONERO.digit(c).ok_or(Error::InvalidSymbol))
+                .collect::<Result<Vec<u8>>>()?;
+            // Digits are below the radix
+            BigUint::from_radix_be(&digits, 58).ok_or(Error::InvalidSymbol)
+        }
+    }
+}
+
+/// Decode base58-encoded data from a possibly non-contiguous buffer into a byte vector
+///
+/// The chunks of the buffer are walked and blocks are assembled across chunk boundaries, only a
+/// single block is copied at a time.
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub fn decode_buf(mut data: impl Buf) -> Result<Vec<u8>> {
+    let mut res = Vec::new();
+    let mut buf = [0u8; FULL_ENCODED_BLOCK_SIZE];
+    let mut clen = 0;
+    while data.has_remaining() {
+        let chunk = data.chunk();
+        let len = chunk.len().min(FULL_ENCODED_BLOCK_SIZE - clen);
+        buf[clen..clen + len].copy_from_slice(&chunk[..len]);
+        clen += len;
+        data.advance(len);
+
+        if clen == FULL_ENCODED_BLOCK_SIZE {
+            let block = decode_block(&buf)?;
+            res.extend_from_slice(&block.data[FULL_BLOCK_SIZE - block.size..]);
+            clen = 0;
+        }
+    }
+    if clen > 0 {
+        let block = decode_block(&buf[..clen])?;
+        res.extend_from_slice(&block.data[FULL_BLOCK_SIZE - block.size..]);
+    }
+    Ok(res)
+}
+
+/// Decode a string of base58-encoded values separated by a delimiter into a list of byte vectors
+///
+/// Each value is decoded independently, an empty value decodes to an empty vector. The first
+/// invalid value returns its decoding error.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn decode_split(data: impl AsRef<str>, delimiter: char) -> Result<Vec<Vec<u8>>> {
+    data.as_ref().split(delimiter).map(decode).collect()
+}
+
+/// Writer decoding base58 text block-wise and forwarding the raw bytes to an inner writer
+///
+/// Complete blocks are decoded as soon as they are written, the final partial block is decoded on
+/// [`finish`](Base58DecodeWriter::finish) or drop, [`flush`](std::io::Write::flush) only flushes
+/// the inner writer. Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors
+/// wrapping [`Error`](enum@Error), errors on drop are ignored.
+///
+/// After an invalid block `write` returns the number of bytes of the valid blocks written by the
+/// call, or the decoding error if there are none, and every later call returns the error.
+///
+/// ```rust
+/// use base58_monero::base58::Base58DecodeWriter;
+/// use std::io::Write;
+///
+/// let mut writer = Base58DecodeWriter::new(Vec::new());
+/// writer.write_all(b"D7LMXYjUbXc").unwrap();
+/// writer.write_all(b"1fS9Z").unwrap();
+/// assert_eq!(b"Hello World", &writer.finish().unwrap()[..]);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct Base58DecodeWriter<W: std::io::Write> {
+    // Only taken by `finish`
+    inner: Option<W>,
+    buf: [u8; FULL_ENCODED_BLOCK_SIZE],
+    len: usize,
+    // First decoding error, returned by every later call
+    error: Option<Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Base58DecodeWriter<W> {
+    /// Create a writer forwarding the decoded bytes to `inner`
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Some(inner),
+            buf: [0; FULL_ENCODED_BLOCK_SIZE],
+            len: 0,
+            error: None,
+        }
+    }
+
+    /// Return a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        match &self.inner {
+            Some(inner) => inner,
+            None => unreachable!(),
+        }
+    }
+
+    /// Decode the final partial block, flush and return the inner writer
+    pub fn finish(mut self) -> std::io::Result<W> {
+        self.poisoned()?;
+        self.write_block()?;
+        self.poisoned()?;
+        let mut inner = match self.inner.take() {
+            Some(inner) => inner,
+            None => unreachable!(),
+        };
+        inner.flush()?;
+        Ok(inner)
+    }
+
+    // Return the stored decoding error
+    fn poisoned(&self) -> std::io::Result<()> {
+        match &self.error {
+            Some(e) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                e.duplicate(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // Decode the buffered block and write it to the inner writer, a decoding error is stored
+    fn write_block(&mut self) -> std::io::Result<()> {
+        if self.len == 0 {
+            return Ok(());
+        }
+        let len = core::mem::take(&mut self.len);
+        let block = match decode_block(&self.buf[..len]) {
+            Ok(block) => block,
+            Err(e) => {
+                self.error = Some(e);
+                return Ok(());
+            }
+        };
+        match &mut self.inner {
+            Some(inner) => inner.write_all(block.as_bytes()),
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for Base58DecodeWriter<W> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.poisoned()?;
+        // Bytes of the blocks written to the inner writer
+        let mut written = 0;
+        for (i, &c) in data.iter().enumerate() {
+            self.buf[self.len] = c;
+            self.len += 1;
+            if self.len == FULL_ENCODED_BLOCK_SIZE {
+                self.write_block()?;
+                if self.error.is_some() {
+                    // The error is returned by the next call if valid blocks were written
+                    return match written {
+                        0 => self.poisoned().map(|_| 0),
+                        n => Ok(n),
+                    };
+                }
+                written = i + 1;
+            }
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.poisoned()?;
+        match &mut self.inner {
+            Some(inner) => inner.flush(),
+            None => unreachable!(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Drop for Base58DecodeWriter<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() && self.error.is_none() {
+            let _ = self.write_block();
+        }
+    }
+}
+
+/// Sink decoding base58 text chunks and forwarding the decoded bytes to an inner sink
+///
+/// Chunks of any size and type `T: AsRef<[u8]>`, e.g. `&str` or `Vec<u8>`, are accepted, blocks
+/// are assembled across chunk boundaries and the bytes decoded from each chunk are sent to the
+/// inner sink as one `Vec<u8>`. The final partial block is decoded on
+/// [`poll_close`](Sink::poll_close), in check mode the 4 bytes checksum is verified and stripped at
+/// that point. After a decoding error every call returns the error and nothing more is sent to the
+/// inner sink.
+///
+/// ```rust
+/// # #[cfg(feature = "stream")]
+/// # fn main() -> Result<(), base58_monero::Error> {
+/// use base58_monero::base58::Base58DecodeSink;
+/// use futures_util::sink::{self, SinkExt};
+///
+/// # tokio_test::block_on(async {
+/// let mut sink = Base58DecodeSink::new(sink::drain());
+/// sink.send("D7LMXYjUbXc").await?;
+/// sink.send("1fS9Z").await?;
+/// sink.close().await
+/// # })?;
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "stream"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub struct Base58DecodeSink<S, T> {
+    inner: S,
+    buf: [u8; FULL_ENCODED_BLOCK_SIZE],
+    len: usize,
+    // Decoded bytes not yet sent to the inner sink
+    pending: Vec<u8>,
+    #[cfg(feature = "check")]
+    check: Option<SinkChecksum>,
+    closing: bool,
+    // First decoding error, returned by every later call
+    error: Option<Error>,
+    // Item type of the sink, inferred from the chunks sent
+    item: core::marker::PhantomData<fn(T)>,
+}
+
+// Checksum state of a sink in check mode
+#[cfg(all(feature = "check", feature = "stream"))]
+struct SinkChecksum {
+    hasher: Keccak,
+    // Last decoded bytes are held until closed as they may be the checksum
+    held: [u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE],
+    held_len: usize,
+}
+
+#[cfg(feature = "stream")]
+impl<S, T> Base58DecodeSink<S, T> {
+    /// Create a sink forwarding the decoded bytes to `inner`
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            buf: [0; FULL_ENCODED_BLOCK_SIZE],
+            len: 0,
+            pending: Vec::new(),
+            #[cfg(feature = "check")]
+            check: None,
+            closing: false,
+            error: None,
+            item: core::marker::PhantomData,
+        }
+    }
+
+    /// Create a sink verifying and stripping the 4 bytes checksum when closed
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+    pub fn new_check(inner: S) -> Self {
+        Self {
+            check: Some(SinkChecksum {
+                hasher: Keccak::v256(),
+                held: [0; FULL_BLOCK_SIZE + CHECKSUM_SIZE],
+                held_len: 0,
+            }),
+            ..Self::new(inner)
+        }
+    }
+
+    /// Consume the sink and return the inner sink
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn push_decoded(&mut self, bytes: &[u8]) {
+        #[cfg(feature = "check")]
+        if let Some(check) = &mut self.check {
+            let SinkChecksum {
+                hasher,
+                held,
+                held_len,
+            } = check;
+            held[*held_len..*held_len + bytes.len()].copy_from_slice(bytes);
+            *held_len += bytes.len();
+            if *held_len > CHECKSUM_SIZE {
+                let len = *held_len - CHECKSUM_SIZE;
+                hasher.update(&held[..len]);
+                self.pending.extend_from_slice(&held[..len]);
+                held.copy_within(len..*held_len, 0);
+                *held_len = CHECKSUM_SIZE;
+            }
+            return;
+        }
+        self.pending.extend_from_slice(bytes);
+    }
+
+    // Return the stored decoding error
+    fn poisoned(&self) -> Result<()> {
+        match &self.error {
+            Some(e) => Err(e.duplicate()),
+            None => Ok(()),
+        }
+    }
+
+    // Store the error, the pending bytes are dropped
+    fn poison(&mut self, error: Error) -> Error {
+        self.len = 0;
+        self.pending.clear();
+        self.error = Some(error.duplicate());
+        error
+    }
+
+    fn decode_chunk(&mut self, data: &[u8]) -> Result<()> {
+        self.poisoned()?;
+        for &c in data {
+            self.buf[self.len] = c;
+            self.len += 1;
+            if self.len == FULL_ENCODED_BLOCK_SIZE {
+                let block = decode_block(&self.buf).map_err(|e| self.poison(e))?;
+                self.len = 0;
+                self.push_decoded(block.as_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    // Decode the final partial block and verify the checksum in check mode
+    fn finish(&mut self) -> Result<()> {
+        self.poisoned()?;
+        if self.len > 0 {
+            let block = decode_block(&self.buf[..self.len]).map_err(|e| self.poison(e))?;
+            self.len = 0;
+            self.push_decoded(block.as_bytes());
+        }
+        #[cfg(feature = "check")]
+        if let Some(check) = self.check.take() {
+            let mut checksum = [0u8; 32];
+            check.hasher.finalize(&mut checksum);
+            if check.held_len < CHECKSUM_SIZE
+                || Checksum::from_hash(&checksum) != check.held[..CHECKSUM_SIZE]
+            {
+                return Err(self.poison(Error::InvalidChecksum));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<S, T> Base58DecodeSink<S, T>
+where
+    S: Sink<Vec<u8>> + Unpin,
+    S::Error: Into<Error>,
+{
+    // Send the pending decoded bytes to the inner sink
+    fn poll_send_pending(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        if !self.pending.is_empty() {
+            ready!(Pin::new(&mut self.inner).poll_ready(cx)).map_err(Into::into)?;
+            let pending = core::mem::take(&mut self.pending);
+            Pin::new(&mut self.inner)
+                .start_send(pending)
+                .map_err(Into::into)?;
+        }
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<S, T> Sink<T> for Base58DecodeSink<S, T>
+where
+    S: Sink<Vec<u8>> + Unpin,
+    S::Error: Into<Error>,
+    T: AsRef<[u8]>,
+{
+    type Error = Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        this.poisoned()?;
+        ready!(this.poll_send_pending(cx))?;
+        Pin::new(&mut this.inner).poll_ready(cx).map_err(Into::into)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<()> {
+        self.get_mut().decode_chunk(item.as_ref())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        this.poisoned()?;
+        ready!(this.poll_send_pending(cx))?;
+        Pin::new(&mut this.inner).poll_flush(cx).map_err(Into::into)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        this.poisoned()?;
+        if !this.closing {
+            this.closing = true;
+            this.finish()?;
+        }
+        ready!(this.poll_send_pending(cx))?;
+        Pin::new(&mut this.inner).poll_close(cx).map_err(Into::into)
+    }
+}
+
+/// Return true if all the characters are in the base58 alphabet
+///
+/// Only the alphabet is checked, the string can still fail to decode with
+/// [`Error::InvalidBlockSize`] or [`Error::Overflow`]. Nothing is allocated, e.g. to gate user
+/// input before decoding it.
+///
+/// ```rust
+/// use base58_monero::is_base58;
+///
+/// assert!(is_base58("D7LMXYjUbXc1fS9Z"));
+/// assert!(!is_base58("D7LMXYjUbXc1fS9O"));
+/// ```
+pub fn is_base58(data: impl AsRef<str>) -> bool {
+    find_first_invalid(data).is_none()
+}
+
+/// Return the byte position of the first character not in the base58 alphabet, if any
+///
+/// Only the alphabet is checked, see [`is_base58`]. The position is a byte offset in the string,
+/// the start of the character for non-ASCII characters, e.g. to highlight the character.
+///
+/// ```rust
+/// use base58_monero::find_first_invalid;
+///
+/// assert_eq!(None, find_first_invalid("D7LMXYjUbXc1fS9Z"));
+/// assert_eq!(Some(4), find_first_invalid("D7LMlYjUbXc1fS9Z"));
+/// ```
+pub fn find_first_invalid(data: impl AsRef<str>) -> Option<usize> {
+    data.as_ref()
+        .bytes()
+        .position(|c| Alphabet::MONERO.digit(c).is_none())
+}
+
+/// Normalize a base58-encoded string into its canonical form
+///
+/// ASCII whitespace, e.g. line breaks introduced when copy-pasting, is ignored. The remaining
+/// characters are decoded and re-encoded, so an invalid input returns the decoding error.
+///
+/// With the `log` feature a `warn!` record lists the positions of the removed characters.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn normalize(data: impl AsRef<str>) -> Result<String> {
+    let data = data.as_ref();
+    let stripped: String = data.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    #[cfg(feature = "log")]
+    if stripped.len() != data.len() {
+        let positions: Vec<usize> = data
+            .char_indices()
+            .filter(|(_, c)| c.is_ascii_whitespace())
+            .map(|(i, _)| i)
+            .collect();
+        log::warn!(
+            "base58 input normalized, removed {} whitespace characters at positions {:?}",
+            positions.len(),
+            positions
+        );
+    }
+    encode(&decode(&stripped)?)
+}
+
+/// Decode base58-encoded string into a byte vector without aborting on allocation failure
+///
+/// The output is allocated with [`Vec::try_reserve_exact`], an allocation failure returns
+/// [`Error::OutOfMemory`] instead of aborting the process.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn try_decode(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    let data = data.as_ref();
+    // Upper bound, the last block decodes to at most a full block
+    let len = data.len().div_ceil(FULL_ENCODED_BLOCK_SIZE) * FULL_BLOCK_SIZE;
+    let mut res = Vec::new();
+    res.try_reserve_exact(len).map_err(|_| Error::OutOfMemory)?;
+    for chunk in data.as_bytes().chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block(chunk)?;
+        res.extend_from_slice(&block.data[FULL_BLOCK_SIZE - block.size..]);
+    }
+    Ok(res)
+}
+
+/// Decode base58-encoded stream in a byte stream
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn decode_stream<T>(data: T) -> impl FusedStream<Item = Result<u8>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        let blocks = decode_stream_blocks(data);
+        pin_mut!(blocks);
+
+        while let Some(block) = blocks.next().await {
+            for c in block?.as_bytes() {
+                yield *c;
+            }
+        }
+    })
+}
+
+/// Decode an owned base58-encoded stream in a `'static` byte stream
+///
+/// Same as [`decode_stream`] with the reader taken by value, the returned stream is `Send` and
+/// `'static` and can be spawned on a task, e.g. with `tokio::spawn`.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn decode_stream_owned<T>(
+    data: T,
+) -> impl FusedStream<Item = Result<u8>> + Unpin + Send + 'static
+where
+    T: AsyncRead + Send + 'static,
+{
+    decode_stream(data)
+}
+
+/// Decode base58-encoded stream in a stream of decoded blocks
+///
+/// Each item is a whole block of 8 bytes, except the last one, for consumers processing
+/// block-sized chunks rather than individual bytes.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn decode_stream_blocks<T>(data: T) -> impl FusedStream<Item = Result<DecodedBlock>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(decode_stream_blocks_with(data, |e, _, _, _| e))
+}
+
+// Decode base58-encoded stream in a stream of decoded blocks, `locate` converts a decoding error
+// given the input from the start of the failed block, its offset and the length read so far
+#[cfg(feature = "stream")]
+fn decode_stream_blocks_with<T, E, F>(
+    data: T,
+    mut locate: F,
+) -> impl FusedStream<Item = core::result::Result<DecodedBlock, E>>
+where
+    T: AsyncRead,
+    E: From<io::Error>,
+    F: FnMut(Error, &[u8], usize, usize) -> E,
+{
+    try_stream! {
+        pin_mut!(data);
+        let mut trace = StreamTrace::new("decode_stream");
+        let mut clen = 0;
+        // Offset of the current block in the input
+        let mut offset = 0;
+        let mut buf = [0; FULL_ENCODED_BLOCK_SIZE];
+
+        loop {
+            let len = data.read(&mut buf[clen..]).await?;
+            clen += len;
+
+            if len == 0 {
+                // EOF reached
+                if clen > 0 {
+                    trace.block(clen);
+                    let block = &buf[..clen];
+                    yield decode_block(block).map_err(|e| {
+                        let e = trace.decode_error(e, block, offset, offset + clen);
+                        locate(e, block, offset, offset + clen)
+                    })?;
+                }
+                trace.finish();
+                break;
+            }
+
+            if clen == FULL_ENCODED_BLOCK_SIZE {
+                trace.block(clen);
+                yield decode_block(&buf).map_err(|e| {
+                    let e = trace.decode_error(e, &buf, offset, offset + clen);
+                    locate(e, &buf, offset, offset + clen)
+                })?;
+                offset += clen;
+                clen = 0;
+            }
+        }
+    }
+}
+
+/// Decode base58-encoded stream in a byte stream, locating decoding errors in the input
+///
+/// Same as [`decode_stream`] with a [`DecodeError`] giving the position of the failure, as
+/// [`decode_verbose`] does, IO errors are returned as [`StreamDecodeError::Io`].
+///
+/// ```rust
+/// # #[tokio::main]
+/// # async fn main() {
+/// use base58_monero::{decode_stream_verbose, DecodeError, StreamDecodeError};
+/// use futures_util::stream::StreamExt;
+///
+/// let mut stream = decode_stream_verbose(&b"D7LMlYjUbXc1fS9Z"[..]);
+/// assert_eq!(
+///     Some(Err(StreamDecodeError::Decode(DecodeError::InvalidSymbol {
+///         offset: 4,
+///         symbol: 'l'
+///     }))),
+///     stream.next().await
+/// );
+/// assert_eq!(None, stream.next().await);
+/// # }
+/// ```
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn decode_stream_verbose<T>(
+    data: T,
+) -> impl FusedStream<Item = core::result::Result<u8, StreamDecodeError>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        let blocks = decode_stream_blocks_with(data, |e, rest, offset, len| {
+            StreamDecodeError::from(DecodeError::locate(&e, rest, offset, len))
+        });
+        pin_mut!(blocks);
+
+        while let Some(block) = blocks.next().await {
+            for c in block?.as_bytes() {
+                yield *c;
+            }
+        }
+    })
+}
+
+/// Decode base58-encoded stream in a stream of byte vectors
+///
+/// The whole blocks read from the input are decoded together and yielded as one vector, of at most
+/// 1024 blocks, the trailing partial block is decoded at the end of the input. Concatenating the
+/// items gives the same bytes as [`decode`], with the per-item overhead of the stream amortized
+/// over many blocks. The stream ends after the first error.
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn decode_stream_chunks<T>(data: T) -> impl FusedStream<Item = Result<Vec<u8>>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut trace = StreamTrace::new("decode_stream");
+        let mut clen = 0;
+        // Offset of the buffer in the input
+        let mut offset = 0;
+        let mut buf = vec![0; STREAM_CHUNK_BLOCKS * FULL_ENCODED_BLOCK_SIZE];
+
+        loop {
+            let len = data.read(&mut buf[clen..]).await?;
+            clen += len;
+
+            // Whole blocks are decoded as soon as read, the trailing partial block at EOF
+            let end = if len == 0 {
+                clen
+            } else {
+                clen - clen % FULL_ENCODED_BLOCK_SIZE
+            };
+            if end > 0 {
+                let mut chunk = Vec::with_capacity(end / FULL_ENCODED_BLOCK_SIZE * FULL_BLOCK_SIZE + FULL_BLOCK_SIZE);
+                for block in buf[..end].chunks(FULL_ENCODED_BLOCK_SIZE) {
+                    trace.block(block.len());
+                }
+                decode_append(&buf[..end], &mut chunk).map_err(|e| {
+                    // Only the error path looks for the failed block
+                    let failed = buf[..end]
+                        .chunks(FULL_ENCODED_BLOCK_SIZE)
+                        .position(|block| decode_block(block).is_err())
+                        .unwrap_or(0)
+                        * FULL_ENCODED_BLOCK_SIZE;
+                    trace.decode_error(e, &buf[failed..end], offset + failed, offset + end)
+                })?;
+                buf.copy_within(end..clen, 0);
+                clen -= end;
+                offset += end;
+                yield chunk;
+            }
+
+            if len == 0 {
+                trace.finish();
+                break;
+            }
+        }
+    })
+}
+
+/// Input size in bytes from which the `*_blocking` functions offload the work to a blocking thread
+///
+/// Encoding or decoding 64 KiB takes in the order of a millisecond, smaller inputs are processed
+/// inline as the cost of dispatching to the blocking pool would dominate.
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub const BLOCKING_THRESHOLD: usize = 64 * 1024;
+
+// Run `f` inline for inputs under `BLOCKING_THRESHOLD`, on the blocking thread pool otherwise
+#[cfg(feature = "blocking")]
+async fn offload<T, F>(len: usize, f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    if len < BLOCKING_THRESHOLD {
+        return f();
+    }
+    match tokio::task::spawn_blocking(f).await {
+        Ok(res) => res,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => panic!("blocking task failed: {}", e),
+    }
+}
+
+/// Encode a byte vector into a base58-encoded string without blocking the async runtime
+///
+/// Inputs of [`BLOCKING_THRESHOLD`] bytes or more are encoded on the `tokio` blocking thread pool
+/// with `spawn_blocking`, smaller inputs are encoded inline. Must be called within a `tokio`
+/// runtime.
+///
+/// # Panics
+///
+/// Panics if the runtime shuts down before the blocking task completes.
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub async fn encode_blocking<D>(data: D) -> Result<String>
+where
+    D: AsRef<[u8]> + Send + 'static,
+{
+    offload(data.as_ref().len(), move || encode(data)).await
+}
+
+/// Decode base58-encoded string into a byte vector without blocking the async runtime
+///
+/// Inputs of [`BLOCKING_THRESHOLD`] characters or more are decoded on the `tokio` blocking thread
+/// pool with `spawn_blocking`, smaller inputs are decoded inline. Must be called within a `tokio`
+/// runtime.
+///
+/// # Panics
+///
+/// Panics if the runtime shuts down before the blocking task completes.
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub async fn decode_blocking<D>(data: D) -> Result<Vec<u8>>
+where
+    D: AsRef<str> + Send + 'static,
+{
+    offload(data.as_ref().len(), move || decode(data)).await
+}
+
+/// Encode a byte vector with a 4 bytes checksum without blocking the async runtime
+///
+/// See [`encode_blocking`] for when the work is offloaded.
+///
+/// # Panics
+///
+/// Panics if the runtime shuts down before the blocking task completes.
+#[cfg(all(feature = "blocking", feature = "check"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "blocking", feature = "check"))))]
+pub async fn encode_check_blocking<D>(data: D) -> Result<String>
+where
+    D: AsRef<[u8]> + Send + 'static,
+{
+    offload(data.as_ref().len(), move || encode_check(data)).await
+}
+
+/// Decode base58-encoded with 4 bytes checksum string without blocking the async runtime
+///
+/// See [`decode_blocking`] for when the work is offloaded.
+///
+/// # Panics
+///
+/// Panics if the runtime shuts down before the blocking task completes.
+#[cfg(all(feature = "blocking", feature = "check"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "blocking", feature = "check"))))]
+pub async fn decode_check_blocking<D>(data: D) -> Result<Vec<u8>>
+where
+    D: AsRef<str> + Send + 'static,
+{
+    offload(data.as_ref().len(), move || decode_check(data)).await
+}
+
+// Number of blocks decoded by each task of `decode_stream_par`
+#[cfg(feature = "parallel-stream")]
+const PAR_BATCH_BLOCKS: usize = 4096;
+
+/// Decode base58-encoded stream with batches of blocks decoded in parallel tasks
+///
+/// The input is read in batches of 4096 blocks, each batch is decoded in a task spawned on the
+/// current `tokio` runtime with at most `parallelism` batches decoded concurrently. Decoded batches
+/// are yielded in input order. A `parallelism` of `0` is treated as `1`. The stream ends after the
+/// first error, the batches following it are not yielded.
+///
+/// The stream must be polled within a `tokio` runtime, batches are only decoded in parallel on a
+/// multi-threaded runtime.
+#[cfg(feature = "parallel-stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel-stream")))]
+pub fn decode_stream_par<T>(
+    data: T,
+    parallelism: usize,
+) -> impl FusedStream<Item = Result<Vec<u8>>> + Unpin
+where
+    T: AsyncRead,
+{
+    const BATCH_SIZE: usize = PAR_BATCH_BLOCKS * FULL_ENCODED_BLOCK_SIZE;
+
+    let batches = Box::pin(try_stream! {
+        pin_mut!(data);
+        loop {
+            let mut batch = vec![0; BATCH_SIZE];
+            let mut len = 0;
+            while len < BATCH_SIZE {
+                match data.read(&mut batch[len..]).await? {
+                    0 => break,
+                    n => len += n,
+                }
+            }
+            batch.truncate(len);
+            if len > 0 {
+                yield batch;
+            }
+            if len < BATCH_SIZE {
+                break;
+            }
+        }
+    });
+
+    batches
+        .map(|batch: Result<Vec<u8>>| async move {
+            match tokio::spawn(async move { decode_bytes(batch?) }).await {
+                Ok(res) => res,
+                Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                // The runtime is shutting down
+                Err(e) => Err(Error::Io(io::Error::other(e))),
+            }
+        })
+        .buffered(parallelism.max(1))
+        // End the stream after the first error, the batches buffered after it are dropped
+        .scan(false, |failed, res| {
+            if *failed {
+                return core::future::ready(None);
+            }
+            *failed = res.is_err();
+            core::future::ready(Some(res))
+        })
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into a byte vector
+///
+/// Input decoding to less bytes than the checksum is rejected with [`Error::InvalidChecksum`].
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    let data = data.as_ref().as_bytes();
+    observe(decode_check_unobserved(data), true, data.len())
+}
+
+// Decode base58-encoded with 4 bytes checksum ASCII bytes without reporting the failures to the
+// `telemetry` module, e.g. to probe the format of the input
+#[cfg(all(feature = "check", feature = "alloc"))]
+fn decode_check_unobserved(data: &[u8]) -> Result<Vec<u8>> {
+    with_scratch(|bytes| {
+        decode_append(data, bytes)?;
+        self_check(bytes, data);
+        let len = bytes.len();
+        if len < CHECKSUM_SIZE {
+            return Err(Error::InvalidChecksum);
+        }
+        let (bytes, check) = (
+            &bytes[..len - CHECKSUM_SIZE],
+            &bytes[len - CHECKSUM_SIZE..len],
+        );
+
+        if checksum(bytes) == *check {
+            Ok(Vec::from(bytes))
+        } else {
+            Err(Error::InvalidChecksum)
+        }
+    })
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into `out`, return the number of bytes
+/// written
+///
+/// Nothing is allocated, the checksum is not written to `out`. Inputs of invalid length return
+/// [`Error::InvalidBlockSize`], or [`Error::InvalidChecksum`] if they decode to less bytes than
+/// the checksum, and buffers shorter than the payload return [`Error::BufferTooSmall`], all before
+/// any block is decoded. The content of `out` is unspecified on error.
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub fn decode_check_into(data: impl AsRef<str>, out: &mut [u8]) -> Result<usize> {
+    let data = data.as_ref().as_bytes();
+    let res = decoded_len(data.len())
+        .ok_or(Error::InvalidBlockSize)
+        .and_then(|len| {
+            let len = len
+                .checked_sub(CHECKSUM_SIZE)
+                .ok_or(Error::InvalidChecksum)?;
+            let out = out.get_mut(..len).ok_or(Error::BufferTooSmall)?;
+            let mut check = [0u8; CHECKSUM_SIZE];
+            decode_blocks_into(data, out, &mut check)?;
+            if checksum(out) == check[..] {
+                Ok(len)
+            } else {
+                Err(Error::InvalidChecksum)
+            }
+        });
+    observe(res, true, data.len())
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into an array of `N` bytes, return the
+/// array and the number of decoded bytes
+///
+/// Nothing is allocated, the checksum is not written to the array. Inputs whose payload exceeds
+/// `N` bytes return [`Error::BufferTooSmall`] before any block is decoded.
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub fn decode_check_to_array<const N: usize>(data: impl AsRef<str>) -> Result<([u8; N], usize)> {
+    let mut res = [0u8; N];
+    let len = decode_check_into(data, &mut res)?;
+    Ok((res, len))
+}
+
+/// Decode base58-encoded with 4 bytes checksum string into a `heapless::Vec` of at most `N` bytes
+///
+/// Inputs whose payload exceeds `N` bytes return [`Error::BufferTooSmall`] before any block is
+/// decoded.
+#[cfg(all(feature = "check", feature = "heapless"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "heapless"))))]
+pub fn decode_check_heapless<const N: usize>(
+    data: impl AsRef<str>,
+) -> Result<heapless::Vec<u8, N>> {
+    let mut res = heapless::Vec::new();
+    // Resizing up to the capacity never fails
+    let _ = res.resize_default(N);
+    let len = decode_check_into(data, &mut res)?;
+    res.truncate(len);
+    Ok(res)
+}
+
+/// Payload of a base58-check string decoded by [`decode_check_lossy`], kept even when the
+/// checksum does not match
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LossyCheck {
+    /// Decoded bytes preceding the checksum
+    pub payload: Vec<u8>,
+    /// Trailing 4 bytes checksum, as found in the string
+    pub found: Checksum,
+    /// Checksum of the payload
+    pub expected: Checksum,
+}
+
+#[cfg(all(feature = "check", feature = "alloc"))]
+impl LossyCheck {
+    /// Return true if the checksum found in the string matches the payload
+    pub fn is_valid(&self) -> bool {
+        self.found == self.expected
+    }
+
+    /// Return the payload if the checksum matches, as [`decode_check`] would
+    pub fn into_checked(self) -> Result<Vec<u8>> {
+        if self.is_valid() {
+            Ok(self.payload)
+        } else {
+            Err(Error::InvalidChecksum)
+        }
+    }
+}
+
+/// Decode base58-encoded with 4 bytes checksum string, returns the payload even if the checksum
+/// does not match
+///
+/// Works as [`decode_check`] but a checksum mismatch is reported in the returned [`LossyCheck`]
+/// with the decoded payload, e.g. to check whether the prefix of a mistyped address is at least
+/// right. Invalid blocks are still errors and input decoding to less bytes than the checksum is
+/// rejected with [`Error::InvalidChecksum`].
+///
+/// ```rust
+/// use base58_monero::decode_check_lossy;
+///
+/// let decoded = decode_check_lossy("D7LMXYjUbXc5LVkq6vWDZ")?;
+/// assert!(!decoded.is_valid());
+/// assert_eq!(b"Hello World", &decoded.payload[..]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check_lossy(data: impl AsRef<str>) -> Result<LossyCheck> {
+    let mut payload = decode(data)?;
+    let len = payload.len();
+    if len < CHECKSUM_SIZE {
+        return Err(Error::InvalidChecksum);
+    }
+    let mut found = [0u8; CHECKSUM_SIZE];
+    found.copy_from_slice(&payload[len - CHECKSUM_SIZE..]);
+    payload.truncate(len - CHECKSUM_SIZE);
+    Ok(LossyCheck {
+        expected: checksum(&payload),
+        found: Checksum::from(found),
+        payload,
+    })
+}
+
+// Decode a base58-check string with the digits table of `Alphabet::MONERO`, `buf` and the
+// hasher state of an empty payload are reused between calls
+#[cfg(all(feature = "check", feature = "alloc"))]
+fn decode_check_reusing(data: &[u8], buf: &mut Vec<u8>, hasher: &Keccak) -> Result<Vec<u8>> {
+    buf.clear();
+    for block in data.chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block_with(block, |c| Alphabet::MONERO.digit(c).map(usize::from))?;
+        buf.extend_from_slice(block.as_bytes());
+    }
+    if buf.len() < CHECKSUM_SIZE {
+        return Err(Error::InvalidChecksum);
+    }
+    let len = buf.len() - CHECKSUM_SIZE;
+    let mut hasher = hasher.clone();
+    let mut hash = [0u8; 32];
+    hasher.update(&buf[..len]);
+    hasher.finalize(&mut hash);
+    if Checksum::from_hash(&hash) != buf[len..] {
+        return Err(Error::InvalidChecksum);
+    }
+    Ok(Vec::from(&buf[..len]))
+}
+
+/// Decode a batch of base58-check strings, returns the result of each string in input order
+///
+/// Works as [`decode_check`] on each string with the decoding buffer and the hasher state shared
+/// by the whole batch, cutting the per-call overhead when verifying many short strings.
+///
+/// ```rust
+/// use base58_monero::{decode_check_many, Error};
+///
+/// let res = decode_check_many(&["D7LMXYjUbXc5LVkq6vWDY", "D7LMXYjUbXc1fS9Z"]);
+/// assert_eq!(Ok(Vec::from(&b"Hello World"[..])), res[0]);
+/// assert_eq!(Err(Error::InvalidChecksum), res[1]);
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check_many<S: AsRef<str>>(data: &[S]) -> Vec<Result<Vec<u8>>> {
+    let hasher = Keccak::v256();
+    let mut buf = Vec::new();
+    data.iter()
+        .map(|s| {
+            let s = s.as_ref().as_bytes();
+            observe(decode_check_reusing(s, &mut buf, &hasher), true, s.len())
+        })
+        .collect()
+}
+
+/// Decode a batch of base58-check strings in parallel, returns the result of each string in input
+/// order
+///
+/// Works as [`decode_check_many`] with the strings split across the rayon global thread pool, each
+/// thread reuses its own decoding buffer and hasher state.
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn decode_check_many_par<S: AsRef<str> + Sync>(data: &[S]) -> Vec<Result<Vec<u8>>> {
+    use rayon::prelude::*;
+
+    let hasher = Keccak::v256();
+    data.par_iter()
+        .map_init(Vec::new, |buf, s| {
+            let s = s.as_ref().as_bytes();
+            observe(decode_check_reusing(s, buf, &hasher), true, s.len())
+        })
+        .collect()
+}
+
+// Number of blocks encoded or decoded by each task of the `_par` functions
+#[cfg(feature = "parallel")]
+const PAR_CHUNK_BLOCKS: usize = 1024;
+
+// Encode the blocks of `data` into `out` on the rayon global thread pool, `out` must be exactly
+// the encoded length of `data`
+#[cfg(feature = "parallel")]
+fn encode_blocks_par(data: &[u8], out: &mut [u8]) -> Result<()> {
+    use rayon::prelude::*;
+
+    out.par_chunks_mut(PAR_CHUNK_BLOCKS * FULL_ENCODED_BLOCK_SIZE)
+        .zip(data.par_chunks(PAR_CHUNK_BLOCKS * FULL_BLOCK_SIZE))
+        .try_for_each(|(out, data)| encode_blocks_into(data, out, 0).map(|_| ()))
+}
+
+// Decode the blocks of `data` into `out` on the rayon global thread pool, `out` must be exactly
+// the decoded length of `data`. Returns the error of the first invalid chunk, as the sequential
+// decoding would.
+#[cfg(feature = "parallel")]
+fn decode_blocks_par(data: &[u8], out: &mut [u8]) -> Result<()> {
+    use rayon::prelude::*;
+
+    let res: Vec<Result<()>> = out
+        .par_chunks_mut(PAR_CHUNK_BLOCKS * FULL_BLOCK_SIZE)
+        .zip(data.par_chunks(PAR_CHUNK_BLOCKS * FULL_ENCODED_BLOCK_SIZE))
+        .map(|(out, data)| decode_blocks_into(data, out, &mut []))
+        .collect();
+    res.into_iter().collect()
+}
+
+// Symbols written by `encode_blocks_par` are ASCII
+#[cfg(feature = "parallel")]
+fn into_string(encoded: Vec<u8>) -> String {
+    match String::from_utf8(encoded) {
+        Ok(encoded) => encoded,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Encode a byte vector into a base58-encoded string in parallel
+///
+/// The blocks are encoded by chunks of 8 KiB on the rayon global thread pool, into a string
+/// allocated once. Produces the same string as [`encode`], worth it for inputs of hundreds of
+/// kilobytes or more.
+///
+/// ```rust
+/// use base58_monero::{encode, encode_par};
+///
+/// let data = vec![0xa5u8; 1 << 20];
+/// assert_eq!(encode(&data)?, encode_par(&data)?);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn encode_par(data: impl AsRef<[u8]>) -> Result<String> {
+    let data = data.as_ref();
+    let mut res = vec![0u8; encoded_len(data.len())];
+    encode_blocks_par(data, &mut res)?;
+    Ok(into_string(res))
+}
+
+/// Decode base58-encoded string in parallel
+///
+/// The blocks are decoded by chunks of 1024 blocks on the rayon global thread pool, into a vector
+/// allocated once. Works as [`decode_into`]: inputs of invalid length return
+/// [`Error::InvalidBlockSize`] before any block is decoded, otherwise the error of the first
+/// invalid block is returned.
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn decode_par(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    let data = data.as_ref().as_bytes();
+    let res = decoded_len(data.len())
+        .ok_or(Error::InvalidBlockSize)
+        .and_then(|len| {
+            let mut res = vec![0u8; len];
+            decode_blocks_par(data, &mut res)?;
+            Ok(res)
+        });
+    observe(res, false, data.len())
+}
+
+/// Encode a byte vector into a base58-check string in parallel, adds 4 bytes checksum
+///
+/// The Keccak checksum cannot be split, it is computed on the calling thread while the full blocks
+/// of the data are encoded on the rayon global thread pool, see [`encode_par`].
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn encode_check_par(data: impl AsRef<[u8]>) -> Result<String> {
+    let data = data.as_ref();
+    let mut res = vec![0u8; encoded_len(data.len() + CHECKSUM_SIZE)];
+    let full = data.len() - data.len() % FULL_BLOCK_SIZE;
+    let (head, tail_out) = res.split_at_mut(full / FULL_BLOCK_SIZE * FULL_ENCODED_BLOCK_SIZE);
+    let (checksum, encoded) =
+        rayon::join(|| checksum(data), || encode_blocks_par(&data[..full], head));
+    encoded?;
+    let (_, tail, tail_len) = split_check_tail(data, &checksum);
+    encode_blocks_into(&tail[..tail_len], tail_out, 0)?;
+    Ok(into_string(res))
+}
+
+/// Decode base58-encoded with 4 bytes checksum string in parallel
+///
+/// The blocks are decoded on the rayon global thread pool as with [`decode_par`], then the
+/// checksum of the decoded bytes is verified on the calling thread.
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub fn decode_check_par(data: impl AsRef<str>) -> Result<Vec<u8>> {
+    let data = data.as_ref().as_bytes();
+    let res = decoded_len(data.len())
+        .ok_or(Error::InvalidBlockSize)
+        .and_then(|len| {
+            let len = len
+                .checked_sub(CHECKSUM_SIZE)
+                .ok_or(Error::InvalidChecksum)?;
+            let mut res = vec![0u8; len + CHECKSUM_SIZE];
+            decode_blocks_par(data, &mut res)?;
+            let (payload, check) = res.split_at(len);
+            if checksum(payload) == *check {
+                res.truncate(len);
+                Ok(res)
+            } else {
+                Err(Error::InvalidChecksum)
+            }
+        });
+    observe(res, true, data.len())
+}
+
+/// Encode a byte vector into a base58-check string with the checksum computed by `checksum`
+///
+/// `checksum` is the state of an empty payload, its [`size`](ChecksumAlgorithm::size) bytes tail
+/// is appended to the data before encoding. With [`MoneroChecksum`] this is [`encode_check`].
+///
+/// ```rust
+/// use base58_monero::base58::MoneroChecksum;
+/// use base58_monero::{decode_check_with, encode_check, encode_check_with};
+///
+/// let encoded = encode_check_with(b"Hello World", MoneroChecksum::new())?;
+/// assert_eq!(encode_check(b"Hello World")?, encoded);
+/// assert_eq!(b"Hello World", &decode_check_with(&encoded, MoneroChecksum::new())?[..]);
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn encode_check_with(
+    data: impl AsRef<[u8]>,
+    mut checksum: impl ChecksumAlgorithm,
+) -> Result<String> {
+    let data = data.as_ref();
+    checksum.update(data);
+    let checksum = checksum.finalize();
+    // The checksum is appended to the trailing partial block of the data
+    let (full, rest) = data.split_at(data.len() - data.len() % FULL_BLOCK_SIZE);
+    let mut tail = Vec::with_capacity(rest.len() + checksum.len());
+    tail.extend_from_slice(rest);
+    tail.extend_from_slice(&checksum);
+    let mut res = String::with_capacity(encoded_len(data.len() + checksum.len()));
+    push_encoded_blocks(full, &mut res)?;
+    push_encoded_blocks(&tail, &mut res)?;
+    Ok(res)
+}
+
+/// Decode a base58-check string with the checksum verified by `checksum` into a byte vector
+///
+/// `checksum` is the state of an empty payload, the last [`size`](ChecksumAlgorithm::size)
+/// decoded bytes are verified with [`verify`](ChecksumAlgorithm::verify) and stripped. Inputs
+/// decoding to less bytes than the checksum return [`Error::InvalidChecksum`]. With
+/// [`MoneroChecksum`] this is [`decode_check`].
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check_with(
+    data: impl AsRef<str>,
+    mut checksum: impl ChecksumAlgorithm,
+) -> Result<Vec<u8>> {
+    let data = data.as_ref().as_bytes();
+    let mut bytes = Vec::new();
+    let res = decode_append(data, &mut bytes).and_then(|()| {
+        let len = bytes
+            .len()
+            .checked_sub(checksum.size())
+            .ok_or(Error::InvalidChecksum)?;
+        checksum.update(&bytes[..len]);
+        if !checksum.verify(&bytes[len..]) {
+            return Err(Error::InvalidChecksum);
+        }
+        bytes.truncate(len);
+        Ok(bytes)
+    });
+    observe(res, true, data.len())
+}
+
+/// Encode a byte vector into a base58-check string with a keyed checksum
+///
+/// The 4 bytes tail is computed with [`KeyedChecksum`] under `key` instead of the public Monero
+/// checksum, decode it with [`decode_check_keyed`] and the same key.
+///
+/// ```rust
+/// use base58_monero::{decode_check, decode_check_keyed, encode_check_keyed, Error};
+///
+/// let token = encode_check_keyed(b"user:42", b"secret key")?;
+/// assert_eq!(b"user:42", &decode_check_keyed(&token, b"secret key")?[..]);
+/// assert_eq!(Err(Error::InvalidChecksum), decode_check_keyed(&token, b"other key"));
+/// assert_eq!(Err(Error::InvalidChecksum), decode_check(&token));
+/// # Ok::<(), Error>(())
+/// ```
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn encode_check_keyed(data: impl AsRef<[u8]>, key: &[u8]) -> Result<String> {
+    encode_check_with(data, KeyedChecksum::new(key))
+}
+
+/// Decode a base58-check string with a keyed checksum into a byte vector
+///
+/// The tag is compared in constant time, a string encoded under another key or with the public
+/// Monero checksum is rejected with [`Error::InvalidChecksum`].
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub fn decode_check_keyed(data: impl AsRef<str>, key: &[u8]) -> Result<Vec<u8>> {
+    decode_check_with(data, KeyedChecksum::new(key))
+}
+
+/// Decode base58-encoded stream with a 4 bytes checksum in a decoded byte stream
+///
+/// Input decoding to less bytes than the checksum ends the stream with [`Error::InvalidChecksum`].
+#[cfg(all(feature = "check", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+pub fn decode_stream_check<T>(data: T) -> impl FusedStream<Item = Result<u8>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(decode_stream_check_with(decode_stream(data)))
+}
+
+/// Decode base58-encoded stream with a 4 bytes checksum in a decoded byte stream, locating
+/// decoding errors in the input
+///
+/// Same as [`decode_stream_check`] with the errors of [`decode_stream_verbose`], the checksum
+/// error has no position.
+#[cfg(all(feature = "check", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+pub fn decode_stream_check_verbose<T>(
+    data: T,
+) -> impl FusedStream<Item = core::result::Result<u8, StreamDecodeError>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(decode_stream_check_with(decode_stream_verbose(data)))
+}
+
+// Verify and strip the checksum of a decoded byte stream, the last bytes are held back until the
+// end of the input
+#[cfg(all(feature = "check", feature = "stream"))]
+fn decode_stream_check_with<S, E>(data: S) -> impl FusedStream<Item = core::result::Result<u8, E>>
+where
+    S: Stream<Item = core::result::Result<u8, E>>,
+    E: From<DecodeError>,
+{
+    try_stream! {
+        let trace = StreamTrace::new("decode_stream_check");
+        let len = CHECKSUM_SIZE + 1;
+        let mut clen = 0;
+        let mut check = [0; CHECKSUM_SIZE];
+        let mut buf = [0; CHECKSUM_SIZE + 1];
+
+        let mut checksum = [0u8; 32];
+        let mut hasher = Keccak::v256();
+
+        pin_mut!(data);
+
+        while let Some(value) = data.next().await {
+            buf[clen % len] = value?;
+            if clen >= CHECKSUM_SIZE {
+                check[0] = buf[(clen - CHECKSUM_SIZE) % len];
+                hasher.update(&check[0..1]);
+                yield check[0];
+            }
+            clen += 1;
+        }
+
+        // Input shorter than the checksum
+        if clen < CHECKSUM_SIZE {
+            trace.checksum(false);
+            Err(E::from(DecodeError::InvalidChecksum))?;
+        }
+
+        hasher.finalize(&mut checksum);
+        for i in 0..CHECKSUM_SIZE {
+            check[i] = buf[(clen - CHECKSUM_SIZE + i) % len];
+        }
+
+        let valid = Checksum::from_hash(&checksum) == check[..];
+        trace.checksum(valid);
+        if !valid {
+            Err(E::from(DecodeError::InvalidChecksum))?;
+        }
+    }
+}
+
+// Reader implementing the `tokio` read trait over a `futures` reader, the `tokio` read trait does
+// not depend on the `tokio` runtime
+#[cfg(feature = "stream-futures")]
+struct FuturesRead<R>(R);
+
+#[cfg(feature = "stream-futures")]
+impl<R: futures_util::io::AsyncRead + Unpin> AsyncRead for FuturesRead<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let len = ready!(Pin::new(&mut self.0).poll_read(cx, buf.initialize_unfilled()))?;
+        buf.advance(len);
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Encode a `futures` byte stream in a base58 stream of characters, see [`encode_stream`]
+///
+/// The reader implements the `futures` read trait instead of the `tokio` one, for async-std, smol
+/// or any other runtime. Pin readers that are not `Unpin` with `Box::pin` or `pin_mut!`.
+///
+/// ```rust
+/// use futures_util::stream::TryStreamExt;
+/// use base58_monero::encode_stream_futures;
+///
+/// # tokio_test::block_on(async {
+/// let s: String = encode_stream_futures(&b"Hello World"[..]).try_collect().await?;
+/// assert_eq!("D7LMXYjUbXc1fS9Z", s);
+/// # Ok::<(), base58_monero::Error>(())
+/// # })?;
+/// # Ok::<(), base58_monero::Error>(())
+/// ```
+#[cfg(feature = "stream-futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream-futures")))]
+pub fn encode_stream_futures<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: futures_util::io::AsyncRead + Unpin,
+{
+    encode_stream(FuturesRead(data))
+}
+
+/// Decode a `futures` base58-encoded stream in a byte stream, see [`decode_stream`]
+///
+/// The reader implements the `futures` read trait instead of the `tokio` one, see
+/// [`encode_stream_futures`].
+#[cfg(feature = "stream-futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream-futures")))]
+pub fn decode_stream_futures<T>(data: T) -> impl FusedStream<Item = Result<u8>> + Unpin
+where
+    T: futures_util::io::AsyncRead + Unpin,
+{
+    decode_stream(FuturesRead(data))
+}
+
+/// Encode a `futures` byte stream in a base58 stream of characters with a 4 bytes checksum, see
+/// [`encode_stream_check`]
+///
+/// The reader implements the `futures` read trait instead of the `tokio` one, see
+/// [`encode_stream_futures`].
+#[cfg(all(feature = "check", feature = "stream-futures"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream-futures"))))]
+pub fn encode_stream_check_futures<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: futures_util::io::AsyncRead + Unpin,
+{
+    encode_stream_check(FuturesRead(data))
+}
+
+/// Decode a `futures` base58-encoded stream with a 4 bytes checksum in a byte stream, see
+/// [`decode_stream_check`]
+///
+/// The reader implements the `futures` read trait instead of the `tokio` one, see
+/// [`encode_stream_futures`].
+#[cfg(all(feature = "check", feature = "stream-futures"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream-futures"))))]
+pub fn decode_stream_check_futures<T>(data: T) -> impl FusedStream<Item = Result<u8>> + Unpin
+where
+    T: futures_util::io::AsyncRead + Unpin,
+{
+    decode_stream_check(FuturesRead(data))
+}
+
+// Decode an async reader with a 4 bytes checksum, writing the payload to `writer` if any, returns
+// `Error::InvalidChecksum` at EOF if the checksum does not match
+#[cfg(all(feature = "check", feature = "stream"))]
+async fn decode_stream_check_pass<T, W>(data: T, mut writer: Option<&mut W>) -> Result<()>
+where
+    T: AsyncRead,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let mut hasher = Keccak::v256();
+    // Last decoded bytes are held until EOF as they may be the checksum
+    let mut held = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+    let mut held_len = 0;
+    let blocks = decode_stream_blocks(data);
+    pin_mut!(blocks);
+    while let Some(block) = blocks.next().await {
+        let bytes = block?;
+        let bytes = bytes.as_bytes();
+        held[held_len..held_len + bytes.len()].copy_from_slice(bytes);
+        held_len += bytes.len();
+        if held_len > CHECKSUM_SIZE {
+            let len = held_len - CHECKSUM_SIZE;
+            hasher.update(&held[..len]);
+            if let Some(writer) = writer.as_mut() {
+                writer.write_all(&held[..len]).await?;
+            }
+            held.copy_within(len..held_len, 0);
+            held_len = CHECKSUM_SIZE;
+        }
+    }
+
+    let mut checksum = [0u8; 32];
+    hasher.finalize(&mut checksum);
+    if held_len < CHECKSUM_SIZE || Checksum::from_hash(&checksum) != held[..CHECKSUM_SIZE] {
+        return Err(Error::InvalidChecksum);
+    }
+    Ok(())
+}
+
+/// Decode a seekable base58-encoded stream with a 4 bytes checksum, verifying the checksum before
+/// writing any byte
+///
+/// Async version of [`decode_check_seekable`]: the reader is read once to verify the checksum,
+/// rewound to its initial position and read again to write the payload, in constant memory. The
+/// writer is flushed once the second pass is verified.
+///
+/// ```rust
+/// # tokio_test::block_on(async {
+/// use base58_monero::decode_stream_check_seekable;
+/// use std::io::Cursor;
+///
+/// let mut out = Vec::new();
+/// decode_stream_check_seekable(Cursor::new(b"D7LMXYjUbXc5LVkq6vWDY"), &mut out).await?;
+/// assert_eq!(b"Hello World", &out[..]);
+///
+/// let mut out = Vec::new();
+/// assert!(decode_stream_check_seekable(Cursor::new(b"D7LMXYjUbXc1fS9Z"), &mut out).await.is_err());
+/// assert!(out.is_empty());
+/// # Ok::<(), base58_monero::Error>(())
+/// # });
+/// ```
+#[cfg(all(feature = "check", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+pub async fn decode_stream_check_seekable<T, W>(mut data: T, mut writer: W) -> Result<()>
+where
+    T: AsyncRead + tokio::io::AsyncSeek + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+    let start = data.stream_position().await?;
+    decode_stream_check_pass(&mut data, None::<&mut W>).await?;
+    data.seek(std::io::SeekFrom::Start(start)).await?;
+    decode_stream_check_pass(&mut data, Some(&mut writer)).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+// Append `bytes` to the pending block `buf[..*len]`, pushing the symbols of each completed block
+#[cfg(all(feature = "check", feature = "stream"))]
+fn push_encoded(
+    buf: &mut [u8; FULL_BLOCK_SIZE],
+    len: &mut usize,
+    bytes: &[u8],
+    out: &mut String,
+) -> Result<()> {
+    for &b in bytes {
+        buf[*len] = b;
+        *len += 1;
+        if *len == FULL_BLOCK_SIZE {
+            out.push_str(encode_block_chars(buf)?.as_str());
+            *len = 0;
+        }
+    }
+    Ok(())
+}
+
+/// Verify a base58-check stream and re-encode it with another checksum in one pass
+///
+/// The input checksum is verified with `source` and the payload is re-encoded with the checksum
+/// computed by `target` appended, e.g. to migrate data between checksum schemes. Symbols are
+/// yielded as the input is decoded, if the input checksum is invalid the stream ends with
+/// [`Error::InvalidChecksum`] and the symbols already yielded must be discarded.
+///
+/// ```rust
+/// # #[cfg(all(feature = "check", feature = "stream"))]
+/// # fn main() -> Result<(), base58_monero::Error> {
+/// use base58_monero::base58::MoneroChecksum;
+/// use base58_monero::{transcode_check, Error};
+/// use futures_util::pin_mut;
+/// use futures_util::stream::StreamExt;
+///
+/// # tokio_test::block_on(async {
+/// let s = transcode_check(&b"D7LMXYjUbXc5LVkq6vWDY"[..], MoneroChecksum::new(), MoneroChecksum::new());
+/// pin_mut!(s);
+/// let mut res = String::new();
+/// while let Some(c) = s.next().await {
+///     res.push(c?);
+/// }
+/// assert_eq!("D7LMXYjUbXc5LVkq6vWDY", res);
+/// # Ok::<(), Error>(())
+/// # })
+/// # }
+/// # #[cfg(not(all(feature = "check", feature = "stream")))]
+/// # fn main() {}
+/// ```
+#[cfg(all(feature = "check", feature = "stream"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "stream"))))]
+pub fn transcode_check<T, S, D>(
+    data: T,
+    mut source: S,
+    mut target: D,
+) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: AsyncRead,
+    S: ChecksumAlgorithm,
+    D: ChecksumAlgorithm,
+{
+    Box::pin(try_stream! {
+        let trace = StreamTrace::new("transcode_check");
+        let tail = source.size();
+        // Last decoded bytes are held until EOF as they may be the checksum
+        let mut held = Vec::new();
+        let mut buf = [0u8; FULL_BLOCK_SIZE];
+        let mut len = 0;
+        let mut out = String::new();
+
+        let blocks = decode_stream_blocks(data);
+        pin_mut!(blocks);
+
+        while let Some(block) = blocks.next().await {
+            held.extend_from_slice(block?.as_bytes());
+            if held.len() > tail {
+                let payload = held.len() - tail;
+                source.update(&held[..payload]);
+                target.update(&held[..payload]);
+                push_encoded(&mut buf, &mut len, &held[..payload], &mut out)?;
+                held.drain(..payload);
+                for c in out.drain(..) {
+                    yield c;
+                }
+            }
+        }
+
+        let valid = bool::from(source.finalize().ct_eq(&held));
+        trace.checksum(valid);
+        if !valid {
+            Err(Error::InvalidChecksum)?;
+        }
+
+        push_encoded(&mut buf, &mut len, &target.finalize(), &mut out)?;
+        if len > 0 {
+            out.push_str(encode_block_chars(&buf[..len])?.as_str());
+        }
+        for c in out.drain(..) {
+            yield c;
+        }
+    })
+}
+
+// Model checking harnesses, run with `cargo kani`
+#[cfg(kani)]
+mod proofs {
+    use super::{
+        decode_block, encode_block, BASE58_CHARS, ENCODED_BLOCK_SIZES, FULL_BLOCK_SIZE,
+        FULL_ENCODED_BLOCK_SIZE,
+    };
+
+    // `decode_block` never panics, i.e. it never overflows nor reaches `unreachable!()`, and a
+    // decoded block is always re-encoded to its input
+    #[kani::proof]
+    #[kani::unwind(12)]
+    fn decode_block_is_total() {
+        let data: [u8; FULL_ENCODED_BLOCK_SIZE] = kani::any();
+        let len: usize = kani::any();
+        kani::assume(len <= FULL_ENCODED_BLOCK_SIZE);
+
+        if let Ok(block) = decode_block(&data[..len]) {
+            assert!(block.size <= FULL_BLOCK_SIZE);
+            assert_eq!(len, ENCODED_BLOCK_SIZES[block.size]);
+            // An empty block decodes to nothing
+            if block.size > 0 {
+                let res = encode_block(&block.data[FULL_BLOCK_SIZE - block.size..]).unwrap();
+                assert_eq!(&data[..len], &res[..len]);
+            }
+        }
+    }
+
+    // `encode_block` only outputs base58 symbols and `decode_block` is its inverse for all valid
+    // block sizes
+    #[kani::proof]
+    #[kani::unwind(12)]
+    fn encode_block_roundtrip() {
+        let data: [u8; FULL_BLOCK_SIZE] = kani::any();
+        let len: usize = kani::any();
+        kani::assume(len >= 1 && len <= FULL_BLOCK_SIZE);
+
+        let encoded = encode_block(&data[..len]).unwrap();
+        let size = ENCODED_BLOCK_SIZES[len];
+        assert!(encoded[..size].iter().all(|c| BASE58_CHARS.contains(c)));
+
+        let block = decode_block(&encoded[..size]).unwrap();
+        assert_eq!(len, block.size);
+        assert_eq!(&data[..len], &block.data[FULL_BLOCK_SIZE - len..]);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{
+        decode, decode_block, decode_bounded, decode_bytes, decode_into, decode_split,
+        decode_with_progress, encode, encode_block, encode_into, encode_iter, encode_join,
+        encode_with_progress, normalize, try_decode, try_encode, u8be_to_u64, Alphabet, Encoder,
+        Error, ENCODED_BLOCK_SIZES, FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE,
+    };
+
+    #[cfg(feature = "check")]
+    use super::{decode_check, decode_check_into, encode_check, encode_check_into};
+    #[cfg(feature = "stream")]
+    use super::{decode_stream, encode_stream};
+    #[cfg(all(feature = "check", feature = "stream"))]
+    use super::{decode_stream_check, encode_stream_check};
+
+    #[cfg(feature = "bytes")]
+    use super::decode_buf;
+
+    #[cfg(feature = "stream")]
+    use futures_util::{pin_mut, stream::StreamExt};
+
+    #[test]
+    fn encode_wrong_block() {
+        assert_eq!(encode_block(&[0u8; 0]), Err(Error::InvalidBlockSize));
+        assert_eq!(
+            encode_block(&[0u8; FULL_BLOCK_SIZE + 1]),
+            Err(Error::InvalidBlockSize)
+        );
+    }
+
+    #[test]
+    // Borrowed slices are still accepted since `encode` takes `impl AsRef<[u8]>`
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    fn encode_empty_value() {
+        assert_eq!(encode(&[0u8; 0]), Ok(String::from("")));
+    }
+
+    #[test]
+    fn encode_array_value() {
+        assert_eq!(encode([0u8; 0]), Ok(String::from("")));
+        assert_eq!(
+            encode(*b"Hello World"),
+            Ok(String::from("D7LMXYjUbXc1fS9Z"))
+        );
+    }
+
+    #[test]
+    fn decode_wrong_block() {
+        assert_eq!(decode_block(&[0u8; 1]), Err(Error::InvalidBlockSize));
+        assert_eq!(decode_block(&[0u8; 4]), Err(Error::InvalidBlockSize));
+        assert_eq!(decode_block(&[0u8; 8]), Err(Error::InvalidBlockSize));
+        assert_eq!(
+            decode_block(&[0u8; FULL_ENCODED_BLOCK_SIZE + 1]),
+            Err(Error::InvalidBlockSize)
+        );
+        //assert!(false);
+    }
+
+    macro_rules! uint_8be_to_64 {
+        ($expected:expr, $string:expr) => {
+            assert_eq!($expected, u8be_to_u64($string));
+        };
+    }
+
+    #[test]
+    fn test_u8be_to_u64() {
+        uint_8be_to_64!(0x0000000000000001, b"\x01");
+        uint_8be_to_64!(0x0000000000000102, b"\x01\x02");
+        uint_8be_to_64!(0x0000000000010203, b"\x01\x02\x03");
+        uint_8be_to_64!(0x0000000001020304, b"\x01\x02\x03\x04");
+        uint_8be_to_64!(0x0000000102030405, b"\x01\x02\x03\x04\x05");
+        uint_8be_to_64!(0x0000010203040506, b"\x01\x02\x03\x04\x05\x06");
+        uint_8be_to_64!(0x0001020304050607, b"\x01\x02\x03\x04\x05\x06\x07");
+        uint_8be_to_64!(0x0102030405060708, b"\x01\x02\x03\x04\x05\x06\x07\x08");
+    }
+
+    macro_rules! encode_block {
+        ($block:expr, $expected:expr) => {
+            let res = &encode_block($block).unwrap()[..ENCODED_BLOCK_SIZES[$block.len()]];
+            assert_eq!($expected.as_bytes(), res);
+        };
+    }
+
+    #[test]
+    fn test_base58_encode_block() {
+        encode_block!(b"\x00", "11");
+        encode_block!(b"\x39", "1z");
+        encode_block!(b"\xFF", "5Q");
+
+        encode_block!(b"\x00\x00", "111");
+        encode_block!(b"\x00\x39", "11z");
+        encode_block!(b"\x01\x00", "15R");
+        encode_block!(b"\xFF\xFF", "LUv");
+
+        encode_block!(b"\x00\x00\x00", "11111");
+        encode_block!(b"\x00\x00\x39", "1111z");
+        encode_block!(b"\x01\x00\x00", "11LUw");
+        encode_block!(b"\xFF\xFF\xFF", "2UzHL");
+
+        encode_block!(b"\x00\x00\x00\x39", "11111z");
+        encode_block!(b"\xFF\xFF\xFF\xFF", "7YXq9G");
+        encode_block!(b"\x00\x00\x00\x00\x39", "111111z");
+        encode_block!(b"\xFF\xFF\xFF\xFF\xFF", "VtB5VXc");
+        encode_block!(b"\x00\x00\x00\x00\x00\x39", "11111111z");
+        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF", "3CUsUpv9t");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x39", "111111111z");
+        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "Ahg1opVcGW");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x39", "1111111111z");
+        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "jpXCZedGfVQ");
+
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x00", "11111111111");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x01", "11111111112");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x08", "11111111119");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x09", "1111111111A");
+        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x3A", "11111111121");
+        encode_block!(b"\x00\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "1Ahg1opVcGW");
+        encode_block!(b"\x06\x15\x60\x13\x76\x28\x79\xF7", "22222222222");
+        encode_block!(b"\x05\xE0\x22\xBA\x37\x4B\x2A\x00", "1z111111111");
+    }
+
+    macro_rules! decode_block_pos {
+        ($enc:expr, $expected:expr) => {
+            let res = decode_block($enc).unwrap();
+            assert_eq!(&$expected[..], &res.data[FULL_BLOCK_SIZE - res.size..]);
+        };
+    }
+
+    macro_rules! decode_block_neg {
+        ($enc:expr, $expected:expr) => {
+            assert_eq!(Err($expected), decode_block($enc));
+        };
+    }
+
+    #[test]
+    fn test_base58_decode_block() {
+        // 1-byte block
+        decode_block_neg!(b"1", Error::InvalidBlockSize);
+        decode_block_neg!(b"z", Error::InvalidBlockSize);
+        // 2-byte block
+        decode_block_pos!(b"11", b"\x00");
+        decode_block_pos!(b"5Q", b"\xFF");
+        decode_block_neg!(b"5R", Error::Overflow);
+        decode_block_neg!(b"zz", Error::Overflow);
+        // 3-bytes block
+        decode_block_pos!(b"111", b"\x00\x00");
+        decode_block_pos!(b"LUv", b"\xFF\xFF");
+        decode_block_neg!(b"LUw", Error::Overflow);
+        decode_block_neg!(b"zzz", Error::Overflow);
+        // 4-bytes block
+        decode_block_neg!(b"1111", Error::InvalidBlockSize);
+        decode_block_neg!(b"zzzz", Error::InvalidBlockSize);
+        // 5-bytes block
+        decode_block_pos!(b"11111", b"\x00\x00\x00");
+        decode_block_pos!(b"2UzHL", b"\xFF\xFF\xFF");
+        decode_block_neg!(b"2UzHM", Error::Overflow);
+        decode_block_neg!(b"zzzzz", Error::Overflow);
+        // 6-bytes block
+        decode_block_pos!(b"111111", b"\x00\x00\x00\x00");
+        decode_block_pos!(b"7YXq9G", b"\xFF\xFF\xFF\xFF");
+        decode_block_neg!(b"7YXq9H", Error::Overflow);
+        decode_block_neg!(b"zzzzzz", Error::Overflow);
+        // 7-bytes block
+        decode_block_pos!(b"1111111", b"\x00\x00\x00\x00\x00");
+        decode_block_pos!(b"VtB5VXc", b"\xFF\xFF\xFF\xFF\xFF");
+        decode_block_neg!(b"VtB5VXd", Error::Overflow);
+        decode_block_neg!(b"zzzzzzz", Error::Overflow);
+        // 8-bytes block
+        decode_block_neg!(b"11111111", Error::InvalidBlockSize);
+        decode_block_neg!(b"zzzzzzzz", Error::InvalidBlockSize);
+        // 9-bytes block
+        decode_block_pos!(b"111111111", b"\x00\x00\x00\x00\x00\x00");
+        decode_block_pos!(b"3CUsUpv9t", b"\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_block_neg!(b"3CUsUpv9u", Error::Overflow);
+        decode_block_neg!(b"zzzzzzzzz", Error::Overflow);
+        // 10-bytes block
+        decode_block_pos!(b"1111111111", b"\x00\x00\x00\x00\x00\x00\x00");
+        decode_block_pos!(b"Ahg1opVcGW", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_block_neg!(b"Ahg1opVcGX", Error::Overflow);
+        decode_block_neg!(b"zzzzzzzzzz", Error::Overflow);
+        // 11-bytes block
+        decode_block_pos!(b"11111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00");
+        decode_block_pos!(b"jpXCZedGfVQ", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_block_neg!(b"jpXCZedGfVR", Error::Overflow);
+        decode_block_neg!(b"zzzzzzzzzzz", Error::Overflow);
+        // Invalid symbolsb"
+        decode_block_neg!(b"01111111111", Error::InvalidSymbol);
+        decode_block_neg!(b"11111111110", Error::InvalidSymbol);
+        decode_block_neg!(b"11111011111", Error::InvalidSymbol);
+        decode_block_neg!(b"I1111111111", Error::InvalidSymbol);
+        decode_block_neg!(b"O1111111111", Error::InvalidSymbol);
+        decode_block_neg!(b"l1111111111", Error::InvalidSymbol);
+        decode_block_neg!(b"_1111111111", Error::InvalidSymbol);
+    }
+
+    macro_rules! encode {
+        ($expected:expr, $data:expr) => {
+            assert_eq!(Ok(String::from($expected)), encode($data));
+        };
+    }
+
+    #[test]
+    fn test_base58_encode() {
+        encode!("11", b"\x00");
+        encode!("111", b"\x00\x00");
+        encode!("11111", b"\x00\x00\x00");
+        encode!("111111", b"\x00\x00\x00\x00");
+        encode!("1111111", b"\x00\x00\x00\x00\x00");
+        encode!("111111111", b"\x00\x00\x00\x00\x00\x00");
+        encode!("1111111111", b"\x00\x00\x00\x00\x00\x00\x00");
+        encode!("11111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00");
+        encode!("1111111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00\x00");
+        encode!(
+            "11111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "1111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "11111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "111111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "11111111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "111111111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "1111111111111111111111",
+            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        );
+        encode!(
+            "22222222222VtB5VXc",
+            b"\x06\x15\x60\x13\x76\x28\x79\xF7\xFF\xFF\xFF\xFF\xFF"
+        );
+    }
+
+    macro_rules! decode_pos {
+        ($enc:expr, $expected:expr) => {
+            assert_eq!(Ok(Vec::from(&$expected[..])), decode($enc));
+        };
+    }
+
+    macro_rules! decode_neg {
+        ($expected:expr, $enc:expr) => {
+            assert_eq!(Err($expected), decode($enc));
+        };
+    }
+
+    #[test]
+    fn test_base58_decode() {
+        decode_pos!("", b"");
+        decode_pos!("5Q", b"\xFF");
+        decode_pos!("LUv", b"\xFF\xFF");
+        decode_pos!("2UzHL", b"\xFF\xFF\xFF");
+        decode_pos!("7YXq9G", b"\xFF\xFF\xFF\xFF");
+        decode_pos!("VtB5VXc", b"\xFF\xFF\xFF\xFF\xFF");
+        decode_pos!("3CUsUpv9t", b"\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_pos!("Ahg1opVcGW", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_pos!("jpXCZedGfVQ", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_pos!("jpXCZedGfVQ5Q", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
+        decode_pos!(
+            "jpXCZedGfVQLUv",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQ2UzHL",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQ7YXq9G",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQVtB5VXc",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQ3CUsUpv9t",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQAhg1opVcGW",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        decode_pos!(
+            "jpXCZedGfVQjpXCZedGfVQ",
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        );
+        // Invalid length
+        decode_neg!(Error::InvalidBlockSize, "1");
+        decode_neg!(Error::InvalidBlockSize, "z");
+        decode_neg!(Error::InvalidBlockSize, "1111");
+        decode_neg!(Error::InvalidBlockSize, "zzzz");
+        decode_neg!(Error::InvalidBlockSize, "11111111");
+        decode_neg!(Error::InvalidBlockSize, "zzzzzzzz");
+        decode_neg!(Error::InvalidBlockSize, "123456789AB1");
+        decode_neg!(Error::InvalidBlockSize, "123456789ABz");
+        decode_neg!(Error::InvalidBlockSize, "123456789AB1111");
+        decode_neg!(Error::InvalidBlockSize, "123456789ABzzzz");
+        decode_neg!(Error::InvalidBlockSize, "123456789AB11111111");
+        decode_neg!(Error::InvalidBlockSize, "123456789ABzzzzzzzz");
+        // Overflow
+        decode_neg!(Error::Overflow, "5R");
+        decode_neg!(Error::Overflow, "zz");
+        decode_neg!(Error::Overflow, "LUw");
+        decode_neg!(Error::Overflow, "zzz");
+        decode_neg!(Error::Overflow, "2UzHM");
+        decode_neg!(Error::Overflow, "zzzzz");
+        decode_neg!(Error::Overflow, "7YXq9H");
+        decode_neg!(Error::Overflow, "zzzzzz");
+        decode_neg!(Error::Overflow, "VtB5VXd");
+        decode_neg!(Error::Overflow, "zzzzzzz");
+        decode_neg!(Error::Overflow, "3CUsUpv9u");
+        decode_neg!(Error::Overflow, "zzzzzzzzz");
+        decode_neg!(Error::Overflow, "Ahg1opVcGX");
+        decode_neg!(Error::Overflow, "zzzzzzzzzz");
+        decode_neg!(Error::Overflow, "jpXCZedGfVR");
+        decode_neg!(Error::Overflow, "zzzzzzzzzzz");
+        decode_neg!(Error::Overflow, "123456789AB5R");
+        decode_neg!(Error::Overflow, "123456789ABzz");
+        decode_neg!(Error::Overflow, "123456789ABLUw");
+        decode_neg!(Error::Overflow, "123456789ABzzz");
+        decode_neg!(Error::Overflow, "123456789AB2UzHM");
+        decode_neg!(Error::Overflow, "123456789ABzzzzz");
+        decode_neg!(Error::Overflow, "123456789AB7YXq9H");
+        decode_neg!(Error::Overflow, "123456789ABzzzzzz");
+        decode_neg!(Error::Overflow, "123456789ABVtB5VXd");
+        decode_neg!(Error::Overflow, "123456789ABzzzzzzz");
+        decode_neg!(Error::Overflow, "123456789AB3CUsUpv9u");
+        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzz");
+        decode_neg!(Error::Overflow, "123456789ABAhg1opVcGX");
+        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzzz");
+        decode_neg!(Error::Overflow, "123456789ABjpXCZedGfVR");
+        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzzzz");
+        decode_neg!(Error::Overflow, "zzzzzzzzzzz11");
+        // Invalid symbols
+        decode_neg!(Error::InvalidSymbol, "10");
+        decode_neg!(Error::InvalidSymbol, "11I");
+        decode_neg!(Error::InvalidSymbol, "11O11");
+        decode_neg!(Error::InvalidSymbol, "11l111");
+        decode_neg!(Error::InvalidSymbol, "11_11111111");
+        decode_neg!(Error::InvalidSymbol, "1101111111111");
+        decode_neg!(Error::InvalidSymbol, "11I11111111111111");
+        decode_neg!(Error::InvalidSymbol, "11O1111111111111111111");
+        decode_neg!(Error::InvalidSymbol, "1111111111110");
+        decode_neg!(Error::InvalidSymbol, "111111111111l1111");
+        decode_neg!(Error::InvalidSymbol, "111111111111_111111111");
+    }
+
+    #[test]
+    fn test_base58_try_encode_decode() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            assert_eq!(Ok(encoded.clone()), try_encode(&data[..len]));
+            assert_eq!(Ok(Vec::from(&data[..len])), try_decode(&encoded));
+        }
+        assert_eq!(Err(Error::InvalidBlockSize), try_decode("123456789AB1"));
+        assert_eq!(Err(Error::Overflow), try_decode("123456789AB5R"));
+        assert_eq!(Err(Error::InvalidSymbol), try_decode("11I"));
+    }
+
+    #[test]
+    fn test_base58_encode_decode_into() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut buf = [0u8; 512];
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            assert_eq!(Ok(encoded.len()), encode_into(&data[..len], &mut buf));
+            assert_eq!(encoded.as_bytes(), &buf[..encoded.len()]);
+            // Exact buffers are large enough
+            assert_eq!(Ok(len), decode_into(&encoded, &mut buf[..len]));
+            assert_eq!(&data[..len], &buf[..len]);
+        }
+
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_into(b"Hello World", &mut buf[..15])
+        );
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            decode_into("D7LMXYjUbXc1fS9Z", &mut buf[..10])
+        );
+        assert_eq!(Ok(0), decode_into("", &mut []));
+        assert_eq!(
+            Err(Error::InvalidBlockSize),
+            decode_into("123456789AB1", &mut buf)
+        );
+        assert_eq!(Err(Error::Overflow), decode_into("123456789AB5R", &mut buf));
+        assert_eq!(Err(Error::InvalidSymbol), decode_into("11I", &mut buf));
+    }
+
+    #[test]
+    fn test_base58_to_array() {
+        use super::{decode_to_array, encode_to_array};
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            let (buf, n) = encode_to_array::<352>(&data[..len]).unwrap();
+            assert_eq!(encoded.as_bytes(), &buf[..n]);
+            let (buf, n) = decode_to_array::<256>(&encoded).unwrap();
+            assert_eq!(&data[..len], &buf[..n]);
+        }
+
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_to_array::<15>(b"Hello World")
+        );
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            decode_to_array::<10>("D7LMXYjUbXc1fS9Z")
+        );
+        assert_eq!(Ok(([], 0)), decode_to_array::<0>(""));
+    }
+
+    #[test]
+    #[cfg(feature = "heapless")]
+    fn test_base58_heapless() {
+        use super::{decode_heapless, encode_heapless};
+
+        let data: Vec<u8> = (0..69).collect();
+        let encoded = encode_heapless::<95>(&data).unwrap();
+        assert_eq!(encode(&data).unwrap(), encoded.as_str());
+        assert_eq!(
+            data.as_slice(),
+            decode_heapless::<69>(&encoded).unwrap().as_slice()
+        );
+
+        assert_eq!(Err(Error::BufferTooSmall), encode_heapless::<94>(&data));
+        assert_eq!(Err(Error::BufferTooSmall), decode_heapless::<68>(&encoded));
+        assert_eq!(Err(Error::InvalidSymbol), decode_heapless::<8>("11I"));
+    }
+
+    #[test]
+    fn test_base58_encode_iter() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                encode(&data[..len]),
+                encode_iter(data[..len].iter().copied())
+            );
+        }
+        let (a, b) = data.split_at(13);
+        assert_eq!(encode(&data), encode_iter(a.iter().chain(b).copied()));
+        assert_eq!(Ok(String::new()), encode_iter(core::iter::empty()));
+    }
+
+    #[test]
+    fn test_base58_with_progress() {
+        let data: Vec<u8> = (0..=255).collect();
+        let data = &data[..100];
+        let encoded = encode(data).unwrap();
+
+        let mut calls = Vec::new();
+        assert_eq!(
+            Ok(encoded.clone()),
+            encode_with_progress(data, 4, |done, total| calls.push((done, total)))
+        );
+        assert_eq!(Vec::from([(4, 13), (8, 13), (12, 13), (13, 13)]), calls);
+
+        let mut calls = Vec::new();
+        assert_eq!(
+            Ok(Vec::from(data)),
+            decode_with_progress(&encoded, 0, |done, total| calls.push((done, total)))
+        );
+        assert_eq!(13, calls.len());
+        assert_eq!(Some(&(13, 13)), calls.last());
+
+        let mut called = false;
+        assert_eq!(
+            Ok(String::new()),
+            encode_with_progress(b"", 1, |_, _| called = true)
+        );
+        assert_eq!(
+            Ok(Vec::new()),
+            decode_with_progress("", 1, |_, _| called = true)
+        );
+        assert!(!called);
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            decode_with_progress("10", 1, |_, _| called = true)
+        );
+        assert!(!called);
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn test_base58_decode_with_digest() {
+        use super::decode_with_digest;
+        use sha2::{Digest, Sha256};
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 8, 9, 100, 256] {
+            let mut hasher = Sha256::new();
+            let encoded = encode(&data[..len]).unwrap();
+            assert_eq!(
+                Ok(Vec::from(&data[..len])),
+                decode_with_digest(&encoded, &mut hasher)
+            );
+            assert_eq!(Sha256::digest(&data[..len]), hasher.finalize());
+        }
+
+        let mut hasher = Sha256::new();
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            decode_with_digest("D7LMXYjUbXc10", &mut hasher)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_decode_writer() {
+        use super::Base58DecodeWriter;
+        use std::io::Write;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 7, 8, 9, 100, 256] {
+            let encoded = encode(&data[..len]).unwrap();
+            let mut writer = Base58DecodeWriter::new(Vec::new());
+            for chunk in encoded.as_bytes().chunks(3) {
+                writer.write_all(chunk).unwrap();
+            }
+            assert_eq!(&data[..len], &writer.finish().unwrap()[..]);
+        }
+
+        // Final partial block is decoded on drop
+        let mut out = Vec::new();
+        {
+            let mut writer = Base58DecodeWriter::new(&mut out);
+            writer.write_all(b"D7LMXYjUbXc1fS9Z").unwrap();
+            assert_eq!(b"Hello Wo", &writer.get_ref()[..]);
+        }
+        assert_eq!(b"Hello World", &out[..]);
+
+        let mut writer = Base58DecodeWriter::new(Vec::new());
+        let err = writer.write_all(b"0OIl0OIl0OI").unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        let mut writer = Base58DecodeWriter::new(Vec::new());
+        writer.write_all(b"1").unwrap();
+        writer.flush().unwrap();
+        assert_eq!(
+            std::io::ErrorKind::InvalidData,
+            writer.finish().unwrap_err().kind()
+        );
+
+        // The invalid block is consumed, the error is returned by every later call
+        let mut writer = Base58DecodeWriter::new(Vec::new());
+        assert_eq!(11, writer.write(b"D7LMXYjUbXc10D7LMXYjUbXc").unwrap());
+        let err = writer.write(b"Xc").unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        let inner = err.into_inner().unwrap().downcast::<Error>().unwrap();
+        assert_eq!(Error::InvalidSymbol, *inner);
+        assert!(writer.flush().is_err());
+        assert_eq!(b"Hello Wo", &writer.get_ref()[..]);
+        assert!(writer.finish().is_err());
+
+        // Flushing mid-stream keeps the partial block
+        let mut writer = Base58DecodeWriter::new(Vec::new());
+        writer.write_all(b"D7LMXYjUbXc1f").unwrap();
+        writer.flush().unwrap();
+        writer.write_all(b"S9Z").unwrap();
+        assert_eq!(b"Hello World", &writer.finish().unwrap()[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_encode_to_writer() {
+        use super::encode_to_writer;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let mut out = Vec::new();
+            encode_to_writer(&data[..len], &mut out).unwrap();
+            assert_eq!(encode(&data[..len]).unwrap().as_bytes(), &out[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_decode_to_writer() {
+        use super::decode_to_writer;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let mut out = Vec::new();
+            decode_to_writer(encode(&data[..len]).unwrap(), &mut out).unwrap();
+            assert_eq!(&data[..len], &out[..]);
+        }
+
+        let mut out = Vec::new();
+        let err = decode_to_writer("D7LMXYjUbXc10", &mut out).unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(b"Hello Wo", &out[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_encode_from_reader() {
+        use super::encode_from_reader;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let mut out = Vec::new();
+            // Chained readers return short reads at the boundary
+            let (a, b) = data[..len].split_at(len / 3);
+            encode_from_reader(std::io::Read::chain(a, b), &mut out).unwrap();
+            assert_eq!(encode(&data[..len]).unwrap().as_bytes(), &out[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_decode_from_reader() {
+        use super::decode_from_reader;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            let (a, b) = encoded.as_bytes().split_at(encoded.len() / 3);
+            let mut out = Vec::new();
+            decode_from_reader(std::io::Read::chain(a, b), &mut out).unwrap();
+            assert_eq!(&data[..len], &out[..]);
+        }
+
+        let err = decode_from_reader(&b"D7LMXYjUbXc10"[..], std::io::sink()).unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    }
+
+    #[test]
+    #[cfg(all(feature = "log", feature = "std"))]
+    fn test_base58_normalize_log() {
+        use std::string::ToString;
+        use std::sync::Mutex;
+
+        static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        struct Capture;
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record) {
+                let msg = record.args().to_string();
+                RECORDS.lock().unwrap().push(msg);
+            }
+
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        normalize("jpXCZedGfVQ5Q").unwrap();
+        normalize(" jpXCZedGfVQ\r\n5Q").unwrap();
+        let records = RECORDS.lock().unwrap();
+        // Other tests may normalize inputs concurrently
+        assert!(records.iter().any(|r| {
+            r == "base58 input normalized, removed 3 whitespace characters at positions [0, 12, 13]"
+        }));
+        assert!(!records.iter().any(|r| r.contains("removed 0")));
+    }
+
+    #[test]
+    fn test_decode_report() {
+        use super::decode_report;
+
+        let report = decode_report("");
+        assert!(report.blocks.is_empty());
+        assert_eq!(None, report.error());
+        assert_eq!(Some(Vec::new()), report.bytes());
+
+        let report = decode_report("1111111111111111111111jpXCZedGfVQ5Q");
+        assert_eq!(4, report.blocks.len());
+        assert_eq!(
+            [0, 11, 22, 33],
+            [0, 1, 2, 3].map(|i| report.blocks[i].offset)
+        );
+        assert_eq!(
+            [11, 11, 0, 0],
+            [0, 1, 2, 3].map(|i| report.blocks[i].padding)
+        );
+        assert_eq!("5Q", report.blocks[3].encoded);
+        assert_eq!(
+            Some(decode("1111111111111111111111jpXCZedGfVQ5Q").unwrap()),
+            report.bytes()
+        );
+
+        // Decoding continues after an invalid block
+        let report = decode_report("jpXCZedGfVRD7LMXYjUbXc1111111111\u{e9}");
+        assert_eq!(4, report.blocks.len());
+        assert_eq!(Err(Error::Overflow), report.blocks[0].decoded);
+        assert_eq!(
+            b"Hello Wo",
+            report.blocks[1].decoded.as_ref().unwrap().as_bytes()
+        );
+        // The last character is split across two blocks
+        assert_eq!("1111111111\u{fffd}", report.blocks[2].encoded);
+        assert_eq!(Err(Error::InvalidSymbol), report.blocks[2].decoded);
+        assert_eq!("\u{fffd}", report.blocks[3].encoded);
+        assert_eq!(Err(Error::InvalidBlockSize), report.blocks[3].decoded);
+        assert_eq!(Some(&Error::Overflow), report.error());
+        assert_eq!(None, report.bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_decode_report_checksum() {
+        use super::{decode_report, ChecksumStatus};
+
+        let status = |data| decode_report(data).checksum;
+        assert_eq!(ChecksumStatus::Valid, status("D7LMXYjUbXc5LVkq6vWDY"));
+        assert_eq!(ChecksumStatus::Invalid, status("D7LMXYjUbXc5LVkq6vWDZ"));
+        assert_eq!(ChecksumStatus::Unavailable, status("D7LMXYjUbXc5LVkq6vWD-"));
+        assert_eq!(ChecksumStatus::Unavailable, status("5Q"));
+        assert_eq!(ChecksumStatus::Unavailable, status(""));
+    }
+
+    #[test]
+    fn test_decode_verbose() {
+        use super::{decode_verbose, DecodeError};
+        use alloc::string::ToString;
+
+        for data in [
+            "",
+            "11",
+            "D7LMXYjUbXc1fS9Z",
+            "1111111111111111111111jpXCZedGfVQ5Q",
+        ] {
+            assert_eq!(decode(data).unwrap(), decode_verbose(data).unwrap());
+        }
+
+        let err = |data| decode_verbose(data).unwrap_err();
+        assert_eq!(
+            DecodeError::InvalidSymbol {
+                offset: 4,
+                symbol: 'l'
+            },
+            err("D7LMlYjUbXc1fS9Z")
+        );
+        assert_eq!(
+            DecodeError::InvalidSymbol {
+                offset: 14,
+                symbol: '0'
+            },
+            err("D7LMXYjUbXc1fS0Z")
+        );
+        // Non-ASCII characters are reported whole, at their first byte
+        assert_eq!(
+            DecodeError::InvalidSymbol {
+                offset: 10,
+                symbol: '\u{e9}'
+            },
+            err("D7LMXYjUbX\u{e9}c1fS9Z")
+        );
+        assert_eq!(
+            DecodeError::InvalidBlockSize { len: 12 },
+            err("D7LMXYjUbXc1")
+        );
+        assert_eq!(
+            DecodeError::Overflow {
+                block: 1,
+                offset: 11
+            },
+            err("123456789AB5R")
+        );
+        // Blocks are decoded in order, the first failure is reported
+        assert_eq!(
+            DecodeError::Overflow {
+                block: 0,
+                offset: 0
+            },
+            err("jpXCZedGfVR-")
+        );
+
+        assert_eq!(Error::InvalidSymbol, Error::from(err("10")));
+        assert_eq!("base58.overflow", err("5R").message_key());
+        assert_eq!(
+            "Invalid symbol error: 'l' at offset 4",
+            err("D7LMlYjUbXc1fS9Z").to_string()
+        );
+        assert_eq!(
+            "Overflow error: block 1 at offset 11",
+            err("123456789AB5R").to_string()
+        );
+        assert_eq!(
+            "Invalid block size error: input of 12 characters, last block of 1",
+            err("D7LMXYjUbXc1").to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_decode_check_verbose() {
+        use super::{decode_check_verbose, DecodeError};
+
+        assert_eq!(
+            b"Hello World",
+            &decode_check_verbose("D7LMXYjUbXc5LVkq6vWDY").unwrap()[..]
+        );
+        assert_eq!(Ok(Vec::new()), decode_check_verbose("64HByS"));
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            decode_check_verbose("D7LMXYjUbXc5LVkq6vWDZ")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            decode_check_verbose("5Q")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidSymbol {
+                offset: 20,
+                symbol: '0'
+            }),
+            decode_check_verbose("D7LMXYjUbXc5LVkq6vWD0")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "generic-array")]
+    #[allow(deprecated)]
+    fn test_decode_generic_array() {
+        use super::decode_generic_array;
+        use generic_array::typenum::{U0, U11, U12, U69, U8};
+
+        assert_eq!(
+            b"Hello World",
+            decode_generic_array::<U11>("D7LMXYjUbXc1fS9Z")
+                .unwrap()
+                .as_slice()
+        );
+        assert_eq!(
+            Ok([0xff; 8].into()),
+            decode_generic_array::<U8>("jpXCZedGfVQ")
+        );
+        assert!(decode_generic_array::<U0>("").unwrap().is_empty());
+        let data: Vec<u8> = (0..69).collect();
+        assert_eq!(
+            data.as_slice(),
+            decode_generic_array::<U69>(encode(&data).unwrap())
+                .unwrap()
+                .as_slice()
+        );
+
+        assert_eq!(
+            Err(Error::InvalidLength),
+            decode_generic_array::<U12>("D7LMXYjUbXc1fS9Z")
+        );
+        assert_eq!(Err(Error::InvalidLength), decode_generic_array::<U8>("1"));
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            decode_generic_array::<U11>("D7LMXYjUbXc1fS90")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "check", feature = "generic-array"))]
+    #[allow(deprecated)]
+    fn test_decode_check_generic_array() {
+        use super::decode_check_generic_array;
+        use generic_array::typenum::{U0, U11, U15};
+
+        assert_eq!(
+            b"Hello World",
+            decode_check_generic_array::<U11>("D7LMXYjUbXc5LVkq6vWDY")
+                .unwrap()
+                .as_slice()
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_generic_array::<U11>("D7LMXYjUbXc5LVkq6vWDZ")
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            decode_check_generic_array::<U15>("D7LMXYjUbXc5LVkq6vWDY")
+        );
+        assert_eq!(
+            Err(Error::InvalidLength),
+            decode_check_generic_array::<U0>("")
+        );
+    }
+
+    #[test]
+    fn test_poll_states() {
+        use super::{DecodeState, EncodeState};
+        use core::task::Poll;
+
+        let data: Vec<u8> = (0..100).collect();
+        let encoded = encode(&data).unwrap();
+        for chunk_size in [1, 3, 8, 11, 100] {
+            let mut state = EncodeState::new();
+            let mut res = String::new();
+            for mut chunk in data.chunks(chunk_size) {
+                while let Poll::Ready(block) = state.poll_encode(&mut chunk) {
+                    res.push_str(block.as_str());
+                }
+                assert!(chunk.is_empty());
+            }
+            if let Some(block) = state.finish() {
+                res.push_str(block.as_str());
+            }
+            assert_eq!(encoded, res);
+
+            let mut state = DecodeState::new();
+            let mut res = Vec::new();
+            for mut chunk in encoded.as_bytes().chunks(chunk_size) {
+                while let Poll::Ready(block) = state.poll_decode(&mut chunk) {
+                    res.extend_from_slice(block.unwrap().as_bytes());
+                }
+            }
+            if let Some(block) = state.finish().unwrap() {
+                res.extend_from_slice(block.as_bytes());
+            }
+            assert_eq!(data, res);
+        }
+
+        assert_eq!(None, EncodeState::new().finish());
+        assert_eq!(Ok(None), DecodeState::new().finish());
+
+        let mut state = DecodeState::new();
+        let mut input = &b"jpXCZedGfVR1"[..];
+        assert_eq!(
+            Poll::Ready(Err(Error::Overflow)),
+            state.poll_decode(&mut input)
+        );
+        assert_eq!(Poll::Pending, state.poll_decode(&mut input));
+        assert_eq!(Err(Error::InvalidBlockSize), state.finish());
+    }
+
+    #[test]
+    fn test_block_sizes() {
+        use super::{
+            decoded_block_size, decoded_len, encoded_len, DECODED_BLOCK_SIZES,
+            FULL_ENCODED_BLOCK_SIZE,
+        };
+
+        for (len, &size) in ENCODED_BLOCK_SIZES.iter().enumerate() {
+            assert_eq!(Some(len), DECODED_BLOCK_SIZES[size]);
+            assert_eq!(Some(len), decoded_block_size(size));
+        }
+        assert_eq!(9, DECODED_BLOCK_SIZES.iter().flatten().count());
+        assert_eq!(None, decoded_block_size(FULL_ENCODED_BLOCK_SIZE + 1));
+
+        for len in 0..100 {
+            let data: Vec<u8> = (0..len as u8).collect();
+            let encoded = encode(&data).unwrap();
+            assert_eq!(encoded.len(), encoded_len(len));
+            assert_eq!(Some(len), decoded_len(encoded.len()));
+        }
+        assert_eq!(95, encoded_len(69));
+        assert_eq!(None, decoded_len(12));
+        assert_eq!(None, decoded_len(26));
+    }
+
+    #[test]
+    fn test_error_message_key() {
+        let errors = [
+            Error::InvalidBlockSize,
+            Error::InvalidSymbol,
+            #[cfg(feature = "check")]
+            Error::InvalidChecksum,
+            #[cfg(feature = "check")]
+            Error::InvalidPrefix,
+            Error::InvalidLength,
+            Error::Overflow,
+            Error::BufferTooSmall,
+            Error::OutOfMemory,
+        ];
+        for (i, a) in errors.iter().enumerate() {
+            assert!(a.message_key().starts_with("base58."));
+            for b in &errors[i + 1..] {
+                assert_ne!(a.message_key(), b.message_key());
+            }
         }
+        assert_eq!("base58.overflow", Error::Overflow.message_key());
+        #[cfg(feature = "stream")]
+        assert_eq!(
+            "base58.io",
+            Error::Io(std::io::ErrorKind::Other.into()).message_key()
+        );
+    }
 
-        if check != checksum[..CHECKSUM_SIZE] {
-            Err(Error::InvalidChecksum)?;
+    #[test]
+    fn test_base58_display() {
+        use super::display;
+        use alloc::string::ToString;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            assert_eq!(
+                encode(&data[..len]).unwrap(),
+                display(&data[..len]).to_string()
+            );
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    extern crate alloc;
-    use alloc::string::String;
-    use alloc::vec::Vec;
+    #[test]
+    #[cfg(feature = "ufmt")]
+    fn test_base58_display_ufmt() {
+        use super::display;
+        use ufmt::uwrite;
 
-    use super::{
-        decode, decode_block, encode, encode_block, u8be_to_u64, Error, ENCODED_BLOCK_SIZES,
-        FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE,
-    };
+        struct Serial(String);
 
-    #[cfg(feature = "check")]
-    use super::{decode_check, encode_check};
-    #[cfg(feature = "stream")]
-    use super::{decode_stream, encode_stream};
-    #[cfg(all(feature = "check", feature = "stream"))]
-    use super::{decode_stream_check, encode_stream_check};
+        impl ufmt::uWrite for Serial {
+            type Error = core::convert::Infallible;
 
-    #[cfg(feature = "stream")]
-    use futures_util::{pin_mut, stream::StreamExt};
+            fn write_str(&mut self, s: &str) -> core::result::Result<(), Self::Error> {
+                self.0.push_str(s);
+                Ok(())
+            }
+        }
+
+        let mut serial = Serial(String::new());
+        uwrite!(serial, "{}", display(b"Hello World")).unwrap();
+        assert_eq!("D7LMXYjUbXc1fS9Z", serial.0);
+    }
 
     #[test]
-    fn encode_wrong_block() {
-        assert_eq!(encode_block(&[0u8; 0]), Err(Error::InvalidBlockSize));
+    fn test_alphabet() {
+        use super::{decode_with_alphabet, encode_with_alphabet, BASE58_CHARS};
+
+        assert_eq!(BASE58_CHARS, &Alphabet::MONERO.as_bytes()[..]);
+        assert_eq!(Some(0), Alphabet::MONERO.digit(b'1'));
+        assert_eq!(Some(57), Alphabet::MONERO.digit(b'z'));
+        assert_eq!(None, Alphabet::MONERO.digit(b'0'));
+        assert_eq!(None, Alphabet::MONERO.digit(0xff));
+
+        // Monero alphabet in reverse order
+        const REVERSED: Alphabet =
+            Alphabet::new(b"zyxwvutsrqponmkjihgfedcbaZYXWVUTSRQPNMLKJHGFEDCBA987654321");
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            assert_eq!(
+                Ok(encoded.clone()),
+                encode_with_alphabet(&data[..len], &Alphabet::MONERO)
+            );
+            let reversed = encode_with_alphabet(&data[..len], &REVERSED).unwrap();
+            let expected: String = encoded
+                .bytes()
+                .map(|c| {
+                    REVERSED.as_bytes()[BASE58_CHARS.iter().position(|&x| x == c).unwrap()] as char
+                })
+                .collect();
+            assert_eq!(expected, reversed);
+            assert_eq!(
+                Ok(Vec::from(&data[..len])),
+                decode_with_alphabet(&reversed, &REVERSED)
+            );
+        }
         assert_eq!(
-            encode_block(&[0u8; FULL_BLOCK_SIZE + 1]),
-            Err(Error::InvalidBlockSize)
+            Err(Error::InvalidSymbol),
+            decode_with_alphabet("1O", &REVERSED)
         );
     }
 
     #[test]
-    fn encode_empty_value() {
-        assert_eq!(encode(&[0u8; 0]), Ok(String::from("")));
+    #[should_panic(expected = "duplicate character")]
+    fn test_alphabet_duplicate() {
+        let mut chars = *Alphabet::MONERO.as_bytes();
+        chars[57] = b'1';
+        Alphabet::new(&chars);
     }
 
     #[test]
-    fn decode_wrong_block() {
-        assert_eq!(decode_block(&[0u8; 1]), Err(Error::InvalidBlockSize));
-        assert_eq!(decode_block(&[0u8; 4]), Err(Error::InvalidBlockSize));
-        assert_eq!(decode_block(&[0u8; 8]), Err(Error::InvalidBlockSize));
+    #[should_panic(expected = "non-ASCII character")]
+    fn test_alphabet_non_ascii() {
+        let mut chars = *Alphabet::MONERO.as_bytes();
+        chars[0] = 0xc3;
+        Alphabet::new(&chars);
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn test_biguint() {
+        use super::{decode_biguint, encode_biguint, BigUintFormat};
+        use num_bigint::BigUint;
+
+        for format in [BigUintFormat::Blocks, BigUintFormat::Positional] {
+            let mut n = BigUint::from(0u8);
+            for _ in 0..64 {
+                let encoded = encode_biguint(&n, format);
+                assert_eq!(Ok(n.clone()), decode_biguint(&encoded, format));
+                n = n * 251u8 + 7u8;
+            }
+            assert_eq!(Err(Error::InvalidSymbol), decode_biguint("10", format));
+        }
+
+        let zero = BigUint::from(0u8);
+        assert_eq!("11", encode_biguint(&zero, BigUintFormat::Blocks));
+        assert_eq!("1", encode_biguint(&zero, BigUintFormat::Positional));
+        assert_eq!(Ok(zero.clone()), decode_biguint("", BigUintFormat::Blocks));
         assert_eq!(
-            decode_block(&[0u8; FULL_ENCODED_BLOCK_SIZE + 1]),
-            Err(Error::InvalidBlockSize)
+            Err(Error::InvalidLength),
+            decode_biguint("", BigUintFormat::Positional)
+        );
+        // Leading zero digits are accepted
+        assert_eq!(Ok(zero), decode_biguint("111", BigUintFormat::Positional));
+
+        let n = BigUint::from(u64::MAX);
+        assert_eq!("jpXCZedGfVQ", encode_biguint(&n, BigUintFormat::Blocks));
+        assert_eq!(
+            Ok(n),
+            decode_biguint("jpXCZedGfVQ", BigUintFormat::Positional)
+        );
+        assert_eq!(
+            Err(Error::Overflow),
+            decode_biguint("zzzzzzzzzzz", BigUintFormat::Blocks)
         );
-        //assert!(false);
     }
 
-    macro_rules! uint_8be_to_64 {
-        ($expected:expr, $string:expr) => {
-            assert_eq!($expected, u8be_to_u64($string));
-        };
+    #[test]
+    #[cfg(all(feature = "self-check", debug_assertions))]
+    #[should_panic(expected = "self-check")]
+    fn test_self_check() {
+        super::self_check(b"Hello World", b"D7LMXYjUbXc1fS9Z");
+        super::self_check(b"Hello World", b"D7LMXYjUbXc1fS9a");
     }
 
     #[test]
-    fn test_u8be_to_u64() {
-        uint_8be_to_64!(0x0000000000000001, b"\x01");
-        uint_8be_to_64!(0x0000000000000102, b"\x01\x02");
-        uint_8be_to_64!(0x0000000000010203, b"\x01\x02\x03");
-        uint_8be_to_64!(0x0000000001020304, b"\x01\x02\x03\x04");
-        uint_8be_to_64!(0x0000000102030405, b"\x01\x02\x03\x04\x05");
-        uint_8be_to_64!(0x0000010203040506, b"\x01\x02\x03\x04\x05\x06");
-        uint_8be_to_64!(0x0001020304050607, b"\x01\x02\x03\x04\x05\x06\x07");
-        uint_8be_to_64!(0x0102030405060708, b"\x01\x02\x03\x04\x05\x06\x07\x08");
+    fn test_with_scratch() {
+        use super::with_scratch;
+
+        let res = with_scratch(|outer| {
+            outer.push(1);
+            // Nested calls get a distinct empty buffer
+            with_scratch(|inner| {
+                assert!(inner.is_empty());
+                inner.push(2);
+            });
+            Vec::from(&outer[..])
+        });
+        assert_eq!(Vec::from([1]), res);
+        with_scratch(|buf| assert!(buf.is_empty()));
     }
 
-    macro_rules! encode_block {
-        ($block:expr, $expected:expr) => {
-            let chars = $expected.chars().collect::<Vec<_>>();
-            let res = &encode_block($block).unwrap()[..ENCODED_BLOCK_SIZES[$block.len()]];
-            assert_eq!(chars, res);
-        };
+    #[test]
+    fn test_base58_decode_bounded() {
+        let encoded = "D7LMXYjUbXc1fS9Z";
+        assert_eq!(decode(encoded), decode_bounded(encoded, 16));
+        assert_eq!(decode(encoded), decode_bounded(encoded, usize::MAX));
+        assert_eq!(Err(Error::InvalidLength), decode_bounded(encoded, 15));
+        assert_eq!(Ok(Vec::new()), decode_bounded("", 0));
+        // Length is checked before the symbols
+        assert_eq!(Err(Error::InvalidLength), decode_bounded("0OIl", 3));
+        assert_eq!(Err(Error::InvalidSymbol), decode_bounded("10", 3));
     }
 
     #[test]
-    fn test_base58_encode_block() {
-        encode_block!(b"\x00", "11");
-        encode_block!(b"\x39", "1z");
-        encode_block!(b"\xFF", "5Q");
+    #[cfg(feature = "std")]
+    fn test_base58_decode_until() {
+        use super::decode_until;
+        use std::io::BufRead;
 
-        encode_block!(b"\x00\x00", "111");
-        encode_block!(b"\x00\x39", "11z");
-        encode_block!(b"\x01\x00", "15R");
-        encode_block!(b"\xFF\xFF", "LUv");
+        let data: Vec<u8> = (0..=255).collect();
+        let mut input = Vec::new();
+        for len in 0..data.len() {
+            input.extend_from_slice(encode(&data[..len]).unwrap().as_bytes());
+            input.push(b'\n');
+        }
+        // Small buffer to split records and blocks across reads
+        let mut reader = std::io::BufReader::with_capacity(7, &input[..]);
+        for len in 0..data.len() {
+            assert_eq!(
+                Some(Vec::from(&data[..len])),
+                decode_until(&mut reader, b'\n').unwrap()
+            );
+        }
+        assert_eq!(None, decode_until(&mut reader, b'\n').unwrap());
 
-        encode_block!(b"\x00\x00\x00", "11111");
-        encode_block!(b"\x00\x00\x39", "1111z");
-        encode_block!(b"\x01\x00\x00", "11LUw");
-        encode_block!(b"\xFF\xFF\xFF", "2UzHL");
+        // Last record without delimiter, the invalid record is skipped
+        let mut reader = &b"10,D7LMXYjUbXc1fS9Z"[..];
+        let err = decode_until(&mut reader, b',').unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        assert_eq!(
+            Some(Vec::from(&b"Hello World"[..])),
+            decode_until(&mut reader, b',').unwrap()
+        );
+        assert_eq!(None, decode_until(&mut reader, b',').unwrap());
 
-        encode_block!(b"\x00\x00\x00\x39", "11111z");
-        encode_block!(b"\xFF\xFF\xFF\xFF", "7YXq9G");
-        encode_block!(b"\x00\x00\x00\x00\x39", "111111z");
-        encode_block!(b"\xFF\xFF\xFF\xFF\xFF", "VtB5VXc");
-        encode_block!(b"\x00\x00\x00\x00\x00\x39", "11111111z");
-        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF", "3CUsUpv9t");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x39", "111111111z");
-        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "Ahg1opVcGW");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x39", "1111111111z");
-        encode_block!(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "jpXCZedGfVQ");
+        // The reader is left after the delimiter
+        let mut reader = &b"D7LMXYjUbXc1fS9Z rest"[..];
+        decode_until(&mut reader, b' ').unwrap();
+        assert_eq!(b"rest", reader.fill_buf().unwrap());
+    }
 
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x00", "11111111111");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x01", "11111111112");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x08", "11111111119");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x09", "1111111111A");
-        encode_block!(b"\x00\x00\x00\x00\x00\x00\x00\x3A", "11111111121");
-        encode_block!(b"\x00\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "1Ahg1opVcGW");
-        encode_block!(b"\x06\x15\x60\x13\x76\x28\x79\xF7", "22222222222");
-        encode_block!(b"\x05\xE0\x22\xBA\x37\x4B\x2A\x00", "1z111111111");
+    #[test]
+    fn test_base58_decode_chars() {
+        use super::decode_chars;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            let res: Result<Vec<u8>, Error> = decode_chars(encoded.chars()).collect();
+            assert_eq!(Ok(Vec::from(&data[..len])), res);
+        }
+
+        // Bytes of the blocks preceding the error are yielded, then the iterator ends
+        let mut iter = decode_chars("D7LMXYjUbXc10".chars());
+        assert_eq!(Some(Ok(b'H')), iter.next());
+        assert_eq!(Err(Error::InvalidSymbol), iter.nth(7).unwrap());
+        assert_eq!(None, iter.next());
+
+        for (input, err) in [
+            ("10", Error::InvalidSymbol),
+            ("1é", Error::InvalidSymbol),
+            ("1", Error::InvalidBlockSize),
+            ("5R", Error::Overflow),
+        ] {
+            let mut iter = decode_chars(input.chars());
+            assert_eq!(Some(Err(err)), iter.next());
+            assert_eq!(None, iter.next());
+        }
     }
 
-    macro_rules! decode_block_pos {
-        ($enc:expr, $expected:expr) => {
-            let res = decode_block($enc).unwrap();
-            assert_eq!(&$expected[..], &res.data[FULL_BLOCK_SIZE - res.size..]);
-        };
+    #[test]
+    fn test_decode_hex_or_base58() {
+        use super::{decode_hex_or_base58, DetectedFormat};
+
+        let hello = Vec::from(&b"Hello World"[..]);
+        for hex in [
+            "48656c6c6f20576f726c64",
+            "48656C6C6F20576F726C64",
+            "0x48656c6c6f20576f726c64",
+        ] {
+            assert_eq!(
+                Ok((DetectedFormat::Hex, hello.clone())),
+                decode_hex_or_base58(hex)
+            );
+        }
+        assert_eq!(
+            Ok((DetectedFormat::Base58, hello.clone())),
+            decode_hex_or_base58("D7LMXYjUbXc1fS9Z")
+        );
+        // Checksums are not verified
+        assert_eq!(
+            Ok((
+                DetectedFormat::Base58,
+                decode("D7LMXYjUbXc5LVkq6vWDY").unwrap()
+            )),
+            decode_hex_or_base58("D7LMXYjUbXc5LVkq6vWDY")
+        );
+        #[cfg(feature = "check")]
+        {
+            use super::decode_hex_or_base58_check;
+
+            assert_eq!(
+                Ok((DetectedFormat::Base58Check, hello.clone())),
+                decode_hex_or_base58_check("D7LMXYjUbXc5LVkq6vWDY")
+            );
+            assert_eq!(
+                Ok((DetectedFormat::Base58, hello.clone())),
+                decode_hex_or_base58_check("D7LMXYjUbXc1fS9Z")
+            );
+            assert_eq!(
+                Ok((DetectedFormat::Hex, hello.clone())),
+                decode_hex_or_base58_check("48656c6c6f20576f726c64")
+            );
+            assert_eq!(
+                Ok((DetectedFormat::Hex, Vec::new())),
+                decode_hex_or_base58_check("0x")
+            );
+            assert_eq!(Err(Error::InvalidSymbol), decode_hex_or_base58_check("ab0"));
+        }
+
+        // Hex digits only, odd length
+        assert_eq!(
+            Ok((DetectedFormat::Base58, decode("11f").unwrap())),
+            decode_hex_or_base58("11f")
+        );
+        assert_eq!(
+            Ok((DetectedFormat::Base58, Vec::new())),
+            decode_hex_or_base58("")
+        );
+        assert_eq!(
+            Ok((DetectedFormat::Hex, Vec::new())),
+            decode_hex_or_base58("0x")
+        );
+        assert_eq!(Err(Error::InvalidSymbol), decode_hex_or_base58("0xabc"));
+        assert_eq!(Err(Error::InvalidSymbol), decode_hex_or_base58("ab0"));
+        assert_eq!(Err(Error::InvalidSymbol), decode_hex_or_base58("0g"));
     }
 
-    macro_rules! decode_block_neg {
-        ($enc:expr, $expected:expr) => {
-            assert_eq!(Err($expected), decode_block($enc));
-        };
+    #[test]
+    fn test_base58_decode_expecting() {
+        use super::decode_expecting;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&data[..len]).unwrap();
+            let res = decode_expecting(&encoded, len).unwrap();
+            assert_eq!(&data[..len], &res[..]);
+            assert_eq!(len, res.capacity());
+            assert_eq!(
+                Err(Error::InvalidLength),
+                decode_expecting(&encoded, len + 1)
+            );
+            if len > 0 {
+                assert_eq!(
+                    Err(Error::InvalidLength),
+                    decode_expecting(&encoded[..encoded.len() - 1], len)
+                );
+            }
+        }
+        // Length is checked before the symbols
+        assert_eq!(Err(Error::InvalidLength), decode_expecting("0OI", 1));
+        assert_eq!(Err(Error::InvalidSymbol), decode_expecting("0O", 1));
     }
 
     #[test]
-    fn test_base58_decode_block() {
-        // 1-byte block
-        decode_block_neg!(b"1", Error::InvalidBlockSize);
-        decode_block_neg!(b"z", Error::InvalidBlockSize);
-        // 2-byte block
-        decode_block_pos!(b"11", b"\x00");
-        decode_block_pos!(b"5Q", b"\xFF");
-        decode_block_neg!(b"5R", Error::Overflow);
-        decode_block_neg!(b"zz", Error::Overflow);
-        // 3-bytes block
-        decode_block_pos!(b"111", b"\x00\x00");
-        decode_block_pos!(b"LUv", b"\xFF\xFF");
-        decode_block_neg!(b"LUw", Error::Overflow);
-        decode_block_neg!(b"zzz", Error::Overflow);
-        // 4-bytes block
-        decode_block_neg!(b"1111", Error::InvalidBlockSize);
-        decode_block_neg!(b"zzzz", Error::InvalidBlockSize);
-        // 5-bytes block
-        decode_block_pos!(b"11111", b"\x00\x00\x00");
-        decode_block_pos!(b"2UzHL", b"\xFF\xFF\xFF");
-        decode_block_neg!(b"2UzHM", Error::Overflow);
-        decode_block_neg!(b"zzzzz", Error::Overflow);
-        // 6-bytes block
-        decode_block_pos!(b"111111", b"\x00\x00\x00\x00");
-        decode_block_pos!(b"7YXq9G", b"\xFF\xFF\xFF\xFF");
-        decode_block_neg!(b"7YXq9H", Error::Overflow);
-        decode_block_neg!(b"zzzzzz", Error::Overflow);
-        // 7-bytes block
-        decode_block_pos!(b"1111111", b"\x00\x00\x00\x00\x00");
-        decode_block_pos!(b"VtB5VXc", b"\xFF\xFF\xFF\xFF\xFF");
-        decode_block_neg!(b"VtB5VXd", Error::Overflow);
-        decode_block_neg!(b"zzzzzzz", Error::Overflow);
-        // 8-bytes block
-        decode_block_neg!(b"11111111", Error::InvalidBlockSize);
-        decode_block_neg!(b"zzzzzzzz", Error::InvalidBlockSize);
-        // 9-bytes block
-        decode_block_pos!(b"111111111", b"\x00\x00\x00\x00\x00\x00");
-        decode_block_pos!(b"3CUsUpv9t", b"\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_block_neg!(b"3CUsUpv9u", Error::Overflow);
-        decode_block_neg!(b"zzzzzzzzz", Error::Overflow);
-        // 10-bytes block
-        decode_block_pos!(b"1111111111", b"\x00\x00\x00\x00\x00\x00\x00");
-        decode_block_pos!(b"Ahg1opVcGW", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_block_neg!(b"Ahg1opVcGX", Error::Overflow);
-        decode_block_neg!(b"zzzzzzzzzz", Error::Overflow);
-        // 11-bytes block
-        decode_block_pos!(b"11111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00");
-        decode_block_pos!(b"jpXCZedGfVQ", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_block_neg!(b"jpXCZedGfVR", Error::Overflow);
-        decode_block_neg!(b"zzzzzzzzzzz", Error::Overflow);
-        // Invalid symbolsb"
-        decode_block_neg!(b"01111111111", Error::InvalidSymbol);
-        decode_block_neg!(b"11111111110", Error::InvalidSymbol);
-        decode_block_neg!(b"11111011111", Error::InvalidSymbol);
-        decode_block_neg!(b"I1111111111", Error::InvalidSymbol);
-        decode_block_neg!(b"O1111111111", Error::InvalidSymbol);
-        decode_block_neg!(b"l1111111111", Error::InvalidSymbol);
-        decode_block_neg!(b"_1111111111", Error::InvalidSymbol);
+    fn test_base58_encoder() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let mut encoder = Encoder::new();
+            let (a, b) = data[..len].split_at(len / 3);
+            encoder.update(a);
+            encoder.extend(b);
+            assert_eq!(encode(&data[..len]), Ok(encoder.finish()));
+        }
+
+        let mut encoder = Encoder::new();
+        core::fmt::Write::write_fmt(&mut encoder, format_args!("Hello {}", "World")).unwrap();
+        assert_eq!("D7LMXYjUbXc1fS9Z", encoder.finish());
+        assert_eq!(String::new(), Encoder::new().finish());
     }
 
-    macro_rules! encode {
-        ($expected:expr, $data:expr) => {
-            assert_eq!(Ok(String::from($expected)), encode($data));
-        };
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_base58_encoder_io_write() {
+        use std::io::Write;
+
+        let mut encoder = Encoder::new();
+        encoder.write_all(b"Hello ").unwrap();
+        std::io::copy(&mut &b"World"[..], &mut encoder).unwrap();
+        assert_eq!("D7LMXYjUbXc1fS9Z", encoder.finish());
     }
 
     #[test]
-    fn test_base58_encode() {
-        encode!("11", b"\x00");
-        encode!("111", b"\x00\x00");
-        encode!("11111", b"\x00\x00\x00");
-        encode!("111111", b"\x00\x00\x00\x00");
-        encode!("1111111", b"\x00\x00\x00\x00\x00");
-        encode!("111111111", b"\x00\x00\x00\x00\x00\x00");
-        encode!("1111111111", b"\x00\x00\x00\x00\x00\x00\x00");
-        encode!("11111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00");
-        encode!("1111111111111", b"\x00\x00\x00\x00\x00\x00\x00\x00\x00");
-        encode!(
-            "11111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
-        );
-        encode!(
-            "1111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
-        );
-        encode!(
-            "11111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
-        );
-        encode!(
-            "111111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+    fn test_base58_encode_join_decode_split() {
+        let payloads = [&b""[..], b"Hello World", b"\x00\x00\x00", b"abc"];
+        let joined = encode_join(payloads, ",").unwrap();
+        assert_eq!(",D7LMXYjUbXc1fS9Z,11111,1ZiCa", joined);
+        assert_eq!(
+            Ok(Vec::from(payloads.map(Vec::from))),
+            decode_split(&joined, ',')
         );
-        encode!(
-            "11111111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+
+        assert_eq!(
+            Ok(String::new()),
+            encode_join(core::iter::empty::<&[u8]>(), ":")
         );
-        encode!(
-            "111111111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+        assert_eq!(Ok(Vec::from([Vec::new()])), decode_split("", ':'));
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            decode_split("D7LMXYjUbXc1fS9Z:10", ':')
         );
-        encode!(
-            "1111111111111111111111",
-            b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"
+    }
+
+    #[test]
+    fn test_base58_decode_bytes() {
+        assert_eq!(Ok(Vec::new()), decode_bytes(b""));
+        assert_eq!(
+            Ok(Vec::from(&b"Hello World"[..])),
+            decode_bytes(b"D7LMXYjUbXc1fS9Z")
         );
-        encode!(
-            "22222222222VtB5VXc",
-            b"\x06\x15\x60\x13\x76\x28\x79\xF7\xFF\xFF\xFF\xFF\xFF"
+        assert_eq!(Err(Error::InvalidSymbol), decode_bytes(b"1\xff"));
+        assert_eq!(Err(Error::InvalidSymbol), decode_bytes(b"1\xc3\xa9"));
+        assert_eq!(Err(Error::Overflow), decode_bytes(b"5R"));
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_base58_decode_buf() {
+        use bytes::Buf;
+
+        let encoded = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+        let expected = decode(encoded);
+        for i in 0..encoded.len() {
+            for j in i..encoded.len() {
+                let (a, rest) = encoded.as_bytes().split_at(i);
+                let (b, c) = rest.split_at(j - i);
+                assert_eq!(expected, decode_buf(a.chain(b).chain(c)));
+            }
+        }
+        assert_eq!(Ok(Vec::new()), decode_buf(&b""[..]));
+        assert_eq!(
+            Err(Error::InvalidBlockSize),
+            decode_buf(b"123456".chain(&b"789AB1"[..]))
         );
+        assert_eq!(Err(Error::InvalidSymbol), decode_buf(b"1".chain(&b"0"[..])));
     }
 
-    macro_rules! decode_pos {
-        ($enc:expr, $expected:expr) => {
-            assert_eq!(Ok(Vec::from(&$expected[..])), decode($enc));
-        };
+    #[test]
+    fn test_base58_as_ref() {
+        let data: Vec<u8> = Vec::from(&b"Hello World"[..]);
+        let encoded: String = encode(&data).unwrap();
+        assert_eq!(Ok(encoded.clone()), encode(data.clone()));
+        assert_eq!(Ok(encoded.clone()), encode(*b"Hello World"));
+        assert_eq!(Ok(data.clone()), decode(encoded.clone()));
+        assert_eq!(Ok(data), decode(alloc::borrow::Cow::from(&encoded[..])));
     }
 
-    macro_rules! decode_neg {
-        ($expected:expr, $enc:expr) => {
-            assert_eq!(Err($expected), decode($enc));
-        };
+    #[test]
+    fn test_base58_digits() {
+        use super::{char_to_index, index_to_char, BASE58_CHARS, BASE58_DIGITS, INVALID_DIGIT};
+
+        for (i, &c) in BASE58_CHARS.iter().enumerate() {
+            assert_eq!(i as u8, BASE58_DIGITS[c as usize]);
+            assert_eq!(Some(i as u8), char_to_index(c as char));
+            assert_eq!(Some(c as char), index_to_char(i as u8));
+        }
+        let valid = BASE58_DIGITS
+            .iter()
+            .filter(|&&d| d != INVALID_DIGIT)
+            .count();
+        assert_eq!(58, valid);
+        for c in ['0', 'O', 'I', 'l', ' ', 'é', '\u{7f}'] {
+            assert_eq!(None, char_to_index(c));
+        }
+        assert_eq!(None, index_to_char(58));
+        assert_eq!(None, index_to_char(255));
     }
 
     #[test]
-    fn test_base58_decode() {
-        decode_pos!("", b"");
-        decode_pos!("5Q", b"\xFF");
-        decode_pos!("LUv", b"\xFF\xFF");
-        decode_pos!("2UzHL", b"\xFF\xFF\xFF");
-        decode_pos!("7YXq9G", b"\xFF\xFF\xFF\xFF");
-        decode_pos!("VtB5VXc", b"\xFF\xFF\xFF\xFF\xFF");
-        decode_pos!("3CUsUpv9t", b"\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_pos!("Ahg1opVcGW", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_pos!("jpXCZedGfVQ", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_pos!("jpXCZedGfVQ5Q", b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF");
-        decode_pos!(
-            "jpXCZedGfVQLUv",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
-        );
-        decode_pos!(
-            "jpXCZedGfVQ2UzHL",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
-        );
-        decode_pos!(
-            "jpXCZedGfVQ7YXq9G",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
-        );
-        decode_pos!(
-            "jpXCZedGfVQVtB5VXc",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
-        );
-        decode_pos!(
-            "jpXCZedGfVQ3CUsUpv9t",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
-        );
-        decode_pos!(
-            "jpXCZedGfVQAhg1opVcGW",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+    fn test_decode_digit() {
+        use super::{decode_digit, BASE58_DIGITS, INVALID_DIGIT};
+
+        for c in 0..=u8::MAX {
+            let expected = BASE58_DIGITS.get(c as usize).copied();
+            assert_eq!(expected.unwrap_or(INVALID_DIGIT), decode_digit(c));
+        }
+    }
+
+    #[test]
+    fn test_is_base58() {
+        use super::{find_first_invalid, is_base58, BASE58_CHARS};
+
+        assert!(is_base58(""));
+        assert!(is_base58(core::str::from_utf8(BASE58_CHARS).unwrap()));
+        // Valid alphabet, invalid block size
+        assert!(is_base58("1"));
+        for (data, pos) in [("0", 0), ("D7LMXYjUbXc1fS9I", 15), ("ab l", 2), ("1é1", 1)] {
+            assert!(!is_base58(data));
+            assert_eq!(Some(pos), find_first_invalid(data));
+        }
+        for c in 0..=255u8 {
+            let valid = BASE58_CHARS.contains(&c);
+            if let Ok(s) = core::str::from_utf8(&[c]) {
+                assert_eq!(valid, is_base58(s));
+            }
+        }
+    }
+
+    #[test]
+    fn test_base58_normalize() {
+        assert_eq!(Ok(String::new()), normalize(""));
+        assert_eq!(Ok(String::new()), normalize(" \n\t"));
+        assert_eq!(
+            Ok(String::from("jpXCZedGfVQ5Q")),
+            normalize("jpXCZedGfVQ5Q")
         );
-        decode_pos!(
-            "jpXCZedGfVQjpXCZedGfVQ",
-            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF"
+        assert_eq!(
+            Ok(String::from("jpXCZedGfVQ5Q")),
+            normalize("  jpXCZedGfVQ\r\n5Q\n")
         );
-        // Invalid length
-        decode_neg!(Error::InvalidBlockSize, "1");
-        decode_neg!(Error::InvalidBlockSize, "z");
-        decode_neg!(Error::InvalidBlockSize, "1111");
-        decode_neg!(Error::InvalidBlockSize, "zzzz");
-        decode_neg!(Error::InvalidBlockSize, "11111111");
-        decode_neg!(Error::InvalidBlockSize, "zzzzzzzz");
-        decode_neg!(Error::InvalidBlockSize, "123456789AB1");
-        decode_neg!(Error::InvalidBlockSize, "123456789ABz");
-        decode_neg!(Error::InvalidBlockSize, "123456789AB1111");
-        decode_neg!(Error::InvalidBlockSize, "123456789ABzzzz");
-        decode_neg!(Error::InvalidBlockSize, "123456789AB11111111");
-        decode_neg!(Error::InvalidBlockSize, "123456789ABzzzzzzzz");
-        // Overflow
-        decode_neg!(Error::Overflow, "5R");
-        decode_neg!(Error::Overflow, "zz");
-        decode_neg!(Error::Overflow, "LUw");
-        decode_neg!(Error::Overflow, "zzz");
-        decode_neg!(Error::Overflow, "2UzHM");
-        decode_neg!(Error::Overflow, "zzzzz");
-        decode_neg!(Error::Overflow, "7YXq9H");
-        decode_neg!(Error::Overflow, "zzzzzz");
-        decode_neg!(Error::Overflow, "VtB5VXd");
-        decode_neg!(Error::Overflow, "zzzzzzz");
-        decode_neg!(Error::Overflow, "3CUsUpv9u");
-        decode_neg!(Error::Overflow, "zzzzzzzzz");
-        decode_neg!(Error::Overflow, "Ahg1opVcGX");
-        decode_neg!(Error::Overflow, "zzzzzzzzzz");
-        decode_neg!(Error::Overflow, "jpXCZedGfVR");
-        decode_neg!(Error::Overflow, "zzzzzzzzzzz");
-        decode_neg!(Error::Overflow, "123456789AB5R");
-        decode_neg!(Error::Overflow, "123456789ABzz");
-        decode_neg!(Error::Overflow, "123456789ABLUw");
-        decode_neg!(Error::Overflow, "123456789ABzzz");
-        decode_neg!(Error::Overflow, "123456789AB2UzHM");
-        decode_neg!(Error::Overflow, "123456789ABzzzzz");
-        decode_neg!(Error::Overflow, "123456789AB7YXq9H");
-        decode_neg!(Error::Overflow, "123456789ABzzzzzz");
-        decode_neg!(Error::Overflow, "123456789ABVtB5VXd");
-        decode_neg!(Error::Overflow, "123456789ABzzzzzzz");
-        decode_neg!(Error::Overflow, "123456789AB3CUsUpv9u");
-        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzz");
-        decode_neg!(Error::Overflow, "123456789ABAhg1opVcGX");
-        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzzz");
-        decode_neg!(Error::Overflow, "123456789ABjpXCZedGfVR");
-        decode_neg!(Error::Overflow, "123456789ABzzzzzzzzzzz");
-        decode_neg!(Error::Overflow, "zzzzzzzzzzz11");
-        // Invalid symbols
-        decode_neg!(Error::InvalidSymbol, "10");
-        decode_neg!(Error::InvalidSymbol, "11I");
-        decode_neg!(Error::InvalidSymbol, "11O11");
-        decode_neg!(Error::InvalidSymbol, "11l111");
-        decode_neg!(Error::InvalidSymbol, "11_11111111");
-        decode_neg!(Error::InvalidSymbol, "1101111111111");
-        decode_neg!(Error::InvalidSymbol, "11I11111111111111");
-        decode_neg!(Error::InvalidSymbol, "11O1111111111111111111");
-        decode_neg!(Error::InvalidSymbol, "1111111111110");
-        decode_neg!(Error::InvalidSymbol, "111111111111l1111");
-        decode_neg!(Error::InvalidSymbol, "111111111111_111111111");
+        assert_eq!(Err(Error::InvalidSymbol), normalize("jpXCZedGfVQ5-Q"));
+        assert_eq!(Err(Error::InvalidBlockSize), normalize("jpXCZedGfVQ5"));
+        assert_eq!(Err(Error::Overflow), normalize("5R"));
     }
 
     #[cfg(feature = "stream")]
@@ -1130,6 +6471,613 @@ mod tests {
         decode_stream_neg!(Error::InvalidSymbol, b"111111111111_111111111");
     }
 
+    // Sink collecting the decoded bytes
+    #[cfg(feature = "stream")]
+    #[derive(Default)]
+    struct CollectSink(Vec<u8>);
+
+    #[cfg(feature = "stream")]
+    impl futures_util::sink::Sink<Vec<u8>> for CollectSink {
+        type Error = Error;
+
+        fn poll_ready(
+            self: core::pin::Pin<&mut Self>,
+            _: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<super::Result<()>> {
+            core::task::Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: core::pin::Pin<&mut Self>, item: Vec<u8>) -> super::Result<()> {
+            self.get_mut().0.extend(item);
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: core::pin::Pin<&mut Self>,
+            _: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<super::Result<()>> {
+            core::task::Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: core::pin::Pin<&mut Self>,
+            _: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<super::Result<()>> {
+            core::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_decode_sink() {
+        use super::Base58DecodeSink;
+        use futures_util::sink::SinkExt;
+
+        let data: Vec<u8> = (0..100).collect();
+        let encoded = encode(&data).unwrap();
+        let mut sink = Base58DecodeSink::new(CollectSink::default());
+        for chunk in encoded.as_bytes().chunks(7) {
+            sink.send(chunk).await.unwrap();
+        }
+        sink.close().await.unwrap();
+        assert_eq!(data, sink.into_inner().0);
+
+        let mut sink = Base58DecodeSink::new(CollectSink::default());
+        assert_eq!(Err(Error::InvalidSymbol), sink.send("0OIl0OIl0OI").await);
+        let mut sink = Base58DecodeSink::new(CollectSink::default());
+        sink.send("D7LMXYjUbXc1").await.unwrap();
+        assert_eq!(Err(Error::InvalidBlockSize), sink.close().await);
+
+        // The error is returned again after an invalid block, nothing more is sent
+        let mut sink = Base58DecodeSink::new(CollectSink::default());
+        sink.send("D7LMXYjUbXc").await.unwrap();
+        assert_eq!(Err(Error::InvalidSymbol), sink.send("10D7LMXYjUb").await);
+        assert_eq!(Err(Error::InvalidSymbol), sink.send("D7LMXYjUbXc").await);
+        assert_eq!(Err(Error::InvalidSymbol), sink.flush().await);
+        assert_eq!(Err(Error::InvalidSymbol), sink.close().await);
+        assert_eq!(b"Hello Wo", &sink.into_inner().0[..]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(all(feature = "check", feature = "stream"))]
+    async fn test_base58_decode_sink_check() {
+        use super::Base58DecodeSink;
+        use futures_util::sink::SinkExt;
+
+        let data: Vec<u8> = (0..100).collect();
+        for len in [0, 1, 3, 4, 5, 8, 100] {
+            let encoded = encode_check(&data[..len]).unwrap();
+            let mut sink = Base58DecodeSink::new_check(CollectSink::default());
+            for chunk in encoded.as_bytes().chunks(5) {
+                sink.send(chunk).await.unwrap();
+            }
+            sink.close().await.unwrap();
+            assert_eq!(&data[..len], &sink.into_inner().0[..]);
+        }
+
+        for input in ["D7LMXYjUbXc1fS9Z", "", "11"] {
+            let mut sink = Base58DecodeSink::new_check(CollectSink::default());
+            sink.send(input).await.unwrap();
+            assert_eq!(Err(Error::InvalidChecksum), sink.close().await);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_streams_fused_send_unpin() {
+        use futures_util::stream::FusedStream;
+
+        fn assert_send<T: Send>(_: &T) {}
+        fn assert_unpin<T: Unpin>(_: &T) {}
+
+        // Polled without pinning
+        let mut stream = encode_stream(&b"Hello World"[..]);
+        assert_send(&stream);
+        assert_unpin(&stream);
+        assert!(!stream.is_terminated());
+        while stream.next().await.is_some() {}
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+
+        let mut stream = decode_stream(&b"D7LMXYjUbXc1fS9Z"[..]);
+        assert_send(&stream);
+        assert_unpin(&stream);
+        while stream.next().await.is_some() {}
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+
+        let data = &b"D7LMXYjUbXc1fS9Z"[..];
+        assert_unpin(&super::encode_stream_blocks(data));
+        assert_unpin(&super::encode_stream_chunks(data));
+        assert_unpin(&super::decode_stream_blocks(data));
+        assert_unpin(&super::decode_stream_chunks(data));
+        #[cfg(feature = "check")]
+        {
+            assert_unpin(&super::encode_stream_check(data));
+            assert_unpin(&super::decode_stream_check(data));
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_stream_owned() {
+        use super::{decode_stream_owned, encode_stream_owned};
+
+        let input: Vec<u8> = (0..100).collect();
+        let encoded = encode(&input).unwrap();
+
+        let s = encode_stream_owned(std::io::Cursor::new(input.clone()));
+        let task = tokio::spawn(async move {
+            pin_mut!(s);
+            let mut res = String::new();
+            while let Some(c) = s.next().await {
+                res.push(c?);
+            }
+            Ok::<_, Error>(res)
+        });
+        assert_eq!(Ok(encoded.clone()), task.await.unwrap());
+
+        let s = decode_stream_owned(std::io::Cursor::new(encoded.into_bytes()));
+        let task = tokio::spawn(async move {
+            pin_mut!(s);
+            let mut res = Vec::new();
+            while let Some(b) = s.next().await {
+                res.push(b?);
+            }
+            Ok::<_, Error>(res)
+        });
+        assert_eq!(Ok(input), task.await.unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "stream-futures")]
+    fn test_base58_stream_futures() {
+        use futures_util::io::Cursor;
+        use futures_util::stream::TryStreamExt;
+
+        use super::{decode_stream_futures, encode_stream_futures};
+
+        // Driven by the `futures` executor, without a `tokio` runtime
+        futures_util::future::FutureExt::now_or_never(async {
+            let input: Vec<u8> = (0..100).collect();
+            let encoded: String = encode_stream_futures(Cursor::new(&input))
+                .try_collect()
+                .await
+                .unwrap();
+            assert_eq!(encode(&input).unwrap(), encoded);
+            let decoded: Vec<u8> = decode_stream_futures(encoded.as_bytes())
+                .try_collect()
+                .await
+                .unwrap();
+            assert_eq!(input, decoded);
+
+            #[cfg(feature = "check")]
+            {
+                use super::{decode_stream_check_futures, encode_stream_check_futures};
+
+                let encoded: String = encode_stream_check_futures(&input[..])
+                    .try_collect()
+                    .await
+                    .unwrap();
+                assert_eq!(encode_check(&input).unwrap(), encoded);
+                let decoded: Vec<u8> = decode_stream_check_futures(encoded.as_bytes())
+                    .try_collect()
+                    .await
+                    .unwrap();
+                assert_eq!(input, decoded);
+                let res: Result<Vec<u8>, Error> =
+                    decode_stream_check_futures(&b"D7LMXYjUbXc1fS9Z"[..])
+                        .try_collect()
+                        .await;
+                assert_eq!(Err(Error::InvalidChecksum), res);
+            }
+        })
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "stream")]
+    async fn test_base58_stream_verbose() {
+        use std::io;
+
+        use super::{decode_stream_verbose, decode_verbose, DecodeError, StreamDecodeError};
+
+        async fn collect_verbose(data: &[u8]) -> core::result::Result<Vec<u8>, StreamDecodeError> {
+            let mut stream = decode_stream_verbose(data);
+            let mut res = Vec::new();
+            while let Some(b) = stream.next().await {
+                res.push(b?);
+            }
+            Ok(res)
+        }
+
+        // Same result and error positions as `decode_verbose`
+        for data in [
+            "",
+            "D7LMXYjUbXc1fS9Z",
+            "D7LMlYjUbXc1fS9Z",
+            "D7LMXYjUbXc1fS0Z",
+            "D7LMX\u{e9}jUbXc1fS9Z",
+            "D7LMXYjUbXc1",
+            "123456789AB5R",
+            "jpXCZedGfVR-",
+        ] {
+            let expected = decode_verbose(data).map_err(StreamDecodeError::Decode);
+            assert_eq!(expected, collect_verbose(data.as_bytes()).await);
+        }
+        // Character split between two blocks
+        assert_eq!(
+            Err(StreamDecodeError::Decode(DecodeError::InvalidSymbol {
+                offset: 10,
+                symbol: char::REPLACEMENT_CHARACTER
+            })),
+            collect_verbose("D7LMXYjUbX\u{e9}c1fS9Z".as_bytes()).await
+        );
+
+        let reader = tokio_test::io::Builder::new()
+            .read(b"D7LMXYjUbXc")
+            .read_error(io::Error::other("disconnected"))
+            .build();
+        let mut stream = decode_stream_verbose(reader);
+        let mut res = Vec::new();
+        let err = loop {
+            match stream.next().await.unwrap() {
+                Ok(b) => res.push(b),
+                Err(e) => break e,
+            }
+        };
+        assert_eq!(b"Hello Wo", &res[..]);
+        assert!(matches!(err, StreamDecodeError::Io(_)));
+        assert_eq!(Error::Io(io::Error::other("")), Error::from(err));
+        assert!(stream.next().await.is_none());
+
+        #[cfg(feature = "check")]
+        {
+            use super::{decode_check_verbose, decode_stream_check_verbose};
+
+            for data in [
+                "D7LMXYjUbXc5LVkq6vWDY",
+                "D7LMXYjUbXc1fS9Z",
+                "D7LMlYjUbXc",
+                "5Q",
+            ] {
+                let stream = decode_stream_check_verbose(data.as_bytes());
+                let res: core::result::Result<Vec<u8>, _> =
+                    stream.collect::<Vec<_>>().await.into_iter().collect();
+                let expected = decode_check_verbose(data).map_err(StreamDecodeError::Decode);
+                assert_eq!(expected, res);
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_stream_not_unpin_reader() {
+        use core::cell::Cell;
+        use core::marker::PhantomPinned;
+        use core::pin::Pin;
+        use core::task::{Context, Poll};
+        use tokio::io::{AsyncRead, ReadBuf};
+
+        // Reader that cannot be moved once pinned
+        struct Pinned<'a> {
+            data: Cell<&'a [u8]>,
+            _pin: PhantomPinned,
+        }
+
+        impl AsyncRead for Pinned<'_> {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _: &mut Context<'_>,
+                buf: &mut ReadBuf<'_>,
+            ) -> Poll<std::io::Result<()>> {
+                let data = self.data.get();
+                let len = data.len().min(buf.remaining()).min(3);
+                buf.put_slice(&data[..len]);
+                self.data.set(&data[len..]);
+                Poll::Ready(Ok(()))
+            }
+        }
+
+        let pinned = |data| Pinned {
+            data: Cell::new(data),
+            _pin: PhantomPinned,
+        };
+
+        // Streams are `Unpin` even if the reader is not
+        let mut s = encode_stream(pinned(b"Hello World"));
+        let mut res = String::new();
+        while let Some(c) = s.next().await {
+            res.push(c.unwrap());
+        }
+        assert_eq!("D7LMXYjUbXc1fS9Z", res);
+
+        let mut s = decode_stream(pinned(b"D7LMXYjUbXc1fS9Z"));
+        let mut res = Vec::new();
+        while let Some(b) = s.next().await {
+            res.push(b.unwrap());
+        }
+        assert_eq!(b"Hello World", &res[..]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_encode_stream_blocks() {
+        use super::encode_stream_blocks;
+
+        let data: Vec<u8> = (0..100).collect();
+        let stream = encode_stream_blocks(&data[..]);
+        pin_mut!(stream);
+        let mut res = String::new();
+        let mut sizes = Vec::new();
+        while let Some(block) = stream.next().await {
+            let block = block.unwrap();
+            sizes.push(block.size());
+            res.push_str(block.as_str());
+        }
+        assert_eq!(encode(&data).unwrap(), res);
+        assert_eq!(
+            Vec::from([11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 6]),
+            sizes
+        );
+
+        let stream = encode_stream_blocks(&b""[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(all(feature = "check", feature = "stream"))]
+    async fn test_base58_transcode_check() {
+        use super::{encode_check, transcode_check, ChecksumAlgorithm, MoneroChecksum};
+
+        // 2 bytes checksum: length and XOR of the payload bytes
+        struct Xor(u8, u8);
+
+        impl ChecksumAlgorithm for Xor {
+            fn size(&self) -> usize {
+                2
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.0 = self.0.wrapping_add(data.len() as u8);
+                self.1 = data.iter().fold(self.1, |acc, b| acc ^ b);
+            }
+
+            fn finalize(self) -> Vec<u8> {
+                Vec::from([self.0, self.1])
+            }
+        }
+
+        async fn collect(
+            data: &[u8],
+            source: impl ChecksumAlgorithm,
+            target: impl ChecksumAlgorithm,
+        ) -> super::Result<String> {
+            let stream = transcode_check(data, source, target);
+            pin_mut!(stream);
+            let mut res = String::new();
+            while let Some(c) = stream.next().await {
+                res.push(c?);
+            }
+            Ok(res)
+        }
+
+        for len in 0..40 {
+            let data: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(37)).collect();
+            let encoded = encode_check(&data).unwrap();
+            let mut xor = Xor(0, 0);
+            xor.update(&data);
+            let mut expected = data.clone();
+            expected.extend_from_slice(&xor.finalize());
+            let expected = encode(&expected).unwrap();
+
+            assert_eq!(
+                Ok(expected.clone()),
+                collect(encoded.as_bytes(), MoneroChecksum::new(), Xor(0, 0)).await
+            );
+            assert_eq!(
+                Ok(encoded.clone()),
+                collect(expected.as_bytes(), Xor(0, 0), MoneroChecksum::new()).await
+            );
+            assert_eq!(
+                Ok(encoded.clone()),
+                collect(
+                    encoded.as_bytes(),
+                    MoneroChecksum::new(),
+                    MoneroChecksum::new()
+                )
+                .await
+            );
+        }
+
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            collect(b"D7LMXYjUbXc5LVkq6vWDZ", MoneroChecksum::new(), Xor(0, 0)).await
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            collect(b"11", MoneroChecksum::new(), Xor(0, 0)).await
+        );
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            collect(b"D7LMXYjUbX0", MoneroChecksum::new(), Xor(0, 0)).await
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "blocking")]
+    async fn test_base58_blocking() {
+        use super::{decode_blocking, encode_blocking, BLOCKING_THRESHOLD};
+
+        // Inline and offloaded
+        for len in [11, BLOCKING_THRESHOLD + 3] {
+            let data: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let encoded = encode_blocking(data.clone()).await.unwrap();
+            assert_eq!(encode(&data).unwrap(), encoded);
+            assert_eq!(Ok(data), decode_blocking(encoded).await);
+        }
+        assert_eq!(Err(Error::InvalidSymbol), decode_blocking("10").await);
+
+        #[cfg(feature = "check")]
+        {
+            use super::{decode_check_blocking, encode_check_blocking};
+
+            let data = Vec::from([0xffu8; BLOCKING_THRESHOLD]);
+            let encoded = encode_check_blocking(data.clone()).await.unwrap();
+            assert_eq!(Ok(data), decode_check_blocking(encoded).await);
+            assert_eq!(
+                Err(Error::InvalidChecksum),
+                decode_check_blocking("D7LMXYjUbXc5LVkq6vWDZ").await
+            );
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "parallel-stream")]
+    async fn test_base58_decode_stream_par() {
+        use super::{decode_stream_par, PAR_BATCH_BLOCKS};
+        use futures_util::stream::FusedStream;
+
+        // Several batches with a partial last block
+        let data: Vec<u8> = (0..FULL_BLOCK_SIZE * PAR_BATCH_BLOCKS * 3 + 5)
+            .map(|i| (i * 7) as u8)
+            .collect();
+        let encoded = encode(&data).unwrap();
+        for parallelism in [0, 1, 4] {
+            let stream = decode_stream_par(encoded.as_bytes(), parallelism);
+            pin_mut!(stream);
+            let mut res = Vec::new();
+            let mut batches = 0;
+            while let Some(batch) = stream.next().await {
+                res.extend_from_slice(&batch.unwrap());
+                batches += 1;
+            }
+            assert_eq!(data, res);
+            assert_eq!(4, batches);
+            assert!(stream.is_terminated());
+        }
+
+        let stream = decode_stream_par(&b""[..], 4);
+        pin_mut!(stream);
+        assert!(stream.next().await.is_none());
+
+        // Errors are reported in input order
+        let mut invalid = encoded.into_bytes();
+        let len = invalid.len();
+        invalid[len - 1] = b'0';
+        let stream = decode_stream_par(&invalid[..], 4);
+        pin_mut!(stream);
+        for _ in 0..3 {
+            assert!(stream.next().await.unwrap().is_ok());
+        }
+        assert_eq!(Some(Err(Error::InvalidSymbol)), stream.next().await);
+        assert!(stream.next().await.is_none());
+        assert!(stream.is_terminated());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "parallel-stream")]
+    async fn test_base58_decode_stream_par_ends_on_error() {
+        use super::{decode_stream_par, PAR_BATCH_BLOCKS};
+        use futures_util::stream::FusedStream;
+
+        // Invalid block in the second of four batches
+        let data: Vec<u8> = (0..FULL_BLOCK_SIZE * PAR_BATCH_BLOCKS * 4)
+            .map(|i| (i * 7) as u8)
+            .collect();
+        let mut invalid = encode(&data).unwrap().into_bytes();
+        invalid[PAR_BATCH_BLOCKS * FULL_ENCODED_BLOCK_SIZE + 5] = b'0';
+        for parallelism in [1, 4] {
+            let stream = decode_stream_par(&invalid[..], parallelism);
+            pin_mut!(stream);
+            assert!(stream.next().await.unwrap().is_ok());
+            assert_eq!(Some(Err(Error::InvalidSymbol)), stream.next().await);
+            assert!(stream.next().await.is_none());
+            assert!(stream.is_terminated());
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_decode_stream_blocks() {
+        use super::decode_stream_blocks;
+
+        let data: Vec<u8> = (0..100).collect();
+        let encoded = encode(&data).unwrap();
+        let stream = decode_stream_blocks(encoded.as_bytes());
+        pin_mut!(stream);
+        let mut res = Vec::new();
+        let mut sizes = Vec::new();
+        while let Some(block) = stream.next().await {
+            let block = block.unwrap();
+            sizes.push(block.size());
+            res.extend_from_slice(block.as_bytes());
+        }
+        assert_eq!(data, res);
+        assert_eq!(Vec::from([8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4]), sizes);
+
+        let stream = decode_stream_blocks(&b""[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.is_none());
+
+        let stream = decode_stream_blocks(&b"123456789AB5R"[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert_eq!(Some(Err(Error::Overflow)), stream.next().await);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(feature = "stream")]
+    async fn test_base58_stream_chunks() {
+        use super::{decode_stream_chunks, encode_stream_chunks, STREAM_CHUNK_BLOCKS};
+        use tokio::io::AsyncReadExt;
+
+        let data: Vec<u8> = (0..STREAM_CHUNK_BLOCKS * 3 * FULL_BLOCK_SIZE + 100)
+            .map(|i| i as u8)
+            .collect();
+        let encoded = encode(&data).unwrap();
+
+        // Reads ending in the middle of a block
+        let stream = encode_stream_chunks((&data[..13]).chain(&data[13..]));
+        pin_mut!(stream);
+        let mut res = String::new();
+        let mut count = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            assert!(chunk.len() <= STREAM_CHUNK_BLOCKS * FULL_ENCODED_BLOCK_SIZE);
+            res.push_str(&chunk);
+            count += 1;
+        }
+        assert_eq!(encoded, res);
+        assert!(count < data.len() / FULL_BLOCK_SIZE);
+
+        let stream =
+            decode_stream_chunks((&encoded.as_bytes()[..13]).chain(&encoded.as_bytes()[13..]));
+        pin_mut!(stream);
+        let mut res = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.unwrap();
+            assert!(chunk.len() <= STREAM_CHUNK_BLOCKS * FULL_BLOCK_SIZE);
+            res.extend_from_slice(&chunk);
+        }
+        assert_eq!(data, res);
+
+        let stream = encode_stream_chunks(&b""[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.is_none());
+        let stream = decode_stream_chunks(&b""[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.is_none());
+
+        // The whole block is yielded before the invalid trailing block is read
+        let stream = decode_stream_chunks(&b"123456789AB5R"[..]);
+        pin_mut!(stream);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert_eq!(Some(Err(Error::Overflow)), stream.next().await);
+        assert!(stream.next().await.is_none());
+    }
+
     macro_rules! encode_address {
         ($expected:expr, $hex:expr, $func:expr) => {
             let hex = hex::decode($hex).unwrap();
@@ -1161,6 +7109,306 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "check", feature = "std"))]
+    fn test_base58_decode_check_from_reader() {
+        use super::decode_check_from_reader;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode_check(&data[..len]).unwrap();
+            let (a, b) = encoded.as_bytes().split_at(encoded.len() / 3);
+            let mut out = Vec::new();
+            decode_check_from_reader(std::io::Read::chain(a, b), &mut out).unwrap();
+            assert_eq!(&data[..len], &out[..]);
+        }
+
+        // Valid base58 with a wrong checksum, and inputs shorter than the checksum
+        for input in ["D7LMXYjUbXc1fS9Z", "", "11", "111"] {
+            let err = decode_check_from_reader(input.as_bytes(), std::io::sink()).unwrap_err();
+            assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        }
+
+        // Writer flushed only after the checksum is verified
+        struct Flushes(Vec<u8>, usize);
+        impl std::io::Write for Flushes {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.1 += 1;
+                Ok(())
+            }
+        }
+        let mut out = Flushes(Vec::new(), 0);
+        decode_check_from_reader("D7LMXYjUbXc5LVkq6vWDY".as_bytes(), &mut out).unwrap();
+        assert_eq!((&b"Hello World"[..], 1), (&out.0[..], out.1));
+        let mut out = Flushes(Vec::new(), 0);
+        decode_check_from_reader("D7LMXYjUbXc1fS9Z".as_bytes(), &mut out).unwrap_err();
+        assert_eq!(0, out.1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "check", feature = "std"))]
+    fn test_base58_decode_check_seekable() {
+        use super::decode_check_seekable;
+        use std::io::{Cursor, Seek, SeekFrom};
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            // Prefixed data is skipped, decoding starts at the current position
+            let encoded = std::format!("xx{}", encode_check(&data[..len]).unwrap());
+            let mut reader = Cursor::new(encoded.as_bytes());
+            reader.seek(SeekFrom::Start(2)).unwrap();
+            let mut out = Vec::new();
+            decode_check_seekable(reader, &mut out).unwrap();
+            assert_eq!(&data[..len], &out[..]);
+        }
+
+        // Nothing is written when the checksum does not match
+        for input in ["D7LMXYjUbXc1fS9Z", "", "111", "10"] {
+            let mut out = Vec::new();
+            let err = decode_check_seekable(Cursor::new(input.as_bytes()), &mut out).unwrap_err();
+            assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+            assert!(out.is_empty());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_decode_check_many() {
+        use super::decode_check_many;
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut inputs: Vec<String> = (0..data.len())
+            .map(|len| encode_check(&data[..len]).unwrap())
+            .collect();
+        inputs.extend(["", "111", "10", "D7LMXYjUbXc1fS9Z"].map(String::from));
+        let expected: Vec<_> = inputs.iter().map(decode_check).collect();
+        assert_eq!(expected, decode_check_many(&inputs));
+        #[cfg(feature = "parallel")]
+        assert_eq!(expected, super::decode_check_many_par(&inputs));
+        assert!(decode_check_many::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_base58_par() {
+        use super::{decode_check_par, decode_par, encode_check_par, encode_par, PAR_CHUNK_BLOCKS};
+
+        let chunk = PAR_CHUNK_BLOCKS * FULL_BLOCK_SIZE;
+        let data: Vec<u8> = (0..3 * chunk + 100).map(|i| (i * 7) as u8).collect();
+        // Around the chunk boundaries, with the checksum in a partial or full block
+        for len in [
+            0,
+            1,
+            8,
+            chunk - 4,
+            chunk,
+            chunk + 3,
+            2 * chunk + 8,
+            data.len(),
+        ] {
+            let data = &data[..len];
+            let encoded = encode(data).unwrap();
+            assert_eq!(encoded, encode_par(data).unwrap());
+            assert_eq!(data, decode_par(&encoded).unwrap());
+            let encoded = encode_check(data).unwrap();
+            assert_eq!(encoded, encode_check_par(data).unwrap());
+            assert_eq!(data, decode_check_par(&encoded).unwrap());
+        }
+
+        // The first invalid block is reported
+        let mut encoded = encode(&data).unwrap();
+        encoded.replace_range(3..4, "0");
+        encoded.replace_range(encoded.len() - 11.., "zzzzzzzzzzz");
+        assert_eq!(Err(Error::InvalidSymbol), decode_par(&encoded));
+        assert_eq!(Err(Error::InvalidBlockSize), decode_par("123456789AB1"));
+        assert_eq!(Err(Error::InvalidChecksum), decode_check_par("11"));
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_par("D7LMXYjUbXc1fS9Z")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_check_with() {
+        use super::{decode_check_with, encode_check_with, ChecksumAlgorithm, MoneroChecksum};
+        use sha2::{Digest, Sha256};
+
+        // Full 32 bytes SHA-256 tag
+        struct Sha(Sha256);
+
+        impl ChecksumAlgorithm for Sha {
+            fn size(&self) -> usize {
+                32
+            }
+
+            fn update(&mut self, data: &[u8]) {
+                self.0.update(data);
+            }
+
+            fn finalize(self) -> Vec<u8> {
+                self.0.finalize().to_vec()
+            }
+        }
+
+        let sha = || Sha(Sha256::new());
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let data = &data[..len];
+            let encoded = encode_check_with(data, MoneroChecksum::new()).unwrap();
+            assert_eq!(encode_check(data).unwrap(), encoded);
+            assert_eq!(
+                Ok(data.to_vec()),
+                decode_check_with(&encoded, MoneroChecksum::new())
+            );
+
+            let encoded = encode_check_with(data, sha()).unwrap();
+            let mut tagged = data.to_vec();
+            tagged.extend_from_slice(&Sha256::digest(data));
+            assert_eq!(encode(&tagged).unwrap(), encoded);
+            assert_eq!(Ok(data.to_vec()), decode_check_with(&encoded, sha()));
+            assert_eq!(Err(Error::InvalidChecksum), decode_check(&encoded));
+        }
+
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_with("D7LMXYjUbXc5LVkq6vWDY", sha())
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_with("D7LMXYjUbXc1fS9Z", MoneroChecksum::new())
+        );
+        assert_eq!(
+            Err(Error::InvalidSymbol),
+            decode_check_with("D7LMXYjUbXc1fS90", sha())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_decode_check_lossy() {
+        use super::{decode_check_lossy, Checksum};
+
+        let decoded = decode_check_lossy("D7LMXYjUbXc5LVkq6vWDY").unwrap();
+        assert!(decoded.is_valid());
+        assert_eq!(b"Hello World", &decoded.payload[..]);
+        assert_eq!(Ok(decoded.payload.clone()), decoded.into_checked());
+
+        // Last symbol mistyped, the payload is intact
+        let decoded = decode_check_lossy("D7LMXYjUbXc5LVkq6vWDZ").unwrap();
+        assert!(!decoded.is_valid());
+        assert_eq!(b"Hello World", &decoded.payload[..]);
+        assert_eq!(Checksum::from([0x59, 0x2f, 0xa7, 0x43]), decoded.expected);
+        assert_eq!(Err(Error::InvalidChecksum), decoded.into_checked());
+
+        for data in ["", "111", "10", "D7LMXYjUbXc1fS9Z", "64HByS"] {
+            assert_eq!(
+                decode_check(data),
+                decode_check_lossy(data).and_then(|d| d.into_checked())
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_check_keyed() {
+        use super::{decode_check_keyed, encode_check_keyed, ChecksumAlgorithm, KeyedChecksum};
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 4, 8, 9, 69, 256] {
+            let encoded = encode_check_keyed(&data[..len], b"key").unwrap();
+            assert_eq!(
+                Ok(Vec::from(&data[..len])),
+                decode_check_keyed(&encoded, b"key")
+            );
+            assert_eq!(
+                Err(Error::InvalidChecksum),
+                decode_check_keyed(&encoded, b"kez")
+            );
+            assert_ne!(encode_check(&data[..len]).unwrap(), encoded);
+
+            // Same tag with the incremental state
+            let mut tag = KeyedChecksum::new(b"key");
+            tag.update(&data[..len / 2]);
+            tag.update(&data[len / 2..len]);
+            assert_eq!(decode(&encoded).unwrap()[len..], tag.finalize()[..]);
+        }
+
+        let encoded = encode_check(b"Hello World").unwrap();
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_keyed(&encoded, b"")
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_keyed("111", b"key")
+        );
+        assert_eq!(Err(Error::InvalidSymbol), decode_check_keyed("10", b"key"));
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_encode_check_full() {
+        use super::encode_check_full;
+
+        let (encoded, checksum) = encode_check_full(b"Hello World").unwrap();
+        assert_eq!("D7LMXYjUbXc5LVkq6vWDY", encoded);
+        let decoded = decode(&encoded).unwrap();
+        assert_eq!(checksum, decoded[decoded.len() - 4..]);
+
+        let (encoded, checksum) = encode_check_full(b"").unwrap();
+        assert_eq!("64HByS", encoded);
+        assert_eq!(checksum, decode(&encoded).unwrap()[..]);
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_checksum() {
+        use super::Checksum;
+        use alloc::string::ToString;
+
+        let checksum = Checksum::from([0x46, 0x47, 0x45, 0x1e]);
+        assert_eq!("4647451e", checksum.to_string());
+        assert_eq!(checksum, Checksum::from([0x46, 0x47, 0x45, 0x1e]));
+        assert_ne!(checksum, Checksum::from([0x46, 0x47, 0x45, 0x1f]));
+        assert_eq!(checksum, [0x46, 0x47, 0x45, 0x1e][..]);
+        // Slices of another length are never equal
+        assert_ne!(checksum, [0x46, 0x47, 0x45][..]);
+        assert_ne!(checksum, [0x46, 0x47, 0x45, 0x1e, 0x00][..]);
+        assert_eq!([0x46, 0x47, 0x45, 0x1e], <[u8; 4]>::from(checksum));
+    }
+
+    #[test]
+    #[cfg(all(feature = "check", feature = "std"))]
+    fn test_base58_encode_check_to_writer() {
+        use super::encode_check_to_writer;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let mut out = Vec::new();
+            encode_check_to_writer(&data[..len], &mut out).unwrap();
+            assert_eq!(encode_check(&data[..len]).unwrap().as_bytes(), &out[..]);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "check", feature = "std"))]
+    fn test_base58_encode_check_from_reader() {
+        use super::encode_check_from_reader;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            // Split the input to get short reads
+            let (a, b) = data[..len].split_at(len / 3);
+            let mut out = Vec::new();
+            encode_check_from_reader(std::io::Read::chain(a, b), &mut out).unwrap();
+            assert_eq!(encode_check(&data[..len]).unwrap().as_bytes(), &out[..]);
+        }
+    }
+
     #[test]
     #[cfg(feature = "check")]
     fn test_base58_encode_check() {
@@ -1313,6 +7561,92 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_encode_decode_check_into() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut buf = [0u8; 512];
+        for len in 0..data.len() {
+            let encoded = encode_check(&data[..len]).unwrap();
+            assert_eq!(Ok(encoded.len()), encode_check_into(&data[..len], &mut buf));
+            assert_eq!(encoded.as_bytes(), &buf[..encoded.len()]);
+            // The checksum is not written
+            assert_eq!(Ok(len), decode_check_into(&encoded, &mut buf[..len]));
+            assert_eq!(&data[..len], &buf[..len]);
+        }
+
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_check_into(b"Hello World", &mut buf[..20])
+        );
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            decode_check_into("D7LMXYjUbXc5LVkq6vWDY", &mut buf[..10])
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_into("D7LMXYjUbXc1fS9Z", &mut buf)
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_into("11", &mut buf)
+        );
+        assert_eq!(
+            Err(Error::InvalidBlockSize),
+            decode_check_into("123456789AB1", &mut buf)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_check_to_array() {
+        use super::{decode_check_to_array, encode_check_to_array};
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            let encoded = encode_check(&data[..len]).unwrap();
+            let (buf, n) = encode_check_to_array::<358>(&data[..len]).unwrap();
+            assert_eq!(encoded.as_bytes(), &buf[..n]);
+            let (buf, n) = decode_check_to_array::<256>(&encoded).unwrap();
+            assert_eq!(&data[..len], &buf[..n]);
+        }
+
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_check_to_array::<20>(b"Hello World")
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_to_array::<32>("D7LMXYjUbXc1fS9Z")
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "check", feature = "heapless"))]
+    fn test_base58_check_heapless() {
+        use super::{decode_check_heapless, encode_check_heapless};
+
+        let encoded = encode_check_heapless::<21>(b"Hello World").unwrap();
+        assert_eq!("D7LMXYjUbXc5LVkq6vWDY", encoded.as_str());
+        assert_eq!(
+            b"Hello World",
+            decode_check_heapless::<11>(&encoded).unwrap().as_slice()
+        );
+
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_check_heapless::<20>(b"Hello World")
+        );
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            decode_check_heapless::<10>(&encoded)
+        );
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_heapless::<16>("D7LMXYjUbXc1fS9Z")
+        );
+    }
+
     #[cfg(all(feature = "check", feature = "stream"))]
     macro_rules! decode_stream_address {
         ($stream:expr, $expected:expr, $func:expr) => {
@@ -1346,6 +7680,34 @@ mod tests {
         };
     }
 
+    #[tokio::test]
+    #[cfg(all(feature = "check", feature = "stream"))]
+    async fn test_base58_decode_stream_check_seekable() {
+        use super::decode_stream_check_seekable;
+        use std::io::Cursor;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in (0..data.len()).step_by(7) {
+            let encoded = encode_check(&data[..len]).unwrap();
+            let mut out = Vec::new();
+            decode_stream_check_seekable(Cursor::new(encoded.as_bytes()), &mut out)
+                .await
+                .unwrap();
+            assert_eq!(&data[..len], &out[..]);
+        }
+
+        for (input, err) in [
+            ("D7LMXYjUbXc1fS9Z", Error::InvalidChecksum),
+            ("111", Error::InvalidChecksum),
+            ("10", Error::InvalidSymbol),
+        ] {
+            let mut out = Vec::new();
+            let res = decode_stream_check_seekable(Cursor::new(input.as_bytes()), &mut out).await;
+            assert_eq!(Err(err), res);
+            assert!(out.is_empty());
+        }
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     #[cfg(all(feature = "check", feature = "stream"))]
     async fn test_base58_decode_stream_check() {
@@ -1391,4 +7753,190 @@ mod tests {
             decode_stream_check
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(all(feature = "check", feature = "stream"))]
+    async fn test_base58_encode_stream_check_lengths() {
+        use super::encode_check;
+
+        // Empty input, tails shorter than, equal to and longer than the checksum
+        for len in 0..=2 * FULL_BLOCK_SIZE + 1 {
+            let data: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(29)).collect();
+            let s = encode_stream_check(&data[..]);
+            pin_mut!(s);
+            let mut res = String::new();
+            while let Some(c) = s.next().await {
+                res.push(c.unwrap());
+            }
+            assert_eq!(encode_check(&data).unwrap(), res, "{} bytes", len);
+        }
+        // Checksum of the empty string alone
+        assert_eq!("64HByS", encode_check(b"").unwrap());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[cfg(all(feature = "check", feature = "stream"))]
+    async fn test_base58_decode_stream_check_short() {
+        // Inputs decoding to less bytes than the checksum
+        for input in ["", "11", "5Q", "111", "11111"] {
+            let s = decode_stream_check(input.as_bytes());
+            pin_mut!(s);
+            let mut res = Vec::new();
+            while let Some(value) = s.next().await {
+                res.push(value);
+            }
+            assert_eq!(Vec::from([Err(Error::InvalidChecksum)]), res, "{:?}", input);
+            assert_eq!(Err(Error::InvalidChecksum), decode_check(input));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod proptests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use proptest::prelude::*;
+
+    use super::{
+        decode, encode, try_decode, try_encode, ENCODED_BLOCK_SIZES, FULL_BLOCK_SIZE,
+        FULL_ENCODED_BLOCK_SIZE,
+    };
+
+    #[cfg(feature = "check")]
+    use super::{decode_check, encode_check, CHECKSUM_SIZE};
+    #[cfg(feature = "stream")]
+    use super::{decode_stream, encode_stream, Result};
+    #[cfg(all(feature = "check", feature = "stream"))]
+    use super::{decode_stream_check, encode_stream_check};
+
+    #[cfg(feature = "stream")]
+    use futures_util::{pin_mut, stream::Stream, stream::StreamExt};
+
+    // Lengths straddling several block boundaries
+    fn bytes() -> impl Strategy<Value = Vec<u8>> {
+        proptest::collection::vec(any::<u8>(), 0..=4 * FULL_BLOCK_SIZE + 1)
+    }
+
+    // Mostly valid base58 strings, with a few symbols outside of the alphabet
+    fn base58() -> impl Strategy<Value = String> {
+        "[1-9A-HJ-NP-Za-km-z0OIl]{0,48}"
+    }
+
+    #[cfg(feature = "stream")]
+    fn collect<T>(stream: impl Stream<Item = Result<T>>) -> Result<Vec<T>> {
+        tokio_test::block_on(async {
+            pin_mut!(stream);
+            let mut res = Vec::new();
+            while let Some(value) = stream.next().await {
+                res.push(value?);
+            }
+            Ok(res)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(data in bytes()) {
+            let encoded = encode(&data).unwrap();
+            let len = data.len() / FULL_BLOCK_SIZE * FULL_ENCODED_BLOCK_SIZE
+                + ENCODED_BLOCK_SIZES[data.len() % FULL_BLOCK_SIZE];
+            prop_assert_eq!(len, encoded.len());
+            prop_assert_eq!(Ok(data), decode(&encoded));
+        }
+
+        #[test]
+        fn canonical(s in base58()) {
+            // A decoded string is always re-encoded to itself
+            if let Ok(data) = decode(&s) {
+                prop_assert_eq!(Ok(s), encode(&data));
+            }
+        }
+
+        #[test]
+        fn fallible_allocation(data in bytes(), s in base58()) {
+            prop_assert_eq!(encode(&data), try_encode(&data));
+            prop_assert_eq!(decode(&s), try_decode(&s));
+        }
+
+        #[test]
+        #[cfg(feature = "check")]
+        fn roundtrip_check(data in bytes()) {
+            let encoded = encode_check(&data).unwrap();
+            prop_assert_eq!(Ok(data.clone()), decode_check(&encoded));
+            // Check mode is the plain encoding of the data followed by its checksum
+            let decoded = decode(&encoded).unwrap();
+            prop_assert_eq!(data.len() + CHECKSUM_SIZE, decoded.len());
+            prop_assert_eq!(&data[..], &decoded[..data.len()]);
+        }
+
+        #[test]
+        #[cfg(feature = "stream")]
+        fn stream_equivalence(data in bytes(), s in base58()) {
+            let encoded = collect(encode_stream(&data[..]))
+                .map(|chars| chars.into_iter().collect::<String>());
+            prop_assert_eq!(encode(&data), encoded);
+            prop_assert_eq!(decode(&s), collect(decode_stream(s.as_bytes())));
+        }
+
+        #[test]
+        #[cfg(all(feature = "check", feature = "stream"))]
+        fn stream_check_equivalence(data in bytes()) {
+            let encoded = encode_check(&data).unwrap();
+            let stream = collect(encode_stream_check(&data[..]))
+                .map(|chars| chars.into_iter().collect::<String>());
+            prop_assert_eq!(Ok(encoded.clone()), stream);
+            prop_assert_eq!(decode_check(&encoded), collect(decode_stream_check(encoded.as_bytes())));
+        }
+    }
+}
+
+// The slice functions are the whole API without `alloc`
+#[cfg(all(test, not(feature = "alloc")))]
+mod no_alloc_tests {
+    use super::{decode_into, decode_to_array, encode_into, encode_to_array, Error};
+
+    #[cfg(feature = "check")]
+    use super::{
+        decode_check_into, decode_check_to_array, encode_check_into, encode_check_to_array,
+    };
+
+    #[test]
+    fn test_base58_no_alloc() {
+        let mut buf = [0u8; 32];
+        assert_eq!(Ok(16), encode_into(b"Hello World", &mut buf));
+        assert_eq!(b"D7LMXYjUbXc1fS9Z", &buf[..16]);
+        assert_eq!(Ok(11), decode_into("D7LMXYjUbXc1fS9Z", &mut buf));
+        assert_eq!(b"Hello World", &buf[..11]);
+        assert_eq!(
+            Err(Error::BufferTooSmall),
+            encode_into(b"Hello World", &mut buf[..15])
+        );
+        assert_eq!(Err(Error::InvalidSymbol), decode_into("11I", &mut buf));
+
+        let (buf, len) = encode_to_array::<16>(b"Hello World").unwrap();
+        assert_eq!(b"D7LMXYjUbXc1fS9Z", &buf[..len]);
+        let (buf, len) = decode_to_array::<11>("D7LMXYjUbXc1fS9Z").unwrap();
+        assert_eq!(b"Hello World", &buf[..len]);
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_base58_no_alloc_check() {
+        let mut buf = [0u8; 32];
+        assert_eq!(Ok(21), encode_check_into(b"Hello World", &mut buf));
+        assert_eq!(b"D7LMXYjUbXc5LVkq6vWDY", &buf[..21]);
+        assert_eq!(Ok(11), decode_check_into("D7LMXYjUbXc5LVkq6vWDY", &mut buf));
+        assert_eq!(b"Hello World", &buf[..11]);
+        assert_eq!(
+            Err(Error::InvalidChecksum),
+            decode_check_into("D7LMXYjUbXc1fS9Z", &mut buf)
+        );
+
+        let (buf, len) = encode_check_to_array::<21>(b"Hello World").unwrap();
+        assert_eq!(b"D7LMXYjUbXc5LVkq6vWDY", &buf[..len]);
+        let (buf, len) = decode_check_to_array::<11>("D7LMXYjUbXc5LVkq6vWDY").unwrap();
+        assert_eq!(b"Hello World", &buf[..len]);
+    }
 }
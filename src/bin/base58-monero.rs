@@ -0,0 +1,353 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Command line tool to encode and decode Monero base58 strings
+//!
+//! Reads from a file or stdin and writes the result on stdout. Exits with `1` when the input
+//! cannot be encoded or decoded, `2` on invalid arguments and `3` on IO errors.
+//!
+//! The `validate` subcommand checks Monero addresses given as arguments or on stdin, one per line,
+//! and prints the network and type of each address. It exits with `1` if any address is invalid.
+//!
+//! With `--stream` the input is processed with the async stream functions in constant memory and
+//! a progress bar is displayed on stderr.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::process::ExitCode;
+use std::task::{ready, Context, Poll};
+
+use base58_monero::address::{AddressKind, Network};
+use base58_monero::{
+    classify_address, decode, decode_check, decode_stream, decode_stream_check, encode,
+    encode_check, encode_stream, encode_stream_check, Error,
+};
+use clap::{Parser, Subcommand};
+use futures_util::pin_mut;
+use futures_util::stream::{Stream, StreamExt};
+use indicatif::{ProgressBar, ProgressStyle};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Encode or decode Monero base58 data
+#[derive(Debug, Parser)]
+#[command(
+    name = "base58-monero",
+    version,
+    about,
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Decode base58 input instead of encoding
+    #[arg(short, long)]
+    decode: bool,
+
+    /// Append (encode) or verify and strip (decode) the 4 bytes checksum
+    #[arg(short, long)]
+    check: bool,
+
+    /// Read hex input when encoding, write hex output when decoding
+    #[arg(short = 'x', long, conflicts_with = "stream")]
+    hex: bool,
+
+    /// Process the input in constant memory and display a progress bar
+    #[arg(short, long)]
+    stream: bool,
+
+    /// File to read, stdin when omitted or `-`
+    file: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validate Monero addresses and print their network and type
+    Validate {
+        /// Addresses to validate, read from stdin one per line when omitted
+        addresses: Vec<String>,
+    },
+}
+
+// Failures reported to the user, each one maps to a distinct exit code
+enum Failure {
+    Data(Error),
+    Hex(hex::FromHexError),
+    InvalidAddresses(usize),
+    Io(io::Error),
+}
+
+impl Failure {
+    fn exit_code(&self) -> ExitCode {
+        match self {
+            Failure::Data(_) | Failure::Hex(_) | Failure::InvalidAddresses(_) => ExitCode::from(1),
+            Failure::Io(_) => ExitCode::from(3),
+        }
+    }
+}
+
+impl core::fmt::Display for Failure {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Failure::Data(e) => write!(f, "{}", e),
+            Failure::Hex(e) => write!(f, "Invalid hex input: {}", e),
+            Failure::InvalidAddresses(n) => write!(f, "{} invalid address(es)", n),
+            Failure::Io(e) => write!(f, "IO error: {}", e),
+        }
+    }
+}
+
+impl From<Error> for Failure {
+    fn from(e: Error) -> Self {
+        match e {
+            // Read errors of the stream functions are IO errors, not invalid data
+            Error::Io(e) => Failure::Io(e),
+            e => Failure::Data(e),
+        }
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(e: io::Error) -> Self {
+        Failure::Io(e)
+    }
+}
+
+fn read_input(file: Option<&PathBuf>) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    match file {
+        Some(path) if path.as_os_str() != "-" => File::open(path)?.read_to_end(&mut buf)?,
+        _ => io::stdin().lock().read_to_end(&mut buf)?,
+    };
+    Ok(buf)
+}
+
+// Async reader advancing a progress bar with the number of bytes read, optionally dropping
+// ASCII whitespaces from the data read, e.g. the trailing new line of a base58 input
+struct ProgressReader<R> {
+    inner: R,
+    progress: ProgressBar,
+    skip_whitespace: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let start = buf.filled().len();
+        loop {
+            ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+            let read = buf.filled().len() - start;
+            self.progress.inc(read as u64);
+            if read == 0 || !self.skip_whitespace {
+                return Poll::Ready(Ok(()));
+            }
+
+            let mut len = start;
+            for i in start..start + read {
+                let b = buf.filled()[i];
+                if !b.is_ascii_whitespace() {
+                    buf.filled_mut()[len] = b;
+                    len += 1;
+                }
+            }
+            buf.set_filled(len);
+            // Reading only whitespaces must not be reported as EOF
+            if len > start {
+                return Poll::Ready(Ok(()));
+            }
+        }
+    }
+}
+
+async fn write_chars<S>(stream: S, out: &mut impl Write) -> Result<(), Failure>
+where
+    S: Stream<Item = base58_monero::base58::Result<char>>,
+{
+    pin_mut!(stream);
+    while let Some(value) = stream.next().await {
+        // Base58 alphabet is ASCII only
+        out.write_all(&[value? as u8])?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+async fn write_bytes<S>(stream: S, out: &mut impl Write) -> Result<(), Failure>
+where
+    S: Stream<Item = base58_monero::base58::Result<u8>>,
+{
+    pin_mut!(stream);
+    while let Some(value) = stream.next().await {
+        out.write_all(&[value?])?;
+    }
+    Ok(())
+}
+
+async fn run_stream(cli: &Cli) -> Result<(), Failure> {
+    let (progress, inner): (ProgressBar, Box<dyn AsyncRead + Unpin>) = match cli.file.as_ref() {
+        Some(path) if path.as_os_str() != "-" => {
+            let file = tokio::fs::File::open(path).await?;
+            let style = ProgressStyle::with_template("{bar:40} {bytes}/{total_bytes} ({eta})")
+                .expect("valid progress template");
+            let len = file.metadata().await?.len();
+            (ProgressBar::new(len).with_style(style), Box::new(file))
+        }
+        _ => (ProgressBar::new_spinner(), Box::new(tokio::io::stdin())),
+    };
+    let reader = ProgressReader {
+        inner,
+        progress: progress.clone(),
+        skip_whitespace: cli.decode,
+    };
+    let mut stdout = BufWriter::new(io::stdout().lock());
+
+    match (cli.decode, cli.check) {
+        (false, false) => write_chars(encode_stream(reader), &mut stdout).await?,
+        (false, true) => write_chars(encode_stream_check(reader), &mut stdout).await?,
+        (true, false) => write_bytes(decode_stream(reader), &mut stdout).await?,
+        (true, true) => write_bytes(decode_stream_check(reader), &mut stdout).await?,
+    }
+
+    stdout.flush()?;
+    progress.finish_and_clear();
+    Ok(())
+}
+
+fn describe(kind: AddressKind) -> String {
+    let network = match kind.network() {
+        Network::Mainnet => "mainnet",
+        Network::Testnet => "testnet",
+        Network::Stagenet => "stagenet",
+    };
+    let kind = match kind {
+        AddressKind::Standard(_) => "standard",
+        AddressKind::Integrated(_) => "integrated",
+        AddressKind::Subaddress(_) => "subaddress",
+    };
+    format!("{} {}", network, kind)
+}
+
+fn run_validate(addresses: &[String]) -> Result<(), Failure> {
+    let mut stdout = io::stdout().lock();
+    let mut invalid = 0;
+    let mut validate = |addr: &str| -> io::Result<()> {
+        match classify_address(addr) {
+            Ok(kind) => writeln!(stdout, "{}: {}", addr, describe(kind)),
+            Err(e) => {
+                invalid += 1;
+                writeln!(stdout, "{}: invalid ({})", addr, e)
+            }
+        }
+    };
+
+    if addresses.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line?;
+            let addr = line.trim();
+            if !addr.is_empty() {
+                validate(addr)?;
+            }
+        }
+    } else {
+        for addr in addresses {
+            validate(addr)?;
+        }
+    }
+
+    match invalid {
+        0 => Ok(()),
+        n => Err(Failure::InvalidAddresses(n)),
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), Failure> {
+    if let Some(Command::Validate { addresses }) = &cli.command {
+        return run_validate(addresses);
+    }
+    if cli.stream {
+        let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+        return runtime.block_on(run_stream(cli));
+    }
+
+    let input = read_input(cli.file.as_ref())?;
+    let mut stdout = io::stdout().lock();
+
+    if cli.decode {
+        // Symbols outside of the alphabet are rejected by the decoder, only strip whitespaces
+        let input = String::from_utf8_lossy(&input);
+        let input = input.trim();
+        let bytes = if cli.check {
+            decode_check(input)?
+        } else {
+            decode(input)?
+        };
+        if cli.hex {
+            writeln!(stdout, "{}", hex::encode(bytes))?;
+        } else {
+            stdout.write_all(&bytes)?;
+        }
+    } else {
+        let bytes = if cli.hex {
+            let input = String::from_utf8_lossy(&input);
+            hex::decode(input.trim()).map_err(Failure::Hex)?
+        } else {
+            input
+        };
+        let encoded = if cli.check {
+            encode_check(&bytes)?
+        } else {
+            encode(&bytes)?
+        };
+        writeln!(stdout, "{}", encoded)?;
+    }
+
+    stdout.flush()?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("base58-monero: {}", e);
+            e.exit_code()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use base58_monero::Error;
+
+    use super::Failure;
+
+    #[test]
+    fn test_failure_from_error() {
+        let failure = Failure::from(Error::Io(io::Error::other("read")));
+        assert!(matches!(failure, Failure::Io(_)));
+        assert!(matches!(
+            Failure::from(Error::InvalidSymbol),
+            Failure::Data(Error::InvalidSymbol)
+        ));
+    }
+}
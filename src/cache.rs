@@ -0,0 +1,246 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Memoization of base58-check encodings
+//!
+//! [`EncodeCheckCache`] keeps the base58-check strings of the most recently encoded payloads, so
+//! re-encoding the same payload skips both the Keccak checksum and the block conversion. The cache
+//! is bounded and evicts the least recently used entry when full.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::cache::EncodeCheckCache;
+//!
+//! let mut cache = EncodeCheckCache::new(1024);
+//! assert_eq!("D7LMXYjUbXc5LVkq6vWDY", cache.encode_check(b"Hello World")?);
+//! // Served from the cache
+//! assert_eq!("D7LMXYjUbXc5LVkq6vWDY", cache.encode_check(b"Hello World")?);
+//! assert_eq!(1, cache.len());
+//! # Ok::<(), base58_monero::Error>(())
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+use crate::base58::{encode_check, Result};
+
+// Index of a missing link in the recency list
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    hash: u64,
+    data: Vec<u8>,
+    encoded: String,
+    // Neighbours in the recency list, from the most to the least recently used
+    prev: usize,
+    next: usize,
+}
+
+/// Bounded least recently used cache of base58-check encodings
+///
+/// Entries are keyed by a hash of the payload, the payload is stored to verify hits: a hash
+/// collision is a miss and replaces the colliding entry.
+#[derive(Debug, Clone)]
+pub struct EncodeCheckCache {
+    capacity: usize,
+    map: HashMap<u64, usize>,
+    entries: Vec<Entry>,
+    // Most and least recently used entries
+    head: usize,
+    tail: usize,
+    state: RandomState,
+}
+
+impl EncodeCheckCache {
+    /// Create a cache holding at most `capacity` encodings, a zero capacity disables caching
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            map: HashMap::new(),
+            entries: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            state: RandomState::new(),
+        }
+    }
+
+    /// Encode a byte vector into a base58-check string, see [`encode_check`]
+    ///
+    /// The encoding is returned from the cache when the payload is cached, otherwise it is
+    /// computed and cached, evicting the least recently used entry if the cache is full.
+    pub fn encode_check(&mut self, data: impl AsRef<[u8]>) -> Result<String> {
+        let data = data.as_ref();
+        let hash = self.state.hash_one(data);
+
+        let slot = self.map.get(&hash).copied();
+        if let Some(i) = slot {
+            if self.entries[i].data == data {
+                self.touch(i);
+                return Ok(self.entries[i].encoded.clone());
+            }
+        }
+
+        let encoded = encode_check(data)?;
+        if self.capacity == 0 {
+            return Ok(encoded);
+        }
+        let i = match slot {
+            // Hash collision, replace the colliding entry
+            Some(i) => i,
+            None if self.entries.len() < self.capacity => {
+                self.entries.push(Entry {
+                    hash,
+                    data: Vec::new(),
+                    encoded: String::new(),
+                    prev: NIL,
+                    next: NIL,
+                });
+                self.link_front(self.entries.len() - 1);
+                self.entries.len() - 1
+            }
+            None => {
+                let i = self.tail;
+                self.map.remove(&self.entries[i].hash);
+                i
+            }
+        };
+        let entry = &mut self.entries[i];
+        entry.hash = hash;
+        entry.data.clear();
+        entry.data.extend_from_slice(data);
+        entry.encoded.clone_from(&encoded);
+        self.map.insert(hash, i);
+        self.touch(i);
+        Ok(encoded)
+    }
+
+    /// Return the number of cached encodings
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return true if no encoding is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the maximum number of cached encodings
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Remove all cached encodings
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.entries.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    // Move an entry to the front of the recency list
+    fn touch(&mut self, i: usize) {
+        if self.head == i {
+            return;
+        }
+        let (prev, next) = (self.entries[i].prev, self.entries[i].next);
+        if prev != NIL {
+            self.entries[prev].next = next;
+        }
+        if next != NIL {
+            self.entries[next].prev = prev;
+        } else {
+            self.tail = prev;
+        }
+        self.link_front(i);
+    }
+
+    // Insert an unlinked entry at the front of the recency list
+    fn link_front(&mut self, i: usize) {
+        self.entries[i].prev = NIL;
+        self.entries[i].next = self.head;
+        if self.head != NIL {
+            self.entries[self.head].prev = i;
+        } else {
+            self.tail = i;
+        }
+        self.head = i;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use super::EncodeCheckCache;
+    use crate::base58::encode_check;
+
+    // Cached payloads, from the most to the least recently used
+    fn cached(cache: &EncodeCheckCache) -> Vec<Vec<u8>> {
+        let mut res = Vec::new();
+        let mut i = cache.head;
+        while i != super::NIL {
+            res.push(cache.entries[i].data.clone());
+            i = cache.entries[i].next;
+        }
+        assert_eq!(cache.len(), res.len());
+        assert_eq!(cache.len(), cache.map.len());
+        res
+    }
+
+    #[test]
+    fn test_cache_lru() {
+        let mut cache = EncodeCheckCache::new(2);
+        for data in [&b"a"[..], b"b", b"a", b"c"] {
+            assert_eq!(encode_check(data), cache.encode_check(data));
+        }
+        // "b" is the least recently used entry
+        assert_eq!(vec![b"c".to_vec(), b"a".to_vec()], cached(&cache));
+
+        assert_eq!(encode_check(b"a"), cache.encode_check(b"a"));
+        assert_eq!(vec![b"a".to_vec(), b"c".to_vec()], cached(&cache));
+        assert_eq!(encode_check(b"b"), cache.encode_check(b"b"));
+        assert_eq!(vec![b"b".to_vec(), b"a".to_vec()], cached(&cache));
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(encode_check(b""), cache.encode_check(b""));
+        assert_eq!(vec![Vec::<u8>::new()], cached(&cache));
+    }
+
+    #[test]
+    fn test_cache_many() {
+        let mut cache = EncodeCheckCache::new(16);
+        let data: Vec<u8> = (0..=255).collect();
+        for round in 0..3 {
+            for len in 0..64 {
+                let data = &data[round..round + len];
+                assert_eq!(encode_check(data), cache.encode_check(data));
+            }
+        }
+        assert_eq!(16, cache.len());
+        assert_eq!(16, cached(&cache).len());
+
+        let mut cache = EncodeCheckCache::new(0);
+        assert_eq!(encode_check(b"a"), cache.encode_check(b"a"));
+        assert!(cache.is_empty());
+    }
+}
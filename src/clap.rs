@@ -0,0 +1,261 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Clap value parser of base58 arguments
+//!
+//! [`Base58ValueParser<T>`] decodes a base58, or base58-check, command line argument and converts
+//! the bytes into `T`, e.g. `Vec<u8>` or `[u8; 32]` to require an exact length. Invalid arguments
+//! are reported by clap as `ValueValidation` errors naming the argument and the reason.
+//!
+//! The [`Base58String`](crate::Base58String) and [`Base58Bytes`](crate::Base58Bytes) wrapper
+//! types implement `FromStr` and can also be used directly with `value_parser!`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::clap::Base58ValueParser;
+//! use clap::{Arg, Command};
+//!
+//! let cmd = Command::new("tool").arg(
+//!     Arg::new("key")
+//!         .long("key")
+//!         .value_parser(Base58ValueParser::<[u8; 11]>::new().with_len(11)),
+//! );
+//!
+//! let matches = cmd.clone().get_matches_from(["tool", "--key", "D7LMXYjUbXc1fS9Z"]);
+//! assert_eq!(Some(b"Hello World"), matches.get_one::<[u8; 11]>("key"));
+//!
+//! let err = cmd.try_get_matches_from(["tool", "--key", "11"]).unwrap_err();
+//! assert_eq!(clap::error::ErrorKind::ValueValidation, err.kind());
+//! assert!(err.to_string().contains("expected 11 bytes, found 1"));
+//! ```
+
+extern crate alloc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use std::ffi::OsStr;
+
+use ::clap::builder::TypedValueParser;
+use ::clap::error::ErrorKind;
+use ::clap::{Arg, Command};
+
+use crate::base58::decode;
+#[cfg(feature = "check")]
+use crate::base58::decode_check;
+
+/// Value parser decoding a base58 argument into `T`
+///
+/// The conversion from the decoded bytes into `T` can only fail on the length, e.g. for
+/// `[u8; N]`. Set the expected length with [`with_len`](Self::with_len) to report it in errors.
+pub struct Base58ValueParser<T> {
+    #[cfg(feature = "check")]
+    check: bool,
+    len: Option<usize>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Base58ValueParser<T> {
+    /// Create a parser of base58 arguments without checksum
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "check")]
+            check: false,
+            len: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Require the decoded argument to be `len` bytes long
+    pub fn with_len(self, len: usize) -> Self {
+        Self {
+            len: Some(len),
+            ..self
+        }
+    }
+
+    /// Verify and strip the 4 bytes checksum of the arguments
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    pub fn check(self) -> Self {
+        Self {
+            check: true,
+            ..self
+        }
+    }
+}
+
+impl<T> Default for Base58ValueParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Derived implementations would require `T: Clone`
+impl<T> Clone for Base58ValueParser<T> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "check")]
+            check: self.check,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for Base58ValueParser<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let mut s = f.debug_struct("Base58ValueParser");
+        #[cfg(feature = "check")]
+        s.field("check", &self.check);
+        s.field("len", &self.len).finish()
+    }
+}
+
+impl<T> TypedValueParser for Base58ValueParser<T>
+where
+    T: TryFrom<Vec<u8>> + Clone + Send + Sync + 'static,
+{
+    type Value = T;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Self::Value, ::clap::Error> {
+        let invalid = |reason: &dyn core::fmt::Display| {
+            let arg = arg.map_or_else(|| "...".into(), |a| a.to_string());
+            cmd.clone().error(
+                ErrorKind::ValueValidation,
+                format!(
+                    "invalid value '{}' for '{}': {}",
+                    value.to_string_lossy(),
+                    arg,
+                    reason
+                ),
+            )
+        };
+        let value_str = value.to_str().ok_or_else(|| invalid(&"not valid UTF-8"))?;
+        #[cfg(feature = "check")]
+        let decoded = if self.check {
+            decode_check(value_str)
+        } else {
+            decode(value_str)
+        };
+        #[cfg(not(feature = "check"))]
+        let decoded = decode(value_str);
+        let bytes = decoded.map_err(|e| invalid(&e))?;
+        let len = bytes.len();
+        match self.len {
+            Some(expected) if expected != len => Err(invalid(&format_args!(
+                "expected {} bytes, found {}",
+                expected, len
+            ))),
+            _ => T::try_from(bytes)
+                .map_err(|_| invalid(&format_args!("invalid length of {} bytes", len))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use ::clap::error::ErrorKind;
+    use ::clap::{Arg, Command};
+
+    use super::Base58ValueParser;
+
+    fn cmd() -> Command {
+        Command::new("tool")
+            .arg(
+                Arg::new("raw")
+                    .long("raw")
+                    .value_parser(Base58ValueParser::<Vec<u8>>::new()),
+            )
+            .arg(
+                Arg::new("key")
+                    .long("key")
+                    .value_parser(Base58ValueParser::<[u8; 11]>::new()),
+            )
+            .arg(
+                Arg::new("len")
+                    .long("len")
+                    .value_parser(Base58ValueParser::<Vec<u8>>::new().with_len(11)),
+            )
+    }
+
+    #[test]
+    fn test_value_parser() {
+        let matches = cmd()
+            .try_get_matches_from([
+                "tool",
+                "--raw",
+                "D7LMXYjUbXc1fS9Z",
+                "--key",
+                "D7LMXYjUbXc1fS9Z",
+            ])
+            .unwrap();
+        assert_eq!(
+            Some(&Vec::from(&b"Hello World"[..])),
+            matches.get_one::<Vec<u8>>("raw")
+        );
+        assert_eq!(Some(b"Hello World"), matches.get_one::<[u8; 11]>("key"));
+
+        let err = cmd()
+            .try_get_matches_from(["tool", "--raw", "10"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err
+            .to_string()
+            .contains("invalid value '10' for '--raw <raw>': Invalid symbol error"));
+
+        let err = cmd()
+            .try_get_matches_from(["tool", "--key", "11"])
+            .unwrap_err();
+        assert_eq!(ErrorKind::ValueValidation, err.kind());
+        assert!(err.to_string().contains("invalid length of 1 bytes"));
+        let err = cmd()
+            .try_get_matches_from(["tool", "--len", "11"])
+            .unwrap_err();
+        assert!(err.to_string().contains("expected 11 bytes, found 1"));
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_value_parser_check() {
+        let cmd = Command::new("tool").arg(
+            Arg::new("key")
+                .long("key")
+                .value_parser(Base58ValueParser::<[u8; 11]>::new().check().with_len(11)),
+        );
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["tool", "--key", "D7LMXYjUbXc5LVkq6vWDY"])
+            .unwrap();
+        assert_eq!(Some(b"Hello World"), matches.get_one::<[u8; 11]>("key"));
+
+        let err = cmd
+            .clone()
+            .try_get_matches_from(["tool", "--key", "D7LMXYjUbXc1fS9Z"])
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid checksum error"));
+        let err = cmd
+            .try_get_matches_from(["tool", "--key", "64HByS"])
+            .unwrap_err();
+        assert!(err.to_string().contains("expected 11 bytes, found 0"));
+    }
+}
@@ -0,0 +1,517 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Codec framing base58 payloads on async transports
+//!
+//! [`Base58Codec`] encodes each payload as a base58 string, optionally with the 4 bytes checksum,
+//! followed by a `\n`, and decodes newline-delimited base58 strings back into payloads. A trailing
+//! `\r` is stripped from decoded lines and the last line does not need a delimiter. With the
+//! `check` feature `Base58CheckCodec` is the same codec always using the checksum.
+//!
+//! With the `asynchronous-codec` feature the codec implements the `asynchronous-codec` traits, to
+//! be used with `Framed` transports on `futures` based stacks, e.g. async-std or smol. With the
+//! `tokio-util` feature it implements the `tokio_util::codec` traits, to be used with `Framed`
+//! transports on `tokio` stacks.
+//!
+//! Lines split across reads, e.g. across TCP segments, are buffered until their delimiter is
+//! received.
+//!
+//! Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors wrapping
+//! [`Error`](enum@Error), lines longer than the maximum length as [`Error::InvalidLength`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "asynchronous-codec")]
+//! # fn main() -> std::io::Result<()> {
+//! use asynchronous_codec::{BytesMut, Decoder, Encoder};
+//! use base58_monero::codec::Base58Codec;
+//!
+//! let mut codec = Base58Codec::new();
+//! let mut buf = BytesMut::new();
+//! codec.encode(Vec::from(&b"Hello World"[..]), &mut buf)?;
+//! assert_eq!(&b"D7LMXYjUbXc1fS9Z\n"[..], &buf[..]);
+//! assert_eq!(Some(Vec::from(&b"Hello World"[..])), codec.decode(&mut buf)?);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "asynchronous-codec"))]
+//! # fn main() {}
+//! ```
+
+extern crate alloc;
+use alloc::vec::Vec;
+use std::io;
+
+#[cfg(feature = "tokio-util")]
+use bytes::Bytes;
+use bytes::BytesMut;
+
+use crate::base58::{decode, encode, Error};
+#[cfg(feature = "check")]
+use crate::base58::{decode_check, encode_check};
+
+/// Default maximum length of a line, in bytes, see [`Base58Codec::with_max_length`]
+pub const DEFAULT_MAX_LENGTH: usize = 64 * 1024;
+
+/// Newline-delimited base58 codec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base58Codec {
+    #[cfg(feature = "check")]
+    check: bool,
+    max_length: usize,
+}
+
+impl Base58Codec {
+    /// Create a codec of base58 strings without checksum
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "check")]
+            check: false,
+            max_length: DEFAULT_MAX_LENGTH,
+        }
+    }
+
+    /// Create a codec of base58-check strings, the checksum is verified when decoding
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    pub fn new_check() -> Self {
+        Self {
+            check: true,
+            ..Self::new()
+        }
+    }
+
+    /// Set the maximum length of a line, delimiter excluded, longer lines are rejected
+    ///
+    /// Bounds the memory buffered while waiting for a delimiter from an untrusted peer.
+    pub fn with_max_length(self, max_length: usize) -> Self {
+        Self { max_length, ..self }
+    }
+
+    /// Return the maximum length of a line
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    // Append the encoded payload and its delimiter to `dst`
+    fn encode_frame(&self, item: &[u8], dst: &mut BytesMut) -> io::Result<()> {
+        #[cfg(feature = "check")]
+        let encoded = if self.check {
+            encode_check(item)
+        } else {
+            encode(item)
+        };
+        #[cfg(not(feature = "check"))]
+        let encoded = encode(item);
+        let encoded = encoded.map_err(invalid_data)?;
+        dst.reserve(encoded.len() + 1);
+        dst.extend_from_slice(encoded.as_bytes());
+        dst.extend_from_slice(b"\n");
+        Ok(())
+    }
+
+    // Decode the first line of `src`, the whole buffer at EOF
+    fn decode_frame(&self, src: &mut BytesMut, eof: bool) -> io::Result<Option<Vec<u8>>> {
+        let (mut len, frame_len) = match src.iter().position(|&b| b == b'\n') {
+            Some(len) => (len, len + 1),
+            None if eof => (src.len(), src.len()),
+            None => (src.len(), 0),
+        };
+        if src[..len].ends_with(b"\r") {
+            len -= 1;
+        }
+        if len > self.max_length {
+            return Err(invalid_data(Error::InvalidLength));
+        }
+        if frame_len == 0 {
+            return Ok(None);
+        }
+        let frame = src.split_to(frame_len);
+        let line = &frame[..len];
+        // Symbols outside of the alphabet are rejected by the decoder
+        let line = core::str::from_utf8(line).map_err(|_| invalid_data(Error::InvalidSymbol))?;
+        #[cfg(feature = "check")]
+        let decoded = if self.check {
+            decode_check(line)
+        } else {
+            decode(line)
+        };
+        #[cfg(not(feature = "check"))]
+        let decoded = decode(line);
+        decoded.map(Some).map_err(invalid_data)
+    }
+}
+
+impl Default for Base58Codec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Newline-delimited base58-check codec, the checksum is verified when decoding
+///
+/// Same as [`Base58Codec::new_check`], as a distinct type to name the checked framing in
+/// signatures.
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base58CheckCodec(Base58Codec);
+
+#[cfg(feature = "check")]
+impl Base58CheckCodec {
+    /// Create a codec of base58-check strings
+    pub fn new() -> Self {
+        Self(Base58Codec::new_check())
+    }
+
+    /// Set the maximum length of a line, delimiter excluded, longer lines are rejected
+    ///
+    /// See [`Base58Codec::with_max_length`].
+    pub fn with_max_length(self, max_length: usize) -> Self {
+        Self(self.0.with_max_length(max_length))
+    }
+
+    /// Return the maximum length of a line
+    pub fn max_length(&self) -> usize {
+        self.0.max_length()
+    }
+}
+
+#[cfg(feature = "check")]
+impl Default for Base58CheckCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "check")]
+impl From<Base58CheckCodec> for Base58Codec {
+    fn from(codec: Base58CheckCodec) -> Self {
+        codec.0
+    }
+}
+
+fn invalid_data(e: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(feature = "asynchronous-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asynchronous-codec")))]
+impl asynchronous_codec::Encoder for Base58Codec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut BytesMut) -> io::Result<()> {
+        self.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(feature = "asynchronous-codec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asynchronous-codec")))]
+impl asynchronous_codec::Decoder for Base58Codec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.decode_frame(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.decode_frame(src, true)
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-util")))]
+impl tokio_util::codec::Encoder<Vec<u8>> for Base58Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut BytesMut) -> io::Result<()> {
+        self.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-util")))]
+impl tokio_util::codec::Encoder<Bytes> for Base58Codec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        self.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(feature = "tokio-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio-util")))]
+impl tokio_util::codec::Decoder for Base58Codec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.decode_frame(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.decode_frame(src, true)
+    }
+}
+
+#[cfg(all(feature = "asynchronous-codec", feature = "check"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "asynchronous-codec", feature = "check")))
+)]
+impl asynchronous_codec::Encoder for Base58CheckCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut BytesMut) -> io::Result<()> {
+        self.0.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(all(feature = "asynchronous-codec", feature = "check"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "asynchronous-codec", feature = "check")))
+)]
+impl asynchronous_codec::Decoder for Base58CheckCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.0.decode_frame(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.0.decode_frame(src, true)
+    }
+}
+
+#[cfg(all(feature = "tokio-util", feature = "check"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tokio-util", feature = "check"))))]
+impl tokio_util::codec::Encoder<Vec<u8>> for Base58CheckCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Vec<u8>, dst: &mut BytesMut) -> io::Result<()> {
+        self.0.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(all(feature = "tokio-util", feature = "check"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tokio-util", feature = "check"))))]
+impl tokio_util::codec::Encoder<Bytes> for Base58CheckCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Bytes, dst: &mut BytesMut) -> io::Result<()> {
+        self.0.encode_frame(&item, dst)
+    }
+}
+
+#[cfg(all(feature = "tokio-util", feature = "check"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "tokio-util", feature = "check"))))]
+impl tokio_util::codec::Decoder for Base58CheckCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.0.decode_frame(src, false)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Self::Item>> {
+        self.0.decode_frame(src, true)
+    }
+}
+
+#[cfg(all(test, feature = "asynchronous-codec"))]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use asynchronous_codec::{BytesMut, Decoder, FramedRead, FramedWrite};
+    use futures_util::{SinkExt, StreamExt};
+
+    use super::Base58Codec;
+    use crate::base58::Error;
+
+    fn payloads() -> Vec<Vec<u8>> {
+        let data: Vec<u8> = (0..=255).collect();
+        (0..64).map(|len| Vec::from(&data[..len])).collect()
+    }
+
+    #[test]
+    fn test_codec_framed() {
+        tokio_test::block_on(async {
+            #[cfg(feature = "check")]
+            let codecs = [Base58Codec::new(), Base58Codec::new_check()];
+            #[cfg(not(feature = "check"))]
+            let codecs = [Base58Codec::new()];
+            for codec in codecs {
+                let mut framed = FramedWrite::new(Vec::new(), codec);
+                for payload in payloads() {
+                    framed.send(payload).await.unwrap();
+                }
+                let encoded = framed.into_inner();
+
+                let framed = FramedRead::new(&encoded[..], codec);
+                let decoded: Vec<_> = framed.map(Result::unwrap).collect().await;
+                assert_eq!(payloads(), decoded);
+            }
+        });
+    }
+
+    #[test]
+    fn test_codec_decode() {
+        let mut codec = Base58Codec::new().with_max_length(16);
+
+        // Partial line, CRLF delimiter and last line without delimiter
+        let mut buf = BytesMut::from(&b"D7LMXYjUbXc1"[..]);
+        assert_eq!(None, codec.decode(&mut buf).unwrap());
+        buf.extend_from_slice(b"fS9Z\r\n\nD7LMXYjUbXc1fS9Z");
+        let hello = Vec::from(&b"Hello World"[..]);
+        assert_eq!(Some(hello.clone()), codec.decode(&mut buf).unwrap());
+        assert_eq!(Some(Vec::new()), codec.decode(&mut buf).unwrap());
+        assert_eq!(None, codec.decode(&mut buf).unwrap());
+        assert_eq!(Some(hello), codec.decode_eof(&mut buf).unwrap());
+        assert!(buf.is_empty());
+
+        // Line too long, with or without delimiter
+        let err = codec
+            .decode(&mut BytesMut::from(&b"D7LMXYjUbXc1fS9Z1"[..]))
+            .unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+        let err = codec
+            .decode(&mut BytesMut::from(&b"D7LMXYjUbXc1fS9Z1\n"[..]))
+            .unwrap_err();
+        assert_eq!(
+            Some(&Error::InvalidLength),
+            err.get_ref().and_then(|e| e.downcast_ref())
+        );
+
+        let err = codec.decode(&mut BytesMut::from(&b"10\n"[..])).unwrap_err();
+        assert_eq!(
+            Some(&Error::InvalidSymbol),
+            err.get_ref().and_then(|e| e.downcast_ref())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_codec_check() {
+        use asynchronous_codec::Encoder;
+
+        let mut codec = Base58Codec::new_check();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(Vec::from(&b"Hello World"[..]), &mut buf)
+            .unwrap();
+        assert_eq!(&b"D7LMXYjUbXc5LVkq6vWDY\n"[..], &buf[..]);
+
+        let err = codec
+            .decode(&mut BytesMut::from(&b"D7LMXYjUbXc1fS9Z\n"[..]))
+            .unwrap_err();
+        assert_eq!(
+            Some(&Error::InvalidChecksum),
+            err.get_ref().and_then(|e| e.downcast_ref())
+        );
+    }
+}
+
+#[cfg(all(test, feature = "tokio-util"))]
+mod tokio_util_tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use bytes::{Bytes, BytesMut};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_util::codec::{Decoder, FramedRead, FramedWrite};
+
+    use super::Base58Codec;
+
+    #[tokio::test]
+    async fn test_tokio_codec_framed() {
+        #[cfg(feature = "check")]
+        let codecs = [Base58Codec::new(), Base58Codec::new_check()];
+        #[cfg(not(feature = "check"))]
+        let codecs = [Base58Codec::new()];
+        let data: Vec<u8> = (0..=255).collect();
+        for codec in codecs {
+            let mut framed = FramedWrite::new(Vec::new(), codec);
+            for len in 0..64 {
+                framed
+                    .send(Bytes::copy_from_slice(&data[..len]))
+                    .await
+                    .unwrap();
+            }
+            let encoded = framed.into_inner();
+
+            // Lines split across reads of 5 bytes
+            let (client, mut server) = tokio::io::duplex(5);
+            let writer = tokio::spawn(async move {
+                tokio::io::AsyncWriteExt::write_all(&mut server, &encoded).await
+            });
+            let decoded: Vec<_> = FramedRead::new(client, codec)
+                .map(Result::unwrap)
+                .collect()
+                .await;
+            writer.await.unwrap().unwrap();
+            let expected: Vec<_> = (0..64).map(|len| Vec::from(&data[..len])).collect();
+            assert_eq!(expected, decoded);
+        }
+    }
+
+    #[test]
+    fn test_tokio_codec_decode() {
+        let mut codec = Base58Codec::new();
+        let mut buf = BytesMut::from(&b"D7LMXYj"[..]);
+        assert_eq!(None, codec.decode(&mut buf).unwrap());
+        buf.extend_from_slice(b"UbXc1fS9Z\n11");
+        assert_eq!(
+            Some(Vec::from(&b"Hello World"[..])),
+            codec.decode(&mut buf).unwrap()
+        );
+        // Last line without delimiter
+        assert_eq!(None, codec.decode(&mut buf).unwrap());
+        assert_eq!(Some(vec![0]), codec.decode_eof(&mut buf).unwrap());
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_tokio_check_codec() {
+        use tokio_util::codec::Encoder;
+
+        use super::Base58CheckCodec;
+        use crate::base58::Error;
+
+        let mut codec = Base58CheckCodec::new();
+        let mut buf = BytesMut::new();
+        codec
+            .encode(Bytes::from_static(b"Hello World"), &mut buf)
+            .unwrap();
+        assert_eq!(&b"D7LMXYjUbXc5LVkq6vWDY\n"[..], &buf[..]);
+        buf.extend_from_slice(b"D7LMXYjUbXc1fS9Z\n");
+        assert_eq!(
+            Some(Vec::from(&b"Hello World"[..])),
+            codec.decode(&mut buf).unwrap()
+        );
+        let err = codec.decode(&mut buf).unwrap_err();
+        assert_eq!(
+            Some(&Error::InvalidChecksum),
+            err.get_ref().and_then(|e| e.downcast_ref())
+        );
+        assert_eq!(Base58Codec::new_check(), Base58Codec::from(codec));
+    }
+}
@@ -0,0 +1,257 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Blocking helpers over `embedded-io` readers and writers
+//!
+//! Encode and decode base58 data between [`embedded_io::Read`] and [`embedded_io::Write`]
+//! implementations, e.g. a UART or a flash region, in `no_std` environments. Data is processed
+//! block by block with fixed size buffers on the stack, nothing is allocated.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::embedded::{decode_from_reader, encode_from_reader};
+//!
+//! let mut encoded = [0u8; 16];
+//! encode_from_reader(&b"Hello World"[..], &mut encoded[..]).unwrap();
+//! assert_eq!(b"D7LMXYjUbXc1fS9Z", &encoded);
+//!
+//! let mut decoded = [0u8; 11];
+//! decode_from_reader(&encoded[..], &mut decoded[..]).unwrap();
+//! assert_eq!(b"Hello World", &decoded);
+//! ```
+
+use core::fmt;
+
+use embedded_io::{ErrorKind, Read, Write};
+
+#[cfg(feature = "check")]
+use crate::base58::decode_check_reader_blocks_with;
+use crate::base58::{
+    decode_reader_blocks_with, encode_block_chars, read_block_with, Error, FULL_BLOCK_SIZE,
+};
+
+/// Possible errors when converting data between a reader and a writer
+#[derive(Debug)]
+pub enum IoError<R, W> {
+    /// Error returned by the reader
+    Read(R),
+    /// Error returned by the writer
+    Write(W),
+    /// Invalid base58 data
+    Base58(Error),
+}
+
+impl<R: fmt::Debug, W: fmt::Debug> fmt::Display for IoError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IoError::Read(e) => write!(f, "Read error: {:?}", e),
+            IoError::Write(e) => write!(f, "Write error: {:?}", e),
+            IoError::Base58(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<R: embedded_io::Error, W: embedded_io::Error> embedded_io::Error for IoError<R, W> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            IoError::Read(e) => e.kind(),
+            IoError::Write(e) => e.kind(),
+            IoError::Base58(_) => ErrorKind::InvalidData,
+        }
+    }
+}
+
+/// Encode a byte slice and write the base58 symbols to a writer
+pub fn encode_to_writer<W: Write>(data: &[u8], mut writer: W) -> Result<(), W::Error> {
+    for block in data.chunks(FULL_BLOCK_SIZE) {
+        match encode_block_chars(block) {
+            Ok(block) => writer.write_all(block.as_str().as_bytes())?,
+            // Chunks are always valid block sizes
+            Err(_) => unreachable!(),
+        }
+    }
+    Ok(())
+}
+
+/// Encode the bytes of a reader and write the base58 symbols to a writer
+///
+/// The reader is consumed in 8 bytes blocks until it returns `0` bytes read.
+pub fn encode_from_reader<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), IoError<R::Error, W::Error>> {
+    let mut buf = [0u8; FULL_BLOCK_SIZE];
+    loop {
+        let len = read_block_with(&mut buf, |buf| reader.read(buf)).map_err(IoError::Read)?;
+        if len > 0 {
+            encode_to_writer(&buf[..len], &mut writer).map_err(IoError::Write)?;
+        }
+        if len < FULL_BLOCK_SIZE {
+            return Ok(());
+        }
+    }
+}
+
+/// Decode the base58 symbols of a reader and write the bytes to a writer
+///
+/// The reader is consumed in 11 characters blocks until it returns `0` bytes read.
+pub fn decode_from_reader<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), IoError<R::Error, W::Error>> {
+    decode_reader_blocks_with(
+        |buf| reader.read(buf).map_err(IoError::Read),
+        IoError::Base58,
+        |bytes| writer.write_all(bytes).map_err(IoError::Write),
+    )
+}
+
+/// Decode the base58 symbols of a reader with a 4 bytes checksum and write the bytes to a writer
+///
+/// The checksum is verified once the reader is exhausted, the bytes preceding the checksum are
+/// already written when [`Error::InvalidChecksum`] is returned. Input shorter than the checksum is
+/// rejected with [`Error::InvalidChecksum`].
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "embedded-io"))))]
+pub fn decode_check_from_reader<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), IoError<R::Error, W::Error>> {
+    decode_check_reader_blocks_with(
+        |buf| reader.read(buf).map_err(IoError::Read),
+        IoError::Base58,
+        |bytes| writer.write_all(bytes).map_err(IoError::Write),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_io::{ErrorKind, Read, Write};
+
+    use super::{decode_from_reader, encode_from_reader, encode_to_writer, IoError};
+    use crate::base58::Error;
+
+    // Reader returning at most 3 bytes per read, like a slow UART
+    struct Uart<'a>(&'a [u8]);
+
+    impl embedded_io::ErrorType for Uart<'_> {
+        type Error = ErrorKind;
+    }
+
+    impl Read for Uart<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let len = buf.len().min(self.0.len()).min(3);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    // Fixed size output buffer
+    struct Flash {
+        data: [u8; 128],
+        len: usize,
+    }
+
+    impl Flash {
+        fn new() -> Self {
+            Self {
+                data: [0; 128],
+                len: 0,
+            }
+        }
+
+        fn as_bytes(&self) -> &[u8] {
+            &self.data[..self.len]
+        }
+    }
+
+    impl embedded_io::ErrorType for Flash {
+        type Error = ErrorKind;
+    }
+
+    impl Write for Flash {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            let len = buf.len().min(self.data.len() - self.len);
+            if len == 0 && !buf.is_empty() {
+                return Err(ErrorKind::OutOfMemory);
+            }
+            self.data[self.len..self.len + len].copy_from_slice(&buf[..len]);
+            self.len += len;
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn encode_decode() {
+        let data: [u8; 69] = core::array::from_fn(|i| i as u8);
+        let mut encoded = Flash::new();
+        encode_from_reader(Uart(&data), &mut encoded).unwrap();
+        assert_eq!(95, encoded.len);
+
+        let mut direct = Flash::new();
+        encode_to_writer(&data, &mut direct).unwrap();
+        assert_eq!(encoded.as_bytes(), direct.as_bytes());
+
+        let mut decoded = Flash::new();
+        decode_from_reader(Uart(encoded.as_bytes()), &mut decoded).unwrap();
+        assert_eq!(&data[..], decoded.as_bytes());
+    }
+
+    #[test]
+    fn errors() {
+        let mut out = Flash::new();
+        assert!(matches!(
+            decode_from_reader(Uart(b"1"), &mut out),
+            Err(IoError::Base58(Error::InvalidBlockSize))
+        ));
+        assert!(matches!(
+            decode_from_reader(Uart(b"10"), &mut out),
+            Err(IoError::Base58(Error::InvalidSymbol))
+        ));
+
+        let mut full = Flash::new();
+        full.len = full.data.len();
+        assert!(matches!(
+            encode_from_reader(Uart(b"Hello World"), &mut full),
+            Err(IoError::Write(ErrorKind::OutOfMemory))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn decode_check() {
+        use super::decode_check_from_reader;
+
+        let encoded = b"D7LMXYjUbXc5LVkq6vWDY";
+        let mut out = Flash::new();
+        decode_check_from_reader(Uart(&encoded[..]), &mut out).unwrap();
+        assert_eq!(b"Hello World", out.as_bytes());
+
+        assert!(matches!(
+            decode_check_from_reader(Uart(b"D7LMXYjUbXc5LVkq6vWDZ"), &mut Flash::new()),
+            Err(IoError::Base58(Error::InvalidChecksum))
+        ));
+        assert!(matches!(
+            decode_check_from_reader(Uart(b""), &mut Flash::new()),
+            Err(IoError::Base58(Error::InvalidChecksum))
+        ));
+    }
+}
@@ -0,0 +1,399 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Adapters encoding and decoding HTTP bodies
+//!
+//! [`DecodeBody`] wraps an incoming [`http_body::Body`] of base58 text and is itself a body of the
+//! decoded bytes, [`EncodeBody`] wraps a body of bytes and is a body of base58 text. Both convert
+//! each data frame as it arrives, carrying incomplete blocks over chunk boundaries, so services
+//! do not buffer whole requests or responses. Trailers are forwarded after the last data frame.
+//!
+//! The adapters are bodies themselves and can be used with any `http-body` 1.0 stack, e.g. passed
+//! to `axum::body::Body::new` or turned into a stream of frames with `http-body-util`.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::http::DecodeBody;
+//! use http_body_util::{BodyExt, Full};
+//!
+//! # tokio_test::block_on(async {
+//! let upload = Full::new(&b"D7LMXYjUbXc1fS9Z\n"[..]);
+//! let body = DecodeBody::new(upload).with_limit(1024);
+//! let bytes = body.collect().await.unwrap().to_bytes();
+//! assert_eq!(&b"Hello World"[..], &bytes[..]);
+//! # })
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use ::http::HeaderMap;
+use bytes::{Buf, Bytes};
+use http_body::{Body, Frame};
+
+use crate::base58::{
+    decode_block, encode_block_chars, Error, FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE,
+};
+
+/// Possible errors of the body adapters
+#[derive(Debug)]
+pub enum BodyError<E> {
+    /// Error returned by the wrapped body
+    Body(E),
+    /// Invalid base58 data or body over the size limit
+    Base58(Error),
+}
+
+impl<E: fmt::Display> fmt::Display for BodyError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BodyError::Body(e) => write!(f, "Body error: {}", e),
+            BodyError::Base58(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for BodyError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BodyError::Body(e) => Some(e),
+            BodyError::Base58(e) => Some(e),
+        }
+    }
+}
+
+/// Body of the bytes decoded from a body of base58 text
+///
+/// ASCII whitespaces are skipped, e.g. the trailing new line of an uploaded file. A body with more
+/// than the limit of base58 symbols, unlimited by default, ends with [`Error::InvalidLength`] as
+/// soon as the limit is crossed.
+#[derive(Debug)]
+pub struct DecodeBody<B> {
+    inner: B,
+    block: [u8; FULL_ENCODED_BLOCK_SIZE],
+    len: usize,
+    read: usize,
+    limit: usize,
+    trailers: Option<HeaderMap>,
+    done: bool,
+}
+
+impl<B> DecodeBody<B> {
+    /// Wrap a body of base58 text
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            block: [0; FULL_ENCODED_BLOCK_SIZE],
+            len: 0,
+            read: 0,
+            limit: usize::MAX,
+            trailers: None,
+            done: false,
+        }
+    }
+
+    /// Set the maximum number of base58 symbols of the body
+    pub fn with_limit(self, limit: usize) -> Self {
+        Self { limit, ..self }
+    }
+
+    /// Return the wrapped body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    // Decode the symbols of a data frame, the symbols of the last incomplete block are kept
+    fn decode_data(&mut self, mut data: impl Buf) -> Result<Vec<u8>, Error> {
+        let mut res = Vec::new();
+        while data.has_remaining() {
+            let chunk = data.chunk();
+            for &c in chunk.iter().filter(|c| !c.is_ascii_whitespace()) {
+                self.read += 1;
+                if self.read > self.limit {
+                    return Err(Error::InvalidLength);
+                }
+                self.block[self.len] = c;
+                self.len += 1;
+                if self.len == FULL_ENCODED_BLOCK_SIZE {
+                    res.extend_from_slice(decode_block(&self.block)?.as_bytes());
+                    self.len = 0;
+                }
+            }
+            let len = chunk.len();
+            data.advance(len);
+        }
+        Ok(res)
+    }
+
+    // Decode the last incomplete block
+    fn finish(&mut self) -> Result<Vec<u8>, Error> {
+        self.done = true;
+        match self.len {
+            0 => Ok(Vec::new()),
+            len => Ok(Vec::from(decode_block(&self.block[..len])?.as_bytes())),
+        }
+    }
+}
+
+impl<B: Body + Unpin> Body for DecodeBody<B> {
+    type Data = Bytes;
+    type Error = BodyError<B::Error>;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+        let this = &mut *self;
+        while !this.done {
+            let frame = match Pin::new(&mut this.inner).poll_frame(cx) {
+                Poll::Ready(frame) => frame,
+                Poll::Pending => return Poll::Pending,
+            };
+            let res = match frame {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(data) => this.decode_data(data),
+                    Err(frame) => {
+                        this.trailers = frame.into_trailers().ok();
+                        this.finish()
+                    }
+                },
+                Some(Err(e)) => return Poll::Ready(Some(Err(BodyError::Body(e)))),
+                None => this.finish(),
+            };
+            match res {
+                Ok(bytes) if bytes.is_empty() => {}
+                Ok(bytes) => return Poll::Ready(Some(Ok(Frame::data(Bytes::from(bytes))))),
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(BodyError::Base58(e))));
+                }
+            }
+        }
+        Poll::Ready(this.trailers.take().map(|t| Ok(Frame::trailers(t))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done && self.trailers.is_none()
+    }
+}
+
+/// Body of the base58 text encoding a body of bytes
+#[derive(Debug)]
+pub struct EncodeBody<B> {
+    inner: B,
+    block: [u8; FULL_BLOCK_SIZE],
+    len: usize,
+    trailers: Option<HeaderMap>,
+    done: bool,
+}
+
+impl<B> EncodeBody<B> {
+    /// Wrap a body of bytes
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            block: [0; FULL_BLOCK_SIZE],
+            len: 0,
+            trailers: None,
+            done: false,
+        }
+    }
+
+    /// Return the wrapped body
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    // Encode the bytes of a data frame, the bytes of the last incomplete block are kept
+    fn encode_data(&mut self, mut data: impl Buf) -> Result<String, Error> {
+        let mut res = String::new();
+        while data.has_remaining() {
+            let chunk = data.chunk();
+            for &b in chunk {
+                self.block[self.len] = b;
+                self.len += 1;
+                if self.len == FULL_BLOCK_SIZE {
+                    res.push_str(encode_block_chars(&self.block)?.as_str());
+                    self.len = 0;
+                }
+            }
+            let len = chunk.len();
+            data.advance(len);
+        }
+        Ok(res)
+    }
+
+    // Encode the last incomplete block
+    fn finish(&mut self) -> Result<String, Error> {
+        self.done = true;
+        match self.len {
+            0 => Ok(String::new()),
+            len => Ok(String::from(
+                encode_block_chars(&self.block[..len])?.as_str(),
+            )),
+        }
+    }
+}
+
+impl<B: Body + Unpin> Body for EncodeBody<B> {
+    type Data = Bytes;
+    type Error = BodyError<B::Error>;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, Self::Error>>> {
+        let this = &mut *self;
+        while !this.done {
+            let frame = match Pin::new(&mut this.inner).poll_frame(cx) {
+                Poll::Ready(frame) => frame,
+                Poll::Pending => return Poll::Pending,
+            };
+            let res = match frame {
+                Some(Ok(frame)) => match frame.into_data() {
+                    Ok(data) => this.encode_data(data),
+                    Err(frame) => {
+                        this.trailers = frame.into_trailers().ok();
+                        this.finish()
+                    }
+                },
+                Some(Err(e)) => return Poll::Ready(Some(Err(BodyError::Body(e)))),
+                None => this.finish(),
+            };
+            match res {
+                Ok(s) if s.is_empty() => {}
+                Ok(s) => return Poll::Ready(Some(Ok(Frame::data(Bytes::from(s))))),
+                Err(e) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(BodyError::Base58(e))));
+                }
+            }
+        }
+        Poll::Ready(this.trailers.take().map(|t| Ok(Frame::trailers(t))))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.done && self.trailers.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+    use alloc::vec::Vec;
+    use core::convert::Infallible;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    use ::http::HeaderMap;
+    use bytes::Bytes;
+    use http_body::{Body, Frame};
+    use http_body_util::BodyExt;
+
+    use super::{BodyError, DecodeBody, EncodeBody};
+    use crate::base58::{encode, Error};
+
+    // Body yielding its frames one by one
+    struct Frames(VecDeque<Frame<Bytes>>);
+
+    impl Frames {
+        fn new(data: &[u8], chunk: usize, trailers: bool) -> Self {
+            let mut frames: VecDeque<_> = data
+                .chunks(chunk)
+                .map(|c| Frame::data(Bytes::copy_from_slice(c)))
+                .collect();
+            if trailers {
+                let mut map = HeaderMap::new();
+                map.insert("x-checksum", "ok".parse().unwrap());
+                frames.push_back(Frame::trailers(map));
+            }
+            Self(frames)
+        }
+    }
+
+    impl Body for Frames {
+        type Data = Bytes;
+        type Error = Infallible;
+
+        fn poll_frame(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<Option<Result<Frame<Bytes>, Infallible>>> {
+            Poll::Ready(self.0.pop_front().map(Ok))
+        }
+    }
+
+    #[test]
+    fn test_body_roundtrip() {
+        tokio_test::block_on(async {
+            let data: Vec<u8> = (0..=255).collect();
+            for len in [0, 1, 7, 8, 9, 100, 256] {
+                for chunk in [1, 3, 11, 64] {
+                    let encoded = EncodeBody::new(Frames::new(&data[..len], chunk, false))
+                        .collect()
+                        .await
+                        .unwrap()
+                        .to_bytes();
+                    assert_eq!(encode(&data[..len]).unwrap().as_bytes(), &encoded[..]);
+
+                    let decoded = DecodeBody::new(Frames::new(&encoded, chunk, true))
+                        .collect()
+                        .await
+                        .unwrap();
+                    let trailers = decoded.trailers().unwrap();
+                    assert_eq!("ok", trailers["x-checksum"]);
+                    assert_eq!(&data[..len], &decoded.to_bytes()[..]);
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn test_decode_body_errors() {
+        tokio_test::block_on(async {
+            let decode = |input: &'static [u8], limit| async move {
+                DecodeBody::new(Frames::new(input, 5, false))
+                    .with_limit(limit)
+                    .collect()
+                    .await
+                    .map(|c| c.to_bytes())
+            };
+
+            // Whitespaces are skipped and not counted in the limit
+            assert_eq!(
+                &b"Hello World"[..],
+                &decode(b"D7LMX YjUbXc\r\n1fS9Z\n", 16).await.unwrap()[..]
+            );
+            assert!(matches!(
+                decode(b"D7LMXYjUbXc1fS9Z", 15).await,
+                Err(BodyError::Base58(Error::InvalidLength))
+            ));
+            assert!(matches!(
+                decode(b"D7LMXYjUbXc10", 100).await,
+                Err(BodyError::Base58(Error::InvalidSymbol))
+            ));
+            assert!(matches!(
+                decode(b"D7LMXYjUbXc1", 100).await,
+                Err(BodyError::Base58(Error::InvalidBlockSize))
+            ));
+        });
+    }
+}
@@ -29,20 +29,86 @@
 //!
 //! ## Features
 //!
-//!  * `std`: enable std error implementation on the Error enum.
-//!  * `check`: enable encoding/decoding base58 strings with a 4 bytes tail checksum.
+//!  * `std`: enable std error implementation on the Error enum and the [`sync_stream`] module,
+//!    implies `alloc`.
+//!  * `alloc`: enable the functions returning a `String` or a `Vec<u8>`, without it only the
+//!    [`encode_into`] and [`decode_into`] slice functions are available.
+//!  * `check`: enable encoding/decoding base58 strings with a 4 bytes tail checksum and the
+//!    Monero [`address`] helpers.
 //!  * `stream`: enable encoding/decoding base58 asyncronous streams of data.
+//!  * `stream-futures`: enable the `*_futures` stream functions reading from `futures` readers,
+//!    e.g. with async-std or smol, implies `stream`.
+//!  * `parallel`: enable validating batches of addresses and base58-check strings, and encoding
+//!    or decoding large inputs, in parallel with `rayon`, implies `check`.
+//!  * `blocking`: enable the `*_blocking` async functions offloading large inputs to the `tokio`
+//!    blocking thread pool.
+//!  * `parallel-stream`: enable decoding async streams with batches of blocks decoded in parallel
+//!    `tokio` tasks, implies `stream`.
+//!  * `coins`: enable the address prefixes of other CryptoNote coins, e.g. Wownero and Aeon,
+//!    implies `check`.
+//!  * `base64`: enable the [`transcode`] module converting between base64 and base58.
+//!  * `digest`: enable [`decode_with_digest`] feeding the decoded bytes to a RustCrypto hasher.
+//!  * `num-bigint`: enable [`encode_biguint`] and [`decode_biguint`] mapping arbitrary-size
+//!    unsigned integers to base58.
+//!  * `generic-array`: enable decoding into `GenericArray` for the RustCrypto APIs.
+//!  * `heapless`: enable encoding into `heapless::String` and decoding into `heapless::Vec`
+//!    bounded at compile time, without allocating.
+//!  * `bytes`: enable decoding from non-contiguous [`bytes::Buf`] buffers.
+//!  * `tracing`: emit `tracing` spans and events from the stream functions, implies `stream`.
+//!  * `uring`: enable the [`uring`] module with file helpers built on `tokio-uring`, Linux only.
+//!  * `scratch`: reuse a per-thread scratch buffer and hasher state in the one-shot functions.
+//!  * `telemetry`: enable the [`telemetry`] module counting and reporting checksum failures and
+//!    invalid symbols to a process-wide hook.
+//!  * `cache`: enable the [`cache`] module with a bounded LRU cache of base58-check encodings,
+//!    implies `check`.
+//!  * `self-check`: verify in debug builds that the encoding and decoding functions round-trip.
+//!  * `log`: emit a `warn!` record when [`normalize`] removes characters from its input.
+//!  * `ufmt`: implement `ufmt::uDisplay` to write base58 strings with `uwrite!` without the
+//!    `core::fmt` machinery.
+//!  * `embedded-io`: enable the [`embedded`] module with blocking helpers over `embedded-io`
+//!    readers and writers.
+//!  * `simd`: convert the symbols with SSE2, AVX2, NEON or WebAssembly `simd128` instructions when
+//!    decoding, AVX2 is detected at runtime with `std`.
+//!  * `small`: trade speed for binary size, e.g. for wasm bundles: no SIMD decoding path, no
+//!    lookup tables to decode or to encode with a custom alphabet and no derive macro on the error
+//!    type.
+//!  * `asynchronous-codec`: enable the [`codec`] module with a newline-delimited base58 codec for
+//!    `asynchronous-codec` framed transports.
+//!  * `tokio-util`: enable the [`codec`] module with the codec implementing the `tokio-util` codec
+//!    traits.
+//!  * `codec`: alias of `tokio-util`.
+//!  * `http`: enable the [`http`] module with `http-body` adapters encoding and decoding HTTP
+//!    bodies without buffering them.
+//!  * `axum`: enable the [`axum`] module with extractors of base58 path and query
+//!    parameters, implies `check`.
+//!  * `clap`: enable the [`clap`] module with a value parser of base58 command line arguments.
+//!  * `serde`: serialize and deserialize the [`types`] wrappers as base58 strings, without
+//!    allocating for [`Base58Str`], and enable the [`serde`](mod@serde) modules for
+//!    `#[serde(with = "...")]` fields.
+//!  * `sqlx`: store [`Base58String`] and [`Base58Bytes`] in text columns with `sqlx`.
+//!  * `diesel`: map [`Base58String`] to `Text` and [`Base58Bytes`] to `Binary` columns with
+//!    `diesel`.
+//!  * `test-vectors`: enable the [`test_vectors`] module with the vectors used to test this crate.
+//!  * `test-util`: enable the [`test_util`] module generating random addresses and base58 strings
+//!    for tests, implies `check`.
+//!  * `cli`: build the `base58-monero` command line tool, implies `check` and `stream`.
 //!
 //! Only the `std` feature is enabled by default, to use this crate in `no_std` environment use:
 //!
 //! ```text
-//! base58-monero = { version = "2", default-features = false }
+//! base58-monero = { version = "3", default-features = false }
+//! ```
+//!
+//! or, with a global allocator, to keep the allocating functions use:
+//!
+//! ```text
+//! base58-monero = { version = "3", default-features = false, features = ["alloc"] }
 //! ```
 //!
 //! or to enable `stream` one use:
 //!
 //! ```text
-//! base58-monero = { version = "2", features = ["stream"] }
+//! base58-monero = { version = "3", features = ["stream"] }
 //! ```
 //!
 //! ## Examples
@@ -66,6 +132,8 @@
 //! with a checksum:
 //!
 //! ```rust
+//! # #[cfg(feature = "check")]
+//! # fn main() -> Result<(), base58_monero::Error> {
 //! use base58_monero::{encode_check, decode_check, Error};
 //!
 //! let input = b"Hello World";
@@ -74,31 +142,261 @@
 //! let decoded_input = decode_check(&encoded_input)?;
 //!
 //! assert_eq!(&input[..], &decoded_input[..]);
-//! # Ok::<(), Error>(())
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "check"))]
+//! # fn main() {}
 //! ```
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![recursion_limit = "256"]
-// Coding conventions
-#![forbid(unsafe_code)]
+// Coding conventions, the `simd` feature allows `unsafe` in the `simd` module only
+#![cfg_attr(not(feature = "simd"), forbid(unsafe_code))]
+#![cfg_attr(feature = "simd", deny(unsafe_code))]
 #![deny(missing_docs)]
 // Use a no_std environment when std feature is not enabled
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(all(feature = "check", feature = "alloc"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "check", feature = "alloc"))))]
+pub mod address;
+#[cfg(feature = "axum")]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub mod axum;
 pub mod base58;
+#[cfg(feature = "cache")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+pub mod cache;
+#[cfg(feature = "clap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap;
+#[cfg(any(feature = "asynchronous-codec", feature = "tokio-util"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "asynchronous-codec", feature = "tokio-util")))
+)]
+pub mod codec;
+#[cfg(feature = "embedded-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "embedded-io")))]
+pub mod embedded;
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+pub mod http;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+#[cfg(all(feature = "simd", not(feature = "small")))]
+mod simd;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod sync_stream;
+#[cfg(feature = "telemetry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "telemetry")))]
+pub mod telemetry;
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod test_util;
+#[cfg(feature = "test-vectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+#[cfg(feature = "base64")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base64")))]
+pub mod transcode;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub mod types;
+#[cfg(all(feature = "uring", target_os = "linux"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "uring", target_os = "linux"))))]
+pub mod uring;
+
+#[cfg(feature = "parallel")]
+pub use address::validate_addresses_par;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use address::{classify_address, decode_address, parse_address_parts};
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use address::{decode_check_with_prefix, ExpectedPrefix};
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use address::{encode_address, encode_integrated_address, validate_address};
 
+#[cfg(feature = "alloc")]
 pub use base58::decode;
-#[cfg(feature = "check")]
+#[cfg(feature = "blocking")]
+pub use base58::decode_blocking;
+#[cfg(feature = "alloc")]
+pub use base58::decode_bounded;
+#[cfg(feature = "bytes")]
+pub use base58::decode_buf;
+#[cfg(feature = "alloc")]
+pub use base58::decode_bytes;
+pub use base58::decode_chars;
+#[cfg(all(feature = "check", feature = "alloc"))]
 pub use base58::decode_check;
+#[cfg(all(feature = "blocking", feature = "check"))]
+pub use base58::decode_check_blocking;
+#[cfg(all(feature = "check", feature = "std"))]
+pub use base58::decode_check_from_reader;
+#[cfg(all(feature = "check", feature = "generic-array"))]
+pub use base58::decode_check_generic_array;
+#[cfg(all(feature = "check", feature = "heapless"))]
+pub use base58::decode_check_heapless;
+#[cfg(feature = "check")]
+pub use base58::decode_check_into;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_check_keyed;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_check_lossy;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_check_many;
+#[cfg(feature = "parallel")]
+pub use base58::decode_check_many_par;
+#[cfg(feature = "parallel")]
+pub use base58::decode_check_par;
+#[cfg(all(feature = "check", feature = "std"))]
+pub use base58::decode_check_seekable;
+#[cfg(feature = "check")]
+pub use base58::decode_check_to_array;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_check_verbose;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_check_with;
+#[cfg(feature = "alloc")]
+pub use base58::decode_expecting;
+#[cfg(feature = "std")]
+pub use base58::decode_from_reader;
+#[cfg(feature = "generic-array")]
+pub use base58::decode_generic_array;
+#[cfg(feature = "heapless")]
+pub use base58::decode_heapless;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::decode_hex_or_base58_check;
+pub use base58::decode_into;
+#[cfg(feature = "parallel")]
+pub use base58::decode_par;
+#[cfg(feature = "alloc")]
+pub use base58::decode_report;
+#[cfg(feature = "alloc")]
+pub use base58::decode_split;
 #[cfg(feature = "stream")]
 pub use base58::decode_stream;
+#[cfg(feature = "stream")]
+pub use base58::decode_stream_blocks;
 #[cfg(all(feature = "check", feature = "stream"))]
 pub use base58::decode_stream_check;
+#[cfg(all(feature = "check", feature = "stream-futures"))]
+pub use base58::decode_stream_check_futures;
+#[cfg(all(feature = "check", feature = "stream"))]
+pub use base58::decode_stream_check_seekable;
+#[cfg(all(feature = "check", feature = "stream"))]
+pub use base58::decode_stream_check_verbose;
+#[cfg(feature = "stream")]
+pub use base58::decode_stream_chunks;
+#[cfg(feature = "stream-futures")]
+pub use base58::decode_stream_futures;
+#[cfg(feature = "stream")]
+pub use base58::decode_stream_owned;
+#[cfg(feature = "parallel-stream")]
+pub use base58::decode_stream_par;
+#[cfg(feature = "stream")]
+pub use base58::decode_stream_verbose;
+pub use base58::decode_to_array;
+#[cfg(feature = "std")]
+pub use base58::decode_to_writer;
+#[cfg(feature = "std")]
+pub use base58::decode_until;
+#[cfg(feature = "alloc")]
+pub use base58::decode_verbose;
+#[cfg(feature = "digest")]
+pub use base58::decode_with_digest;
+#[cfg(feature = "alloc")]
 pub use base58::encode;
-#[cfg(feature = "check")]
+#[cfg(feature = "blocking")]
+pub use base58::encode_blocking;
+#[cfg(all(feature = "check", feature = "alloc"))]
 pub use base58::encode_check;
+#[cfg(all(feature = "blocking", feature = "check"))]
+pub use base58::encode_check_blocking;
+#[cfg(all(feature = "check", feature = "std"))]
+pub use base58::encode_check_from_reader;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::encode_check_full;
+#[cfg(all(feature = "check", feature = "heapless"))]
+pub use base58::encode_check_heapless;
+#[cfg(feature = "check")]
+pub use base58::encode_check_into;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::encode_check_keyed;
+#[cfg(feature = "parallel")]
+pub use base58::encode_check_par;
+#[cfg(feature = "check")]
+pub use base58::encode_check_to_array;
+#[cfg(all(feature = "check", feature = "std"))]
+pub use base58::encode_check_to_writer;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::encode_check_with;
+#[cfg(feature = "std")]
+pub use base58::encode_from_reader;
+#[cfg(feature = "heapless")]
+pub use base58::encode_heapless;
+pub use base58::encode_into;
+#[cfg(feature = "alloc")]
+pub use base58::encode_iter;
+#[cfg(feature = "alloc")]
+pub use base58::encode_join;
+#[cfg(feature = "parallel")]
+pub use base58::encode_par;
 #[cfg(feature = "stream")]
 pub use base58::encode_stream;
+#[cfg(feature = "stream")]
+pub use base58::encode_stream_blocks;
 #[cfg(all(feature = "check", feature = "stream"))]
 pub use base58::encode_stream_check;
+#[cfg(all(feature = "check", feature = "stream-futures"))]
+pub use base58::encode_stream_check_futures;
+#[cfg(feature = "stream")]
+pub use base58::encode_stream_chunks;
+#[cfg(feature = "stream-futures")]
+pub use base58::encode_stream_futures;
+#[cfg(feature = "stream")]
+pub use base58::encode_stream_owned;
+pub use base58::encode_to_array;
+#[cfg(feature = "std")]
+pub use base58::encode_to_writer;
+#[cfg(feature = "alloc")]
+pub use base58::normalize;
+#[cfg(all(feature = "check", feature = "stream"))]
+pub use base58::transcode_check;
+pub use base58::Alphabet;
+#[cfg(feature = "stream")]
+pub use base58::Base58DecodeSink;
+#[cfg(feature = "std")]
+pub use base58::Base58DecodeWriter;
+#[cfg(feature = "check")]
+pub use base58::Checksum;
+#[cfg(feature = "check")]
+pub use base58::ChecksumStatus;
+pub use base58::DecodeError;
+#[cfg(feature = "alloc")]
+pub use base58::Encoder;
 pub use base58::Error;
+#[cfg(all(feature = "check", feature = "alloc"))]
+pub use base58::LossyCheck;
+#[cfg(feature = "stream")]
+pub use base58::StreamDecodeError;
+#[cfg(feature = "num-bigint")]
+pub use base58::{decode_biguint, encode_biguint, BigUintFormat};
+#[cfg(feature = "alloc")]
+pub use base58::{decode_hex_or_base58, DetectedFormat};
+#[cfg(feature = "alloc")]
+pub use base58::{decode_with_alphabet, encode_with_alphabet};
+#[cfg(feature = "alloc")]
+pub use base58::{decode_with_progress, encode_with_progress};
+pub use base58::{decoded_block_size, decoded_len, encoded_len};
+pub use base58::{display, Base58Display};
+pub use base58::{find_first_invalid, is_base58};
+#[cfg(feature = "alloc")]
+pub use base58::{try_decode, try_encode};
+#[cfg(feature = "alloc")]
+pub use base58::{BlockReport, DecodeReport};
+pub use base58::{DecodeState, EncodeState};
+#[cfg(feature = "alloc")]
+pub use types::{Base58Bytes, Base58Str, Base58String};
@@ -0,0 +1,206 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Serde modules storing bytes as base58 strings
+//!
+//! The [`string`] and [`check`] modules serialize byte containers, e.g. `Vec<u8>` or `[u8; N]`,
+//! as base58 and base58-check strings, to be used with `#[serde(with = "...")]` on fields. Strings
+//! are decoded and validated when deserialized, decoded lengths not fitting the field are
+//! rejected. See [`Base58Bytes`](crate::Base58Bytes) for a newtype serialized the same way.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Message {
+//!     #[serde(with = "base58_monero::serde::string")]
+//!     payload: Vec<u8>,
+//!     #[serde(with = "base58_monero::serde::string")]
+//!     key: [u8; 11],
+//! }
+//!
+//! let msg = Message { payload: b"Hello World".to_vec(), key: *b"Hello World" };
+//! let json = serde_json::to_string(&msg)?;
+//! assert_eq!(r#"{"payload":"D7LMXYjUbXc1fS9Z","key":"D7LMXYjUbXc1fS9Z"}"#, json);
+//! let msg: Message = serde_json::from_str(&json)?;
+//! assert_eq!(b"Hello World", &msg.key);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+extern crate alloc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Visitor};
+
+use crate::base58::Result;
+
+// Visitor decoding a string with `decode`, then converting the bytes into `T`
+struct DecodeVisitor<T> {
+    decode: fn(&str) -> Result<Vec<u8>>,
+    expecting: &'static str,
+    marker: PhantomData<T>,
+}
+
+impl<T: TryFrom<Vec<u8>>> Visitor<'_> for DecodeVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> core::result::Result<T, E> {
+        let bytes = (self.decode)(v).map_err(E::custom)?;
+        let len = bytes.len();
+        T::try_from(bytes).map_err(|_| E::invalid_length(len, &self))
+    }
+}
+
+/// Serialize bytes as base58 strings, see [`encode`](crate::encode)
+///
+/// Use with `#[serde(with = "base58_monero::serde::string")]`.
+pub mod string {
+    use core::marker::PhantomData;
+
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{DecodeVisitor, Vec};
+    use crate::base58::{decode, display};
+
+    /// Serialize bytes as a base58 string
+    pub fn serialize<T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        serializer.collect_str(&display(data.as_ref()))
+    }
+
+    /// Deserialize bytes from a base58 string
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DecodeVisitor {
+            decode: |s| decode(s),
+            expecting: "a base58 string",
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Serialize bytes as base58-check strings, see [`encode_check`](crate::encode_check)
+///
+/// Use with `#[serde(with = "base58_monero::serde::check")]`, the checksum is verified when
+/// deserialized.
+#[cfg(feature = "check")]
+#[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+pub mod check {
+    use core::marker::PhantomData;
+
+    use ::serde::ser::Error;
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{DecodeVisitor, Vec};
+    use crate::base58::{decode_check, encode_check};
+
+    /// Serialize bytes as a base58-check string
+    pub fn serialize<T, S>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        let encoded = encode_check(data.as_ref()).map_err(S::Error::custom)?;
+        serializer.serialize_str(&encoded)
+    }
+
+    /// Deserialize bytes from a base58-check string
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: TryFrom<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DecodeVisitor {
+            decode: |s| decode_check(s),
+            expecting: "a base58-check string",
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "super::string")]
+        raw: Vec<u8>,
+        #[serde(with = "super::string")]
+        array: [u8; 11],
+    }
+
+    #[test]
+    fn test_serde_string() {
+        let fields = Fields {
+            raw: Vec::new(),
+            array: *b"Hello World",
+        };
+        let json = serde_json::to_string(&fields).unwrap();
+        assert_eq!(r#"{"raw":"","array":"D7LMXYjUbXc1fS9Z"}"#, json);
+        assert_eq!(fields, serde_json::from_str(&json).unwrap());
+
+        let err = serde_json::from_str::<Fields>(r#"{"raw":"10","array":"D7LMXYjUbXc1fS9Z"}"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid symbol error"));
+        let err = serde_json::from_str::<Fields>(r#"{"raw":"","array":"11"}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid length 1, expected a base58 string"));
+        // Escaped strings are not borrowed from the input
+        let fields: Fields =
+            serde_json::from_str(r#"{"raw":"\u0031\u0031","array":"D7LMXYjUbXc1fS9Z"}"#).unwrap();
+        assert_eq!(vec![0], fields.raw);
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_serde_check() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Check {
+            #[serde(with = "super::check")]
+            key: [u8; 11],
+        }
+
+        let check = Check {
+            key: *b"Hello World",
+        };
+        let json = serde_json::to_string(&check).unwrap();
+        assert_eq!(r#"{"key":"D7LMXYjUbXc5LVkq6vWDY"}"#, json);
+        assert_eq!(check, serde_json::from_str(&json).unwrap());
+
+        let err = serde_json::from_str::<Check>(r#"{"key":"D7LMXYjUbXc1fS9Z"}"#).unwrap_err();
+        assert!(err.to_string().contains("Invalid checksum error"));
+    }
+}
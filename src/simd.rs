@@ -0,0 +1,291 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+// SIMD conversion of base58 symbols into digits on x86, AArch64 and WebAssembly, compiled with
+// the `simd` feature. AVX2 is selected at runtime when the CPU supports it, otherwise SSE2 on x86
+// and NEON on AArch64, both part of the baseline of the 64-bit targets. WebAssembly uses `simd128`
+// when the target feature is enabled at compile time. Other targets use the scalar path.
+//
+// This is the only module allowed to use `unsafe`, for the intrinsics loading and storing vectors
+// and for calling the functions compiled with a target feature once it is detected.
+#![allow(unsafe_code)]
+
+use crate::base58::Alphabet;
+
+// Contiguous ranges of the base58 alphabet: first symbol, last symbol and digit of the first
+// symbol
+const RANGES: [(u8, u8, u8); 6] = [
+    (b'1', b'9', 0),
+    (b'A', b'H', 9),
+    (b'J', b'N', 17),
+    (b'P', b'Z', 22),
+    (b'a', b'k', 33),
+    (b'm', b'z', 44),
+];
+
+// Convert the base58 symbols of `data` into their digit values in `digits`, with the widest
+// vectors supported by the CPU. Returns false if any symbol is not in the alphabet, `digits`
+// content is then unspecified.
+pub(crate) fn symbols_to_digits(data: &[u8], digits: &mut [u8]) -> bool {
+    debug_assert_eq!(data.len(), digits.len());
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if x86::has_avx2() {
+            // SAFETY: the CPU supports AVX2
+            return unsafe { x86::symbols_to_digits_avx2(data, digits) };
+        }
+        if x86::has_sse2() {
+            // SAFETY: the CPU supports SSE2
+            return unsafe { x86::symbols_to_digits_sse2(data, digits) };
+        }
+        symbols_to_digits_scalar(data, digits)
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        neon::symbols_to_digits(data, digits)
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        wasm::symbols_to_digits(data, digits)
+    }
+    #[cfg(not(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )))]
+    {
+        symbols_to_digits_scalar(data, digits)
+    }
+}
+
+// Convert the symbols one at a time, for the inputs shorter than a vector
+fn symbols_to_digits_scalar(data: &[u8], digits: &mut [u8]) -> bool {
+    for (&c, d) in data.iter().zip(digits) {
+        match Alphabet::MONERO.digit(c) {
+            Some(digit) => *d = digit,
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use super::{symbols_to_digits_scalar, RANGES};
+
+    // Detect the CPU features at runtime with `std`, only rely on the features enabled at compile
+    // time without it
+    pub(super) fn has_avx2() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx2")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avx2")
+        }
+    }
+
+    pub(super) fn has_sse2() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("sse2")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "sse2")
+        }
+    }
+
+    // 16 symbols at a time. SSE2 only compares signed bytes: the alphabet is ASCII, so bytes over
+    // 0x7f are negative and below all ranges.
+    //
+    // SAFETY: the caller must check that the CPU supports SSE2
+    #[target_feature(enable = "sse2")]
+    pub(super) unsafe fn symbols_to_digits_sse2(data: &[u8], digits: &mut [u8]) -> bool {
+        let mut chunks = data.chunks_exact(16);
+        let mut out = digits.chunks_exact_mut(16);
+        for (chunk, out) in (&mut chunks).zip(&mut out) {
+            // SAFETY: unaligned load of a 16 bytes slice
+            let symbols = unsafe { _mm_loadu_si128(chunk.as_ptr() as *const __m128i) };
+            let mut valid = _mm_setzero_si128();
+            let mut res = _mm_setzero_si128();
+            for &(first, last, digit) in &RANGES {
+                let mask = _mm_and_si128(
+                    _mm_cmpgt_epi8(symbols, _mm_set1_epi8(first as i8 - 1)),
+                    _mm_cmpgt_epi8(_mm_set1_epi8(last as i8 + 1), symbols),
+                );
+                let value = _mm_add_epi8(symbols, _mm_set1_epi8(digit.wrapping_sub(first) as i8));
+                valid = _mm_or_si128(valid, mask);
+                res = _mm_or_si128(res, _mm_and_si128(mask, value));
+            }
+            if _mm_movemask_epi8(valid) != 0xffff {
+                return false;
+            }
+            // SAFETY: unaligned store into a 16 bytes slice
+            unsafe { _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, res) };
+        }
+        symbols_to_digits_scalar(chunks.remainder(), out.into_remainder())
+    }
+
+    // 32 symbols at a time, the remainder goes through the SSE2 path
+    //
+    // SAFETY: the caller must check that the CPU supports AVX2
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn symbols_to_digits_avx2(data: &[u8], digits: &mut [u8]) -> bool {
+        let mut chunks = data.chunks_exact(32);
+        let mut out = digits.chunks_exact_mut(32);
+        for (chunk, out) in (&mut chunks).zip(&mut out) {
+            // SAFETY: unaligned load of a 32 bytes slice
+            let symbols = unsafe { _mm256_loadu_si256(chunk.as_ptr() as *const __m256i) };
+            let mut valid = _mm256_setzero_si256();
+            let mut res = _mm256_setzero_si256();
+            for &(first, last, digit) in &RANGES {
+                let mask = _mm256_and_si256(
+                    _mm256_cmpgt_epi8(symbols, _mm256_set1_epi8(first as i8 - 1)),
+                    _mm256_cmpgt_epi8(_mm256_set1_epi8(last as i8 + 1), symbols),
+                );
+                let value =
+                    _mm256_add_epi8(symbols, _mm256_set1_epi8(digit.wrapping_sub(first) as i8));
+                valid = _mm256_or_si256(valid, mask);
+                res = _mm256_or_si256(res, _mm256_and_si256(mask, value));
+            }
+            if _mm256_movemask_epi8(valid) != -1 {
+                return false;
+            }
+            // SAFETY: unaligned store into a 32 bytes slice
+            unsafe { _mm256_storeu_si256(out.as_mut_ptr() as *mut __m256i, res) };
+        }
+        // SAFETY: AVX2 implies SSE2
+        unsafe { symbols_to_digits_sse2(chunks.remainder(), out.into_remainder()) }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use super::{symbols_to_digits_scalar, RANGES};
+
+    // 16 symbols at a time, NEON is part of the AArch64 baseline
+    pub(super) fn symbols_to_digits(data: &[u8], digits: &mut [u8]) -> bool {
+        let mut chunks = data.chunks_exact(16);
+        let mut out = digits.chunks_exact_mut(16);
+        for (chunk, out) in (&mut chunks).zip(&mut out) {
+            // SAFETY: NEON is always available on AArch64, unaligned load and store of 16 bytes
+            // slices
+            let valid = unsafe {
+                let symbols = vld1q_u8(chunk.as_ptr());
+                let mut valid = vdupq_n_u8(0);
+                let mut res = vdupq_n_u8(0);
+                for &(first, last, digit) in &RANGES {
+                    let mask = vandq_u8(
+                        vcgeq_u8(symbols, vdupq_n_u8(first)),
+                        vcleq_u8(symbols, vdupq_n_u8(last)),
+                    );
+                    let value = vaddq_u8(symbols, vdupq_n_u8(digit.wrapping_sub(first)));
+                    valid = vorrq_u8(valid, mask);
+                    res = vorrq_u8(res, vandq_u8(mask, value));
+                }
+                vst1q_u8(out.as_mut_ptr(), res);
+                vminvq_u8(valid) == 0xff
+            };
+            if !valid {
+                return false;
+            }
+        }
+        symbols_to_digits_scalar(chunks.remainder(), out.into_remainder())
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm {
+    use core::arch::wasm32::*;
+
+    use super::{symbols_to_digits_scalar, RANGES};
+
+    // 16 symbols at a time, `simd128` is enabled at compile time
+    pub(super) fn symbols_to_digits(data: &[u8], digits: &mut [u8]) -> bool {
+        let mut chunks = data.chunks_exact(16);
+        let mut out = digits.chunks_exact_mut(16);
+        for (chunk, out) in (&mut chunks).zip(&mut out) {
+            // SAFETY: unaligned load of a 16 bytes slice, allowed in WebAssembly
+            let symbols = unsafe { v128_load(chunk.as_ptr() as *const v128) };
+            let mut valid = u8x16_splat(0);
+            let mut res = u8x16_splat(0);
+            for &(first, last, digit) in &RANGES {
+                let mask = v128_and(
+                    u8x16_ge(symbols, u8x16_splat(first)),
+                    u8x16_le(symbols, u8x16_splat(last)),
+                );
+                let value = u8x16_add(symbols, u8x16_splat(digit.wrapping_sub(first)));
+                valid = v128_or(valid, mask);
+                res = v128_or(res, v128_and(mask, value));
+            }
+            if !u8x16_all_true(valid) {
+                return false;
+            }
+            // SAFETY: unaligned store into a 16 bytes slice, allowed in WebAssembly
+            unsafe { v128_store(out.as_mut_ptr() as *mut v128, res) };
+        }
+        symbols_to_digits_scalar(chunks.remainder(), out.into_remainder())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{symbols_to_digits, symbols_to_digits_scalar};
+    use crate::base58::BASE58_CHARS;
+
+    #[test]
+    fn digits_match_alphabet() {
+        for c in 0..=255u8 {
+            // Place the symbol in the 32, 16 bytes vectors and in the scalar remainder
+            let mut data = [b'1'; 49];
+            for i in [0, 31, 32, 47, 48] {
+                data[i] = c;
+            }
+            let mut digits = [0; 49];
+            match BASE58_CHARS.iter().position(|&x| x == c) {
+                Some(digit) => {
+                    assert!(symbols_to_digits(&data, &mut digits));
+                    for i in [0, 31, 32, 47, 48] {
+                        assert_eq!(digit, digits[i] as usize);
+                    }
+                    assert_eq!(0, digits[1]);
+                }
+                None => assert!(!symbols_to_digits(&data, &mut digits)),
+            }
+        }
+    }
+
+    #[test]
+    fn digits_match_scalar() {
+        let data: [u8; 58 * 3] = core::array::from_fn(|i| BASE58_CHARS[i % 58]);
+        for len in 0..data.len() {
+            let mut digits = [0; 58 * 3];
+            let mut expected = [0; 58 * 3];
+            assert!(symbols_to_digits(&data[..len], &mut digits[..len]));
+            assert!(symbols_to_digits_scalar(&data[..len], &mut expected[..len]));
+            assert_eq!(expected, digits);
+        }
+    }
+}
@@ -0,0 +1,475 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Blocking `std::io` adapters encoding and decoding base58 incrementally
+//!
+//! [`Base58Encoder`] wraps a writer and encodes the bytes written to it, [`Base58Decoder`] wraps
+//! a reader and decodes the base58 symbols read from it. Both process the data in blocks with a
+//! constant memory usage, arbitrarily large inputs can be piped through them with
+//! [`std::io::copy`] without an async runtime, see the `stream` feature for the async
+//! counterparts.
+//!
+//! With the `check` feature, `new_check` constructors add the 4 bytes checksum when the encoder is
+//! finished and verify it when the decoder reaches EOF.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use std::io::{Read, Write};
+//! use base58_monero::sync_stream::{Base58Decoder, Base58Encoder};
+//!
+//! let mut encoder = Base58Encoder::new(Vec::new());
+//! encoder.write_all(b"Hello World")?;
+//! let encoded = encoder.finish()?;
+//! assert_eq!(b"D7LMXYjUbXc1fS9Z", &encoded[..]);
+//!
+//! let mut decoded = Vec::new();
+//! Base58Decoder::new(&encoded[..]).read_to_end(&mut decoded)?;
+//! assert_eq!(b"Hello World", &decoded[..]);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use core::fmt;
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "check")]
+use tiny_keccak::{Hasher, Keccak};
+
+#[cfg(feature = "check")]
+use crate::base58::Checksum;
+use crate::base58::{decode_block, read_block, write_encoded_block, Error};
+use crate::base58::{CHECKSUM_SIZE, FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE};
+
+/// Writer encoding the bytes written to it into base58 symbols written to the inner writer
+///
+/// Bytes are buffered until a full 8 bytes block is available. The last partial block, and the
+/// checksum of [`new_check`](Self::new_check) encoders, can only be written once: call
+/// [`finish`](Self::finish) after the last write. [`flush`](Write::flush) only flushes the inner
+/// writer. Dropping an unfinished encoder finishes it, ignoring errors.
+pub struct Base58Encoder<W: Write> {
+    // Taken only by `finish`
+    inner: Option<W>,
+    buf: [u8; FULL_BLOCK_SIZE],
+    len: usize,
+    finished: bool,
+    #[cfg(feature = "check")]
+    hasher: Option<Keccak>,
+}
+
+impl<W: Write> Base58Encoder<W> {
+    /// Create an encoder writing base58 symbols to `writer`
+    pub fn new(writer: W) -> Self {
+        Self {
+            inner: Some(writer),
+            buf: [0; FULL_BLOCK_SIZE],
+            len: 0,
+            finished: false,
+            #[cfg(feature = "check")]
+            hasher: None,
+        }
+    }
+
+    /// Create an encoder writing base58-check symbols to `writer`, the 4 bytes checksum is
+    /// appended when the encoder is finished
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    pub fn new_check(writer: W) -> Self {
+        let mut encoder = Self::new(writer);
+        encoder.hasher = Some(Keccak::v256());
+        encoder
+    }
+
+    /// Return a reference to the inner writer
+    pub fn get_ref(&self) -> &W {
+        match &self.inner {
+            Some(inner) => inner,
+            None => unreachable!(),
+        }
+    }
+
+    /// Return a mutable reference to the inner writer
+    ///
+    /// Writing directly to the inner writer corrupts the encoded output.
+    pub fn get_mut(&mut self) -> &mut W {
+        match &mut self.inner {
+            Some(inner) => inner,
+            None => unreachable!(),
+        }
+    }
+
+    /// Write the last partial block and the checksum, if any, and flush the inner writer
+    ///
+    /// Subsequent writes fail, finishing again does nothing.
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        let mut tail = [0u8; FULL_BLOCK_SIZE + CHECKSUM_SIZE];
+        let len = self.len;
+        tail[..len].copy_from_slice(&self.buf[..len]);
+        #[cfg(feature = "check")]
+        let len = match self.hasher.take() {
+            Some(hasher) => {
+                let mut hash = [0u8; 32];
+                hasher.finalize(&mut hash);
+                let checksum = Checksum::from_hash(&hash);
+                tail[len..len + CHECKSUM_SIZE].copy_from_slice(checksum.as_bytes());
+                len + CHECKSUM_SIZE
+            }
+            None => len,
+        };
+        let inner = self.get_mut();
+        tail[..len]
+            .chunks(FULL_BLOCK_SIZE)
+            .try_for_each(|block| write_encoded_block(block, inner))?;
+        inner.flush()
+    }
+
+    /// Finish the encoding, see [`try_finish`](Self::try_finish), and return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.try_finish()?;
+        match self.inner.take() {
+            Some(inner) => Ok(inner),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<W: Write> Write for Base58Encoder<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if self.finished {
+            return Err(io::Error::other("write to a finished base58 encoder"));
+        }
+        #[cfg(feature = "check")]
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(data);
+        }
+        for &b in data {
+            self.buf[self.len] = b;
+            self.len += 1;
+            if self.len == FULL_BLOCK_SIZE {
+                self.len = 0;
+                if let Some(inner) = &mut self.inner {
+                    write_encoded_block(&self.buf, inner)?;
+                }
+            }
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl<W: Write + fmt::Debug> fmt::Debug for Base58Encoder<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Base58Encoder");
+        s.field("inner", &self.inner);
+        #[cfg(feature = "check")]
+        s.field("check", &self.hasher.is_some());
+        s.field("finished", &self.finished).finish()
+    }
+}
+
+impl<W: Write> Drop for Base58Encoder<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.try_finish();
+        }
+    }
+}
+
+/// Reader decoding the base58 symbols of the inner reader
+///
+/// The inner reader is consumed in 11 characters blocks. Decoding errors are returned as
+/// [`std::io::ErrorKind::InvalidData`] errors wrapping [`Error`](enum@Error). The first error,
+/// including the errors of the inner reader, is returned again by every later read.
+///
+/// The decoders created with [`new_check`](Self::new_check) hold back the trailing 4 bytes and
+/// verify the checksum at EOF: the bytes preceding the checksum are returned before it is
+/// verified, discard them when reading fails with [`Error::InvalidChecksum`]. The held back bytes
+/// are never returned once the verification failed.
+pub struct Base58Decoder<R: Read> {
+    inner: R,
+    // Decoded bytes not returned yet, the checksum is held back until EOF
+    buf: [u8; 2 * FULL_BLOCK_SIZE + CHECKSUM_SIZE],
+    start: usize,
+    end: usize,
+    eof: bool,
+    // First error, returned by every later read
+    error: Option<io::Error>,
+    #[cfg(feature = "check")]
+    hasher: Option<Keccak>,
+}
+
+// Copy of an error returned by the decoder, keeping the wrapped base58 error
+fn copy_error(e: &io::Error) -> io::Error {
+    match e.get_ref().and_then(|inner| inner.downcast_ref::<Error>()) {
+        Some(inner) => io::Error::new(e.kind(), inner.duplicate()),
+        None => io::Error::new(e.kind(), e.to_string()),
+    }
+}
+
+impl<R: Read> Base58Decoder<R> {
+    /// Create a decoder reading base58 symbols from `reader`
+    pub fn new(reader: R) -> Self {
+        Self {
+            inner: reader,
+            buf: [0; 2 * FULL_BLOCK_SIZE + CHECKSUM_SIZE],
+            start: 0,
+            end: 0,
+            eof: false,
+            error: None,
+            #[cfg(feature = "check")]
+            hasher: None,
+        }
+    }
+
+    /// Create a decoder reading base58-check symbols from `reader`, the 4 bytes checksum is
+    /// verified at EOF and not returned
+    #[cfg(feature = "check")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "check")))]
+    pub fn new_check(reader: R) -> Self {
+        let mut decoder = Self::new(reader);
+        decoder.hasher = Some(Keccak::v256());
+        decoder
+    }
+
+    /// Return a reference to the inner reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Return a mutable reference to the inner reader
+    ///
+    /// Reading directly from the inner reader corrupts the decoded output.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Return the inner reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // Number of buffered bytes that may be the checksum
+    fn held(&self) -> usize {
+        #[cfg(feature = "check")]
+        if self.hasher.is_some() && !self.eof {
+            return CHECKSUM_SIZE;
+        }
+        0
+    }
+
+    // Decode the next block of the inner reader into the buffer
+    fn fill_buf(&mut self) -> io::Result<()> {
+        self.buf.copy_within(self.start..self.end, 0);
+        self.end -= self.start;
+        self.start = 0;
+
+        let mut block = [0u8; FULL_ENCODED_BLOCK_SIZE];
+        let len = read_block(&mut self.inner, &mut block)?;
+        if len > 0 {
+            let decoded = decode_block(&block[..len])
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let bytes = decoded.as_bytes();
+            self.buf[self.end..self.end + bytes.len()].copy_from_slice(bytes);
+            self.end += bytes.len();
+        }
+        if len < FULL_ENCODED_BLOCK_SIZE {
+            self.eof = true;
+            #[cfg(feature = "check")]
+            if let Some(mut hasher) = self.hasher.take() {
+                let invalid = || io::Error::new(io::ErrorKind::InvalidData, Error::InvalidChecksum);
+                let len = self.end.checked_sub(CHECKSUM_SIZE).ok_or_else(invalid)?;
+                hasher.update(&self.buf[..len]);
+                let mut hash = [0u8; 32];
+                hasher.finalize(&mut hash);
+                if Checksum::from_hash(&hash) != self.buf[len..self.end] {
+                    return Err(invalid());
+                }
+                self.end = len;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Base58Decoder<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if out.is_empty() {
+            return Ok(0);
+        }
+        loop {
+            let available = (self.end - self.start).saturating_sub(self.held());
+            if available > 0 {
+                let len = available.min(out.len());
+                let bytes = &self.buf[self.start..self.start + len];
+                #[cfg(feature = "check")]
+                if let Some(hasher) = &mut self.hasher {
+                    hasher.update(bytes);
+                }
+                out[..len].copy_from_slice(bytes);
+                self.start += len;
+                return Ok(len);
+            }
+            if let Some(e) = &self.error {
+                return Err(copy_error(e));
+            }
+            if self.eof {
+                return Ok(0);
+            }
+            if let Err(e) = self.fill_buf() {
+                // Drop the bytes held back for the checksum and stop decoding
+                self.start = 0;
+                self.end = 0;
+                self.error = Some(copy_error(&e));
+                return Err(e);
+            }
+        }
+    }
+}
+
+impl<R: Read + fmt::Debug> fmt::Debug for Base58Decoder<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Base58Decoder");
+        s.field("inner", &self.inner);
+        #[cfg(feature = "check")]
+        s.field("check", &self.hasher.is_some());
+        s.field("eof", &self.eof).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read, Write};
+
+    use super::{Base58Decoder, Base58Encoder};
+    use crate::base58::{decode, encode, Error};
+
+    // Reader returning at most one byte per read
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.0.len().min(buf.len()).min(1);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    fn invalid_data(err: io::Error) -> Error {
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        match err.into_inner().map(|e| e.downcast::<Error>()) {
+            Some(Ok(e)) => *e,
+            _ => panic!("not a base58 error"),
+        }
+    }
+
+    #[test]
+    fn test_sync_encoder() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 7, 8, 9, 16, 100, 256] {
+            let mut encoder = Base58Encoder::new(Vec::new());
+            // Uneven writes
+            for chunk in data[..len].chunks(3) {
+                encoder.write_all(chunk).unwrap();
+            }
+            let encoded = encoder.finish().unwrap();
+            assert_eq!(encode(&data[..len]).unwrap().as_bytes(), &encoded[..]);
+        }
+
+        // Dropping the encoder finishes it
+        let mut out = Vec::new();
+        Base58Encoder::new(&mut out)
+            .write_all(b"Hello World")
+            .unwrap();
+        assert_eq!(b"D7LMXYjUbXc1fS9Z", &out[..]);
+
+        let mut encoder = Base58Encoder::new(Vec::new());
+        encoder.try_finish().unwrap();
+        assert!(encoder.write_all(b"a").is_err());
+    }
+
+    #[test]
+    fn test_sync_decoder() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 7, 8, 9, 16, 100, 256] {
+            let encoded = encode(&data[..len]).unwrap();
+            let mut decoded = Vec::new();
+            Base58Decoder::new(Trickle(encoded.as_bytes()))
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(&data[..len], &decoded[..]);
+        }
+
+        let mut decoded = Vec::new();
+        let err = Base58Decoder::new(&b"D7LMXYjUbXc10"[..])
+            .read_to_end(&mut decoded)
+            .unwrap_err();
+        assert_eq!(Error::InvalidSymbol, invalid_data(err));
+        assert_eq!(decode("D7LMXYjUbXc").unwrap(), decoded);
+
+        // The error is returned again, decoding does not resume in the middle of the input
+        let mut decoder = Base58Decoder::new(&b"D7LMXYjUbXc10D7LMXYjUbXc"[..]);
+        let mut buf = [0u8; 64];
+        assert_eq!(8, decoder.read(&mut buf).unwrap());
+        for _ in 0..2 {
+            let err = decoder.read(&mut buf).unwrap_err();
+            assert_eq!(Error::InvalidSymbol, invalid_data(err));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_sync_check() {
+        use crate::base58::encode_check;
+
+        let data: Vec<u8> = (0..=255).collect();
+        for len in [0, 1, 3, 4, 5, 8, 12, 100, 256] {
+            let mut encoder = Base58Encoder::new_check(Vec::new());
+            for chunk in data[..len].chunks(5) {
+                encoder.write_all(chunk).unwrap();
+            }
+            let encoded = encoder.finish().unwrap();
+            assert_eq!(encode_check(&data[..len]).unwrap().as_bytes(), &encoded[..]);
+
+            let mut decoded = Vec::new();
+            Base58Decoder::new_check(Trickle(&encoded))
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(&data[..len], &decoded[..]);
+        }
+
+        for invalid in ["D7LMXYjUbXc1fS9Z", "111", ""] {
+            let err = Base58Decoder::new_check(invalid.as_bytes())
+                .read_to_end(&mut Vec::new())
+                .unwrap_err();
+            assert_eq!(Error::InvalidChecksum, invalid_data(err));
+        }
+
+        // The bytes held back for the checksum are never returned after a failed verification
+        let mut decoder = Base58Decoder::new_check(&b"D7LMXYjUbXc1fS9Z"[..]);
+        let mut buf = [0u8; 64];
+        assert_eq!(4, decoder.read(&mut buf).unwrap());
+        for _ in 0..2 {
+            let err = decoder.read(&mut buf).unwrap_err();
+            assert_eq!(Error::InvalidChecksum, invalid_data(err));
+        }
+    }
+}
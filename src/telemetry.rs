@@ -0,0 +1,189 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Process-wide telemetry of failed validations
+//!
+//! Checksum failures and invalid-symbol rejections of the one-shot decoding functions, i.e.
+//! [`decode`](crate::decode), [`decode_check`](crate::decode_check) and the functions built on
+//! them such as the [`address`](crate::address) helpers, are counted and reported to an optional
+//! hook, so spikes of malformed submissions can be monitored without wrapping every call site.
+//!
+//! Only coarse metadata is reported, see [`Failure`], never the input or the decoded payload.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::telemetry::{self, FailureKind};
+//!
+//! telemetry::set_hook(|failure| {
+//!     if failure.kind == FailureKind::InvalidChecksum {
+//!         // e.g. increment a metrics counter
+//!     }
+//! });
+//! assert!(base58_monero::decode("10").is_err());
+//! assert!(telemetry::counters().invalid_symbols >= 1);
+//! telemetry::clear_hook();
+//! ```
+
+extern crate alloc;
+use alloc::boxed::Box;
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use crate::base58::Error;
+
+/// Kind of failed validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureKind {
+    /// A symbol is not in the base58 alphabet
+    InvalidSymbol,
+    /// The 4 bytes checksum does not match the payload
+    InvalidChecksum,
+}
+
+/// Metadata of a failed validation reported to the hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Failure {
+    /// Kind of failure
+    pub kind: FailureKind,
+    /// Whether the input was decoded as a base58-check string
+    pub check: bool,
+    /// Length of the encoded input, in bytes
+    pub input_len: usize,
+}
+
+/// Number of failed validations since the start of the process
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Counters {
+    /// Number of inputs rejected with an invalid symbol
+    pub invalid_symbols: u64,
+    /// Number of inputs rejected with an invalid checksum
+    pub checksum_failures: u64,
+}
+
+type Hook = Box<dyn Fn(&Failure) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+static INVALID_SYMBOLS: AtomicU64 = AtomicU64::new(0);
+static CHECKSUM_FAILURES: AtomicU64 = AtomicU64::new(0);
+
+/// Install the hook called on each failed validation, replacing the previous one
+///
+/// The hook is called on the thread running the decoding function, it should be cheap and must
+/// not install or clear the hook itself.
+pub fn set_hook(hook: impl Fn(&Failure) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(hook));
+}
+
+/// Remove the hook, failures are still counted
+pub fn clear_hook() {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Return the number of failed validations since the start of the process
+pub fn counters() -> Counters {
+    Counters {
+        invalid_symbols: INVALID_SYMBOLS.load(Ordering::Relaxed),
+        checksum_failures: CHECKSUM_FAILURES.load(Ordering::Relaxed),
+    }
+}
+
+// Count and report a decoding error, other errors than invalid symbols and checksums are ignored
+pub(crate) fn record(error: &Error, check: bool, input_len: usize) {
+    let kind = match error {
+        Error::InvalidSymbol => {
+            INVALID_SYMBOLS.fetch_add(1, Ordering::Relaxed);
+            FailureKind::InvalidSymbol
+        }
+        #[cfg(feature = "check")]
+        Error::InvalidChecksum => {
+            CHECKSUM_FAILURES.fetch_add(1, Ordering::Relaxed);
+            FailureKind::InvalidChecksum
+        }
+        _ => return,
+    };
+    if let Some(hook) = HOOK.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        hook(&Failure {
+            kind,
+            check,
+            input_len,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+    use std::sync::{Arc, Mutex};
+
+    use super::{clear_hook, counters, set_hook, Failure, FailureKind};
+    use crate::base58::decode;
+
+    // The hook and counters are process-wide, a single test exercises them to avoid interferences
+    // with tests running in parallel
+    #[test]
+    fn test_telemetry() {
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let hook_failures = failures.clone();
+        // Other tests decode invalid inputs concurrently, only keep the inputs of this test
+        set_hook(move |failure| {
+            if failure.input_len == 13 {
+                hook_failures.lock().unwrap().push(*failure);
+            }
+        });
+
+        let before = counters();
+        assert!(decode("D7LMXYjUbXc10").is_err());
+        assert!(decode("D7LMXYjUbXc1f").is_ok());
+        assert!(counters().invalid_symbols > before.invalid_symbols);
+        #[cfg(feature = "check")]
+        {
+            use crate::base58::decode_check;
+            assert!(decode_check("D7LMXYjUbXc1f").is_err());
+            assert!(counters().checksum_failures > before.checksum_failures);
+            // Probing the format is not a failed validation
+            assert!(crate::base58::decode_hex_or_base58_check("D7LMXYjUbXc1f").is_ok());
+        }
+        clear_hook();
+        assert!(decode("D7LMXYjUbXc10").is_err());
+
+        let failures = failures.lock().unwrap();
+        assert_eq!(
+            Failure {
+                kind: FailureKind::InvalidSymbol,
+                check: false,
+                input_len: 13,
+            },
+            failures[0]
+        );
+        #[cfg(feature = "check")]
+        assert_eq!(
+            Failure {
+                kind: FailureKind::InvalidChecksum,
+                check: true,
+                input_len: 13,
+            },
+            failures[1]
+        );
+        #[cfg(feature = "check")]
+        assert_eq!(2, failures.len());
+        #[cfg(not(feature = "check"))]
+        assert_eq!(1, failures.len());
+    }
+}
@@ -0,0 +1,142 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Generators of random test data
+//!
+//! Functions generating syntactically valid random addresses and base58 strings, for downstream
+//! integration tests and load generators. The keys are random bytes, not valid curve points, so
+//! the addresses decode and validate but cannot receive funds.
+//!
+//! All functions take the random number generator as argument, use a seeded generator to get
+//! reproducible data.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::address::{validate_address, AddressKind, Network};
+//! use base58_monero::test_util::random_address;
+//!
+//! let addr = random_address(&mut rand::rng(), AddressKind::Standard(Network::Mainnet));
+//! assert_eq!(Ok(()), validate_address(&addr, Network::Mainnet));
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec;
+
+use rand::Rng;
+
+use crate::address::{encode_address, encode_integrated_address, AddressKind};
+use crate::address::{PAYMENT_ID_SIZE, PUBLIC_KEY_SIZE};
+use crate::base58::{encode_check, Encoder, Result};
+
+// Encoding bytes never fails, unwrap the results of the encoding functions
+fn encoded(res: Result<String>) -> String {
+    match res {
+        Ok(encoded) => encoded,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Generate a random address of the given kind, with random keys and a valid checksum
+///
+/// Integrated addresses get a random payment ID.
+pub fn random_address<R: Rng + ?Sized>(rng: &mut R, kind: AddressKind) -> String {
+    let mut spend_key = [0u8; PUBLIC_KEY_SIZE];
+    let mut view_key = [0u8; PUBLIC_KEY_SIZE];
+    rng.fill_bytes(&mut spend_key);
+    rng.fill_bytes(&mut view_key);
+    match kind {
+        AddressKind::Integrated(_) => {
+            let mut payment_id = [0u8; PAYMENT_ID_SIZE];
+            rng.fill_bytes(&mut payment_id);
+            encoded(encode_integrated_address(
+                kind.prefix(),
+                &spend_key,
+                &view_key,
+                &payment_id,
+            ))
+        }
+        _ => encoded(encode_address(kind.prefix(), &spend_key, &view_key)),
+    }
+}
+
+/// Generate a random base58 string decoding to `len` bytes
+pub fn random_base58<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
+    let mut data = vec![0u8; len];
+    rng.fill_bytes(&mut data);
+    let mut encoder = Encoder::new();
+    encoder.update(data);
+    encoder.finish()
+}
+
+/// Generate a random base58-check string decoding to `len` bytes, checksum excluded
+pub fn random_base58_check<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
+    let mut data = vec![0u8; len];
+    rng.fill_bytes(&mut data);
+    encoded(encode_check(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::{random_address, random_base58, random_base58_check};
+    use crate::address::{classify_address, AddressKind, Network};
+    use crate::address::{INTEGRATED_ADDRESS_LENGTH, STANDARD_ADDRESS_LENGTH};
+    use crate::base58::{decode, decode_check};
+
+    #[test]
+    fn test_random_address() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for network in Network::ALL {
+            for kind in [
+                AddressKind::Standard(network),
+                AddressKind::Integrated(network),
+                AddressKind::Subaddress(network),
+            ] {
+                let addr = random_address(&mut rng, kind);
+                let len = match kind {
+                    AddressKind::Integrated(_) => INTEGRATED_ADDRESS_LENGTH,
+                    _ => STANDARD_ADDRESS_LENGTH,
+                };
+                assert_eq!(len, addr.len());
+                assert_eq!(Ok(kind), classify_address(&addr));
+                assert_ne!(addr, random_address(&mut rng, kind));
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_base58() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for len in 0..64 {
+            assert_eq!(len, decode(random_base58(&mut rng, len)).unwrap().len());
+            assert_eq!(
+                len,
+                decode_check(random_base58_check(&mut rng, len))
+                    .unwrap()
+                    .len()
+            );
+        }
+
+        // Same seed, same data
+        assert_eq!(
+            random_base58(&mut StdRng::seed_from_u64(1), 32),
+            random_base58(&mut StdRng::seed_from_u64(1), 32)
+        );
+    }
+}
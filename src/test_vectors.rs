@@ -0,0 +1,477 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Monero base58 test vectors
+//!
+//! Vectors used to test this crate against the Monero reference implementation, published so
+//! downstream crates and alternative implementations can run the same conformance tests.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::test_vectors::ENCODED;
+//! use base58_monero::{decode, encode};
+//!
+//! for vector in ENCODED {
+//!     assert_eq!(Ok(String::from(vector.encoded)), encode(vector.data));
+//!     assert_eq!(Ok(Vec::from(vector.data)), decode(vector.encoded));
+//! }
+//! ```
+
+use crate::base58::Error;
+
+/// Valid vector, `data` encodes to `encoded` and `encoded` decodes to `data`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vector {
+    /// Raw bytes
+    pub data: &'static [u8],
+    /// Base58 encoding of the raw bytes
+    pub encoded: &'static str,
+}
+
+/// Invalid vector, decoding `encoded` fails with `error`
+#[derive(Debug)]
+pub struct InvalidVector {
+    /// Invalid base58 string
+    pub encoded: &'static str,
+    /// Error returned when decoding the string
+    pub error: Error,
+}
+
+/// Address vector, `hex` encodes to `address` with the base58-check encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressVector {
+    /// Hex encoded prefix and keys of the address, without checksum
+    pub hex: &'static str,
+    /// Base58-check encoded address
+    pub address: &'static str,
+}
+
+macro_rules! vectors {
+    ($(($data:expr, $encoded:expr)),* $(,)?) => {
+        &[$(Vector { data: $data, encoded: $encoded }),*]
+    };
+}
+
+/// Single block vectors, from 1 to 8 bytes blocks
+pub const BLOCKS: &[Vector] = vectors![
+    (b"\x00", "11"),
+    (b"\x39", "1z"),
+    (b"\xFF", "5Q"),
+    (b"\x00\x00", "111"),
+    (b"\x00\x39", "11z"),
+    (b"\x01\x00", "15R"),
+    (b"\xFF\xFF", "LUv"),
+    (b"\x00\x00\x00", "11111"),
+    (b"\x00\x00\x39", "1111z"),
+    (b"\x01\x00\x00", "11LUw"),
+    (b"\xFF\xFF\xFF", "2UzHL"),
+    (b"\x00\x00\x00\x39", "11111z"),
+    (b"\xFF\xFF\xFF\xFF", "7YXq9G"),
+    (b"\x00\x00\x00\x00\x39", "111111z"),
+    (b"\xFF\xFF\xFF\xFF\xFF", "VtB5VXc"),
+    (b"\x00\x00\x00\x00\x00\x39", "11111111z"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF", "3CUsUpv9t"),
+    (b"\x00\x00\x00\x00\x00\x00\x39", "111111111z"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "Ahg1opVcGW"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x39", "1111111111z"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "jpXCZedGfVQ"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", "11111111111"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x01", "11111111112"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x08", "11111111119"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x09", "1111111111A"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x3A", "11111111121"),
+    (b"\x00\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "1Ahg1opVcGW"),
+    (b"\x06\x15\x60\x13\x76\x28\x79\xF7", "22222222222"),
+    (b"\x05\xE0\x22\xBA\x37\x4B\x2A\x00", "1z111111111"),
+];
+
+/// Multi-blocks vectors, including empty and partial last blocks
+pub const ENCODED: &[Vector] = vectors![
+    (b"\x00", "11"),
+    (b"\x00\x00", "111"),
+    (b"\x00\x00\x00", "11111"),
+    (b"\x00\x00\x00\x00", "111111"),
+    (b"\x00\x00\x00\x00\x00", "1111111"),
+    (b"\x00\x00\x00\x00\x00\x00", "111111111"),
+    (b"\x00\x00\x00\x00\x00\x00\x00", "1111111111"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00", "11111111111"),
+    (b"\x00\x00\x00\x00\x00\x00\x00\x00\x00", "1111111111111"),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "11111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "1111111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "11111111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "111111111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "11111111111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "111111111111111111111"
+    ),
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        "1111111111111111111111"
+    ),
+    (
+        b"\x06\x15\x60\x13\x76\x28\x79\xF7\xFF\xFF\xFF\xFF\xFF",
+        "22222222222VtB5VXc"
+    ),
+    (b"", ""),
+    (b"\xFF", "5Q"),
+    (b"\xFF\xFF", "LUv"),
+    (b"\xFF\xFF\xFF", "2UzHL"),
+    (b"\xFF\xFF\xFF\xFF", "7YXq9G"),
+    (b"\xFF\xFF\xFF\xFF\xFF", "VtB5VXc"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF", "3CUsUpv9t"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "Ahg1opVcGW"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "jpXCZedGfVQ"),
+    (b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", "jpXCZedGfVQ5Q"),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQLUv"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQ2UzHL"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQ7YXq9G"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQVtB5VXc"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQ3CUsUpv9t"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQAhg1opVcGW"
+    ),
+    (
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "jpXCZedGfVQjpXCZedGfVQ"
+    ),
+];
+
+/// Invalid base58 strings: invalid block sizes, overflowing blocks and invalid symbols
+pub const INVALID: &[InvalidVector] = &[
+    InvalidVector {
+        encoded: "1",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "z",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "1111",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "zzzz",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "11111111",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "zzzzzzzz",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789AB1",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789ABz",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789AB1111",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzz",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789AB11111111",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzzzz",
+        error: Error::InvalidBlockSize,
+    },
+    InvalidVector {
+        encoded: "5R",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "LUw",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "2UzHM",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "7YXq9H",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "VtB5VXd",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "3CUsUpv9u",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "Ahg1opVcGX",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "jpXCZedGfVR",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789AB5R",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABLUw",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789AB2UzHM",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789AB7YXq9H",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABVtB5VXd",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789AB3CUsUpv9u",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABAhg1opVcGX",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABjpXCZedGfVR",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "123456789ABzzzzzzzzzzz",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "zzzzzzzzzzz11",
+        error: Error::Overflow,
+    },
+    InvalidVector {
+        encoded: "10",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11I",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11O11",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11l111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11_11111111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "1101111111111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11I11111111111111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "11O1111111111111111111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "1111111111110",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "111111111111l1111",
+        error: Error::InvalidSymbol,
+    },
+    InvalidVector {
+        encoded: "111111111111_111111111",
+        error: Error::InvalidSymbol,
+    },
+];
+
+/// Monero mainnet addresses with their hex encoded prefix and keys
+pub const ADDRESSES: &[AddressVector] = &[
+    AddressVector {
+        hex: "12f4bd0587c43594b0ddb2ef4e616d24232d14eee07f45b46ac19ef3b11e7c7e6be2a59b6284ad5b1a1b43051d07e788756dcfff36008637322a1c975eeb614927",
+        address: "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5",
+    },
+    AddressVector {
+        hex: "1298a05f07a0c9f94da6e0bb1ebe819748ab787e95b72f6157555d2fa45644e076319c740890b4f86fdbe5528942af2c52c6810b6c9773d903437c090d99b39707",
+        address: "47Qa9iJeiYxDzKakP4SxpWD9zKB7B1nYgFcF9TdvxVzXLmdR6dX8BNPKiAyyZqVbcPEr2TYdJrRxC1YfM1APP9qg1oBnVip",
+    },
+    AddressVector {
+        hex: "1284c19bf4557a66aaa18f2af53814d694a7ccf0c6a245bcb10546ea40f6e261a8b6a587843c6943beeba8f386547f53e332bcef66bfee04de027879b51ec5fbe9",
+        address: "46eu6J7WC5jVYLT2NPovGDRs9NMyJpeH1WcKfBRNZ1CLVDjTDtKopLiYwAmhc4Bx9gf17DGe6CubRe8mm3Z1HNqgTNKbyu8",
+    },
+    AddressVector {
+        hex: "128916f019baad1f65e2eb2deae8af83045d7be1accf57034fb2b23b72a4cf023a9429b5ffcaf9daf1f4d5e3c85906aefc554f15e95956c185e60e5521cb71b8b6",
+        address: "46pRWGRUvUvJ3Rh7kRujCW1jMASA18S9xELAuPT28dguAoHfhLZVKqshUHF7XwdmUZjCx1jaEkYHWPPz7WVkz26TMbFxFq2",
+    },
+];
+
+/// Monero addresses with an invalid checksum
+pub const INVALID_CHECKSUM: &[&str] = &[
+    "46pRWGRUvUvJ3Rh7kRujCW1jMASA18S9xELAuPT28dguAoHfhLZVKqshUHF7XwdmUZjCx1jaEkYHWPPz7WVkz26TMbFxFq3",
+    "46Qa9iJeiYxDzKakP4SxpWD9zKB7B1nYgFcF9TdvxVzXLmdR6dX8BNPKiAyyZqVbcPEr2TYdJrRxC1YfM1APP9qg1oBnVip",
+    "46eu6J7WC5jVYLT2NPovGDRs9NMyJpeH1WcKfBRNZ1CLV3jTDtKopLiYwAmhc4Bx9gf17DGe6CubRe8mm3Z1HNqgTNKbyu8",
+    "46pRWGRUvUvJ3Rh7kRujCW1jMASA18S9xELAuPT28dguA1HfhLZVKqshUHF7XwdmUZjCx1jaEkYHWPPz7WVkz26TMbFxFq2",
+];
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use super::{BLOCKS, ENCODED, INVALID};
+    use crate::base58::{decode, encode};
+
+    #[cfg(feature = "check")]
+    use super::{ADDRESSES, INVALID_CHECKSUM};
+    #[cfg(feature = "check")]
+    use crate::base58::{decode_check, encode_check, Error};
+
+    #[test]
+    fn test_vectors() {
+        for vector in BLOCKS.iter().chain(ENCODED) {
+            assert_eq!(Ok(String::from(vector.encoded)), encode(vector.data));
+            assert_eq!(Ok(Vec::from(vector.data)), decode(vector.encoded));
+        }
+        for vector in INVALID {
+            assert_eq!(Err(&vector.error), decode(vector.encoded).as_ref());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "check")]
+    fn test_address_vectors() {
+        for vector in ADDRESSES {
+            let hex = hex::decode(vector.hex).unwrap();
+            assert_eq!(Ok(String::from(vector.address)), encode_check(&hex));
+            assert_eq!(Ok(hex), decode_check(vector.address));
+        }
+        for addr in INVALID_CHECKSUM {
+            assert_eq!(Err(Error::InvalidChecksum), decode_check(addr));
+        }
+    }
+}
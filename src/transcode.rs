@@ -0,0 +1,270 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Transcoding between base64 and Monero base58
+//!
+//! Converts directly between the standard padded base64 encoding and Monero base58 without
+//! materializing the binary payload. Both encodings are processed in chunks of 24 bytes, i.e. 32
+//! base64 characters or 33 base58 characters, so memory usage is bounded by the chunk size.
+//!
+//! Invalid base64 characters are reported as [`Error::InvalidSymbol`] and invalid base64 lengths
+//! or misplaced padding as [`Error::InvalidLength`].
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::transcode::{base58_to_base64, base64_to_base58};
+//!
+//! assert_eq!(Ok("D7LMXYjUbXc1fS9Z".into()), base64_to_base58("SGVsbG8gV29ybGQ="));
+//! assert_eq!(Ok("SGVsbG8gV29ybGQ=".into()), base58_to_base64("D7LMXYjUbXc1fS9Z"));
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::{DecodeError, DecodeSliceError, Engine};
+
+#[cfg(feature = "stream")]
+use async_stream::try_stream;
+#[cfg(feature = "stream")]
+use futures_util::pin_mut;
+#[cfg(feature = "stream")]
+use futures_util::stream::FusedStream;
+#[cfg(feature = "stream")]
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::base58::{
+    decode_block, encode_block_chars, Error, Result, FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE,
+};
+
+// Number of bytes converted at once, common multiple of the base64 and base58 block sizes
+const CHUNK_SIZE: usize = 3 * FULL_BLOCK_SIZE;
+// Number of base64 characters encoding a chunk
+const BASE64_CHUNK_SIZE: usize = CHUNK_SIZE / 3 * 4;
+// Number of base58 characters encoding a chunk
+const BASE58_CHUNK_SIZE: usize = 3 * FULL_ENCODED_BLOCK_SIZE;
+
+fn base64_error(e: DecodeSliceError) -> Error {
+    match e {
+        DecodeSliceError::DecodeError(DecodeError::InvalidByte(..))
+        | DecodeSliceError::DecodeError(DecodeError::InvalidLastSymbol(..)) => Error::InvalidSymbol,
+        _ => Error::InvalidLength,
+    }
+}
+
+// Convert a chunk of base64 characters, only the last chunk may be shorter than a full chunk or
+// decode to less than a full chunk
+fn base64_chunk_to_base58(chunk: &[u8], last: bool, out: &mut String) -> Result<()> {
+    let mut bytes = [0u8; CHUNK_SIZE];
+    let len = STANDARD
+        .decode_slice(chunk, &mut bytes)
+        .map_err(base64_error)?;
+    if !last && len < CHUNK_SIZE {
+        // Padding before the end of the input
+        return Err(Error::InvalidLength);
+    }
+    for block in bytes[..len].chunks(FULL_BLOCK_SIZE) {
+        out.push_str(encode_block_chars(block)?.as_str());
+    }
+    Ok(())
+}
+
+// Convert a chunk of base58 characters, only the last chunk may be shorter than a full chunk
+fn base58_chunk_to_base64(chunk: &[u8], out: &mut String) -> Result<()> {
+    let mut bytes = [0u8; CHUNK_SIZE];
+    let mut len = 0;
+    for block in chunk.chunks(FULL_ENCODED_BLOCK_SIZE) {
+        let block = decode_block(block)?;
+        bytes[len..len + block.size()].copy_from_slice(block.as_bytes());
+        len += block.size();
+    }
+    let mut encoded = [0u8; BASE64_CHUNK_SIZE];
+    let size = match STANDARD.encode_slice(&bytes[..len], &mut encoded) {
+        Ok(size) => size,
+        // The output buffer fits a full chunk
+        Err(_) => unreachable!(),
+    };
+    // Base64 alphabet is ASCII only
+    out.extend(encoded[..size].iter().map(|&c| c as char));
+    Ok(())
+}
+
+/// Transcode a padded base64 string into a base58-encoded string
+pub fn base64_to_base58(data: impl AsRef<str>) -> Result<String> {
+    let data = data.as_ref().as_bytes();
+    let mut res = String::new();
+    let mut chunks = data.chunks(BASE64_CHUNK_SIZE).peekable();
+    while let Some(chunk) = chunks.next() {
+        base64_chunk_to_base58(chunk, chunks.peek().is_none(), &mut res)?;
+    }
+    Ok(res)
+}
+
+/// Transcode a base58-encoded string into a padded base64 string
+pub fn base58_to_base64(data: impl AsRef<str>) -> Result<String> {
+    let mut res = String::new();
+    for chunk in data.as_ref().as_bytes().chunks(BASE58_CHUNK_SIZE) {
+        base58_chunk_to_base64(chunk, &mut res)?;
+    }
+    Ok(res)
+}
+
+// Fill `buf` from `reader`, returns less than `buf.len()` bytes only at EOF
+#[cfg(feature = "stream")]
+async fn read_chunk<T>(reader: &mut T, buf: &mut [u8]) -> Result<usize>
+where
+    T: AsyncReadExt + Unpin,
+{
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]).await? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    Ok(len)
+}
+
+/// Transcode a padded base64 stream into a base58 stream of characters
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn base64_to_base58_stream<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut buf = [0u8; BASE64_CHUNK_SIZE];
+        let mut next = [0u8; BASE64_CHUNK_SIZE];
+        let mut len = read_chunk(&mut data, &mut buf).await?;
+        let mut out = String::new();
+        while len > 0 {
+            // Read ahead to know whether the current chunk is the last one
+            let next_len = read_chunk(&mut data, &mut next).await?;
+            base64_chunk_to_base58(&buf[..len], next_len == 0, &mut out)?;
+            for c in out.drain(..) {
+                yield c;
+            }
+            buf = next;
+            len = next_len;
+        }
+    })
+}
+
+/// Transcode a base58 stream into a padded base64 stream of characters
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub fn base58_to_base64_stream<T>(data: T) -> impl FusedStream<Item = Result<char>> + Unpin
+where
+    T: AsyncRead,
+{
+    Box::pin(try_stream! {
+        pin_mut!(data);
+        let mut buf = [0u8; BASE58_CHUNK_SIZE];
+        let mut out = String::new();
+        loop {
+            let len = read_chunk(&mut data, &mut buf).await?;
+            if len > 0 {
+                base58_chunk_to_base64(&buf[..len], &mut out)?;
+                for c in out.drain(..) {
+                    yield c;
+                }
+            }
+            if len < BASE58_CHUNK_SIZE {
+                break;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+
+    use super::{base58_to_base64, base64_to_base58};
+    use crate::base58::{encode, Error};
+
+    #[test]
+    fn transcode() {
+        for len in 0..100 {
+            let data: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(91)).collect();
+            let base64 = STANDARD.encode(&data);
+            let base58 = encode(&data).unwrap();
+            assert_eq!(Ok(base58.clone()), base64_to_base58(&base64));
+            assert_eq!(Ok(base64), base58_to_base64(&base58));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Err(Error::InvalidSymbol), base64_to_base58("SGVs*G8="));
+        assert_eq!(Err(Error::InvalidLength), base64_to_base58("SGVsbG8"));
+        // Padding in the middle of the input
+        let mut data = String::from(&STANDARD.encode([0u8; 23])[..]);
+        data.push_str("AAAA");
+        assert_eq!(Err(Error::InvalidLength), base64_to_base58(&data));
+
+        assert_eq!(Err(Error::InvalidSymbol), base58_to_base64("D7LMXYjUbX0"));
+        assert_eq!(Err(Error::InvalidBlockSize), base58_to_base64("1"));
+        assert_eq!(Err(Error::Overflow), base58_to_base64("5R"));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "stream")]
+    async fn transcode_stream() {
+        use futures_util::pin_mut;
+        use futures_util::stream::{Stream, StreamExt};
+
+        use super::{base58_to_base64_stream, base64_to_base58_stream};
+
+        async fn collect(
+            stream: impl Stream<Item = crate::base58::Result<char>>,
+        ) -> crate::base58::Result<String> {
+            pin_mut!(stream);
+            let mut res = String::new();
+            while let Some(c) = stream.next().await {
+                res.push(c?);
+            }
+            Ok(res)
+        }
+
+        for len in 0..100 {
+            let data: Vec<u8> = (0..len as u8).map(|b| b.wrapping_mul(91)).collect();
+            let base64 = STANDARD.encode(&data);
+            let base58 = encode(&data).unwrap();
+            assert_eq!(
+                Ok(base58.clone()),
+                collect(base64_to_base58_stream(base64.as_bytes())).await
+            );
+            assert_eq!(
+                Ok(base64),
+                collect(base58_to_base64_stream(base58.as_bytes())).await
+            );
+        }
+
+        let mut data = String::from(&STANDARD.encode([0u8; 23])[..]);
+        data.push_str("AAAA");
+        assert_eq!(
+            Err(Error::InvalidLength),
+            collect(base64_to_base58_stream(data.as_bytes())).await
+        );
+    }
+}
@@ -0,0 +1,841 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Wrapper types holding base58 data
+//!
+//! [`Base58String`] is a string validated as Monero base58, [`Base58Str`] its borrowed
+//! counterpart, and [`Base58Bytes`] holds raw bytes displayed and parsed as Monero base58. All
+//! types may hold secret material, e.g. private keys, so their [`Debug`](core::fmt::Debug) and
+//! [`Display`](core::fmt::Display) implementations are redacted: only the first and last 4
+//! characters of the base58 string are printed, and its length by `Debug`. Use `reveal` to access
+//! the full value. Equality is tested in constant time.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use base58_monero::types::Base58Bytes;
+//!
+//! let key: Base58Bytes = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(69, key.reveal().len());
+//! assert_eq!(r#"Base58Bytes("4Au2..vSk5", len: 95)"#, format!("{:?}", key));
+//! assert_eq!("4Au2..vSk5", key.to_string());
+//! ```
+//!
+//! With the `serde` feature all types serialize as base58 strings. [`Base58Str`] deserializes
+//! without allocating from formats lending strings from their input, e.g. `serde_json` without
+//! escape sequences; mark the fields holding it with `#[serde(borrow)]`:
+//!
+//! ```rust
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use base58_monero::types::Base58Str;
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Deposit<'a> {
+//!     #[serde(borrow)]
+//!     address: Base58Str<'a>,
+//! }
+//!
+//! let json = r#"{"address": "D7LMXYjUbXc1fS9Z"}"#;
+//! let deposit: Deposit = serde_json::from_str(json).unwrap();
+//! assert_eq!("D7LMXYjUbXc1fS9Z", deposit.address.reveal());
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+//!
+//! With the `sqlx` feature both types are stored as text columns, e.g. `TEXT` in Postgres or
+//! SQLite, holding the base58 string. Values read from the database are validated, a column that
+//! is not valid base58 fails to decode.
+//!
+//! With the `diesel` feature [`Base58String`] maps to `Text` columns, validated when loaded, and
+//! [`Base58Bytes`] maps to `Binary` columns holding the raw bytes.
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use subtle::ConstantTimeEq;
+
+use crate::base58::FULL_ENCODED_BLOCK_SIZE;
+use crate::base58::{decode, decode_block, Encoder, Error, Result};
+
+// Number of characters kept at each end of a redacted base58 string
+const REDACTED_CHARS: usize = 4;
+
+// First and last characters of a redacted base58 string, `None` if too short to hide a middle part
+fn redacted_ends(encoded: &str) -> Option<(&str, &str)> {
+    let len = encoded.len();
+    if len > 2 * REDACTED_CHARS {
+        Some((&encoded[..REDACTED_CHARS], &encoded[len - REDACTED_CHARS..]))
+    } else {
+        None
+    }
+}
+
+// Print `NAME("abcd..wxyz", len: N)`, strings too short to hide a middle part only print their
+// length
+fn fmt_redacted(name: &str, encoded: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    match redacted_ends(encoded) {
+        Some((first, last)) => write!(
+            f,
+            "{}(\"{}..{}\", len: {})",
+            name,
+            first,
+            last,
+            encoded.len()
+        ),
+        None => write!(f, "{}(len: {})", name, encoded.len()),
+    }
+}
+
+// Print `abcd..wxyz`, only `..` for strings too short to hide a middle part
+fn display_redacted(encoded: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    match redacted_ends(encoded) {
+        Some((first, last)) => write!(f, "{}..{}", first, last),
+        None => f.write_str(".."),
+    }
+}
+
+// Write the redacted display of a base58 string to a `ufmt` formatter
+#[cfg(feature = "ufmt")]
+fn ufmt_redacted<W>(
+    encoded: &str,
+    f: &mut ufmt::Formatter<'_, W>,
+) -> core::result::Result<(), W::Error>
+where
+    W: ufmt::uWrite + ?Sized,
+{
+    if let Some((first, last)) = redacted_ends(encoded) {
+        f.write_str(first)?;
+        f.write_str("..")?;
+        f.write_str(last)
+    } else {
+        f.write_str("..")
+    }
+}
+
+// Validate a base58 string block by block, without allocating
+fn validate(data: &str) -> Result<()> {
+    data.as_bytes()
+        .chunks(FULL_ENCODED_BLOCK_SIZE)
+        .try_for_each(|block| decode_block(block).map(drop))
+}
+
+/// String validated as Monero base58
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct Base58String(String);
+
+impl Base58String {
+    /// Validate a base58-encoded string, returns the decoding error if invalid
+    pub fn new(data: impl Into<String>) -> Result<Self> {
+        let data = data.into();
+        validate(&data)?;
+        Ok(Self(data))
+    }
+
+    /// Encode bytes into a base58 string
+    pub fn from_bytes(data: impl AsRef<[u8]>) -> Self {
+        let mut encoder = Encoder::new();
+        encoder.update(data);
+        Self(encoder.finish())
+    }
+
+    /// Return the full base58 string
+    pub fn reveal(&self) -> &str {
+        &self.0
+    }
+
+    /// Decode the base58 string into bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match decode(&self.0) {
+            Ok(bytes) => bytes,
+            // The string is validated on construction
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Consume the wrapper and return the base58 string
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Debug for Base58String {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted("Base58String", &self.0, f)
+    }
+}
+
+impl fmt::Display for Base58String {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display_redacted(&self.0, f)
+    }
+}
+
+impl ConstantTimeEq for Base58String {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.as_bytes().ct_eq(other.0.as_bytes())
+    }
+}
+
+impl PartialEq for Base58String {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Base58String {}
+
+impl Hash for Base58String {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+// Same redacted string as `Display`
+#[cfg(feature = "ufmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+impl ufmt::uDisplay for Base58String {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt_redacted(&self.0, f)
+    }
+}
+
+impl FromStr for Base58String {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl From<Base58Bytes> for Base58String {
+    fn from(data: Base58Bytes) -> Self {
+        Self::from_bytes(data.0)
+    }
+}
+
+impl From<Base58Str<'_>> for Base58String {
+    fn from(data: Base58Str) -> Self {
+        Self(String::from(data.0))
+    }
+}
+
+/// Borrowed string validated as Monero base58
+#[derive(Clone, Copy)]
+pub struct Base58Str<'a>(&'a str);
+
+impl<'a> Base58Str<'a> {
+    /// Validate a borrowed base58-encoded string without allocating, returns the decoding error if
+    /// invalid
+    pub fn new(data: &'a str) -> Result<Self> {
+        validate(data)?;
+        Ok(Self(data))
+    }
+
+    /// Return the full base58 string
+    pub fn reveal(&self) -> &'a str {
+        self.0
+    }
+
+    /// Decode the base58 string into bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match decode(self.0) {
+            Ok(bytes) => bytes,
+            // The string is validated on construction
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Debug for Base58Str<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted("Base58Str", self.0, f)
+    }
+}
+
+impl fmt::Display for Base58Str<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display_redacted(self.0, f)
+    }
+}
+
+// Same redacted string as `Display`
+#[cfg(feature = "ufmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+impl ufmt::uDisplay for Base58Str<'_> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt_redacted(self.0, f)
+    }
+}
+
+impl ConstantTimeEq for Base58Str<'_> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.as_bytes().ct_eq(other.0.as_bytes())
+    }
+}
+
+impl PartialEq for Base58Str<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Base58Str<'_> {}
+
+impl Hash for Base58Str<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Base58Str<'a> {
+    type Error = Error;
+
+    fn try_from(data: &'a str) -> Result<Self> {
+        Self::new(data)
+    }
+}
+
+/// Raw bytes displayed and parsed as Monero base58
+#[derive(Clone, Default)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
+pub struct Base58Bytes(Vec<u8>);
+
+impl Base58Bytes {
+    /// Wrap raw bytes
+    pub fn new(data: impl Into<Vec<u8>>) -> Self {
+        Self(data.into())
+    }
+
+    /// Return the raw bytes
+    pub fn reveal(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Consume the wrapper and return the raw bytes
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl fmt::Debug for Base58Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_redacted("Base58Bytes", Base58String::from_bytes(&self.0).reveal(), f)
+    }
+}
+
+impl fmt::Display for Base58Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display_redacted(Base58String::from_bytes(&self.0).reveal(), f)
+    }
+}
+
+impl ConstantTimeEq for Base58Bytes {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for Base58Bytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Base58Bytes {}
+
+impl Hash for Base58Bytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+// Same redacted string as `Display`
+#[cfg(feature = "ufmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+impl ufmt::uDisplay for Base58Bytes {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> core::result::Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt_redacted(Base58String::from_bytes(&self.0).reveal(), f)
+    }
+}
+
+impl FromStr for Base58Bytes {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        decode(s).map(Self)
+    }
+}
+
+impl From<Vec<u8>> for Base58Bytes {
+    fn from(data: Vec<u8>) -> Self {
+        Self(data)
+    }
+}
+
+impl From<Base58String> for Base58Bytes {
+    fn from(data: Base58String) -> Self {
+        Self(data.to_bytes())
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    extern crate alloc;
+    use alloc::string::String;
+    use core::fmt;
+
+    use serde::de::{self, Deserialize, Deserializer, Visitor};
+    use serde::{Serialize, Serializer};
+
+    use super::{Base58Bytes, Base58Str, Base58String};
+    use crate::base58::{decode, display};
+
+    impl Serialize for Base58String {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    impl Serialize for Base58Str<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.0)
+        }
+    }
+
+    impl Serialize for Base58Bytes {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(&display(&self.0))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Base58String {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Base58String::new(String::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+
+    // Only strings borrowed from the input are accepted, transient strings would have to be copied
+    impl<'de: 'a, 'a> Deserialize<'de> for Base58Str<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Base58Str::new(<&'a str>::deserialize(deserializer)?).map_err(de::Error::custom)
+        }
+    }
+
+    struct BytesVisitor;
+
+    impl Visitor<'_> for BytesVisitor {
+        type Value = Base58Bytes;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a base58 string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Base58Bytes, E> {
+            decode(v).map(Base58Bytes).map_err(E::custom)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Base58Bytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_str(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    extern crate alloc;
+    use alloc::string::{String, ToString};
+
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    use super::{Base58Bytes, Base58String};
+    use crate::base58::display;
+
+    impl<DB: Database> Type<DB> for Base58String
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Base58String
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.0.encode_by_ref(buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Base58String
+    where
+        String: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(Self::new(String::decode(value)?)?)
+        }
+    }
+
+    impl<DB: Database> Type<DB> for Base58Bytes
+    where
+        String: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Base58Bytes
+    where
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            display(&self.0).to_string().encode(buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for Base58Bytes
+    where
+        String: Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(String::decode(value)?.parse()?)
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    extern crate alloc;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::{Binary, Text};
+
+    use super::{Base58Bytes, Base58String};
+
+    impl<DB: Backend> ToSql<Text, DB> for Base58String
+    where
+        str: ToSql<Text, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0.as_str().to_sql(out)
+        }
+    }
+
+    impl<DB: Backend> FromSql<Text, DB> for Base58String
+    where
+        String: FromSql<Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(Self::new(String::from_sql(bytes)?)?)
+        }
+    }
+
+    impl<DB: Backend> ToSql<Binary, DB> for Base58Bytes
+    where
+        [u8]: ToSql<Binary, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0.as_slice().to_sql(out)
+        }
+    }
+
+    impl<DB: Backend> FromSql<Binary, DB> for Base58Bytes
+    where
+        Vec<u8>: FromSql<Binary, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Vec::from_sql(bytes).map(Self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::format;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use super::{Base58Bytes, Base58Str, Base58String};
+    use crate::base58::Error;
+
+    #[test]
+    fn base58_string() {
+        let s = Base58String::new("D7LMXYjUbXc1fS9Z").unwrap();
+        assert_eq!("D7LMXYjUbXc1fS9Z", s.reveal());
+        assert_eq!(Vec::from(&b"Hello World"[..]), s.to_bytes());
+        assert_eq!(s, Base58String::from_bytes(b"Hello World"));
+        assert_eq!(Ok(s.clone()), "D7LMXYjUbXc1fS9Z".parse());
+        assert_eq!("D7LM..fS9Z", s.to_string());
+        assert_eq!(
+            String::from("D7LMXYjUbXc1fS9Z"),
+            Base58String::from(Base58Bytes::from(s)).into_string()
+        );
+
+        assert_eq!(Err(Error::InvalidSymbol), Base58String::new("10"));
+        assert_eq!(Err(Error::InvalidBlockSize), "1".parse::<Base58String>());
+    }
+
+    #[test]
+    fn base58_str() {
+        let data = String::from("D7LMXYjUbXc1fS9Z");
+        let s = Base58Str::new(&data).unwrap();
+        assert_eq!("D7LMXYjUbXc1fS9Z", s.reveal());
+        assert_eq!(Vec::from(&b"Hello World"[..]), s.to_bytes());
+        assert_eq!("D7LM..fS9Z", s.to_string());
+        assert_eq!(Base58String::new(&data[..]), Ok(Base58String::from(s)));
+        assert_eq!(r#"Base58Str("D7LM..fS9Z", len: 16)"#, format!("{:?}", s));
+
+        assert_eq!(Err(Error::InvalidSymbol), Base58Str::new("10"));
+        assert_eq!(Err(Error::InvalidBlockSize), Base58Str::try_from("1"));
+        assert_eq!(Err(Error::Overflow), Base58Str::try_from("5R"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let addrs = r#"["D7LMXYjUbXc1fS9Z","D7LMXYjUbXc5LVkq6vWDY"]"#;
+        let strs: Vec<Base58Str> = serde_json::from_str(addrs).unwrap();
+        // Borrowed from the input
+        assert!(addrs
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&strs[0].reveal().as_ptr()));
+        assert_eq!(addrs, serde_json::to_string(&strs).unwrap());
+
+        let strings: Vec<Base58String> = serde_json::from_str(addrs).unwrap();
+        assert_eq!(addrs, serde_json::to_string(&strings).unwrap());
+        let bytes: Vec<Base58Bytes> = serde_json::from_str(addrs).unwrap();
+        assert_eq!(b"Hello World", bytes[0].reveal());
+        assert_eq!(addrs, serde_json::to_string(&bytes).unwrap());
+
+        // Invalid base58 and strings that cannot be borrowed are rejected
+        assert!(serde_json::from_str::<Base58String>(r#""10""#).is_err());
+        assert!(serde_json::from_str::<Base58Bytes>(r#""5R""#).is_err());
+        assert!(serde_json::from_str::<Base58Str>(r#""10""#).is_err());
+        assert!(serde_json::from_str::<Base58Str>(r#""D7LMXYjUbXc1fS9\u005a""#).is_err());
+    }
+
+    #[test]
+    fn base58_bytes() {
+        let b: Base58Bytes = "D7LMXYjUbXc1fS9Z".parse().unwrap();
+        assert_eq!(b"Hello World", b.reveal());
+        assert_eq!("D7LM..fS9Z", b.to_string());
+        assert_eq!(b, Base58Bytes::new(&b"Hello World"[..]));
+        assert_eq!(Vec::from(&b"Hello World"[..]), b.into_vec());
+        assert_eq!(Err(Error::Overflow), "5R".parse::<Base58Bytes>());
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let s = Base58String::new("D7LMXYjUbXc1fS9Z").unwrap();
+        assert_eq!(r#"Base58String("D7LM..fS9Z", len: 16)"#, format!("{:?}", s));
+        let b = Base58Bytes::from(s);
+        assert_eq!(r#"Base58Bytes("D7LM..fS9Z", len: 16)"#, format!("{:?}", b));
+
+        // Too short to keep both ends
+        assert_eq!(
+            "Base58String(len: 7)",
+            format!("{:?}", Base58String::new("1111111").unwrap())
+        );
+        assert_eq!(
+            "Base58Bytes(len: 0)",
+            format!("{:?}", Base58Bytes::default())
+        );
+        assert_eq!("..", Base58String::new("1111111").unwrap().to_string());
+        assert_eq!("..", Base58Bytes::default().to_string());
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Keys {
+            spend: Base58Bytes,
+        }
+        let keys = Keys {
+            spend: Base58Bytes::new(&[0xffu8; 32][..]),
+        };
+        let spend = Base58String::from(keys.spend.clone()).into_string();
+        assert!(!format!("{:?}", keys).contains(&spend));
+    }
+
+    #[test]
+    #[cfg(feature = "ufmt")]
+    fn ufmt_is_redacted() {
+        use ufmt::uwrite;
+
+        struct Buf(String);
+
+        impl ufmt::uWrite for Buf {
+            type Error = core::convert::Infallible;
+
+            fn write_str(&mut self, s: &str) -> Result<(), Self::Error> {
+                self.0.push_str(s);
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf(String::new());
+        let s = Base58String::new("D7LMXYjUbXc1fS9Z").unwrap();
+        uwrite!(buf, "{}", s).unwrap();
+        uwrite!(buf, "{}", Base58Bytes::from(s)).unwrap();
+        uwrite!(buf, "{}", Base58Bytes::default()).unwrap();
+        uwrite!(buf, "{}", Base58Str::new("D7LMXYjUbXc1fS9Z").unwrap()).unwrap();
+        assert_eq!("D7LM..fS9ZD7LM..fS9Z..D7LM..fS9Z", buf.0);
+    }
+
+    #[test]
+    fn constant_time_eq() {
+        use subtle::ConstantTimeEq;
+
+        let a = Base58Bytes::new(&b"Hello World"[..]);
+        let b = Base58Bytes::new(&b"Hello Worle"[..]);
+        assert!(bool::from(a.ct_eq(&a.clone())));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert_ne!(a, b);
+        assert_ne!(a, Base58Bytes::new(&b"Hello"[..]));
+
+        assert_ne!(Base58String::from(a), Base58String::from(b));
+        let data = String::from("D7LMXYjUbXc1fS9Z");
+        assert_eq!(Base58Str::new(&data), Base58Str::new("D7LMXYjUbXc1fS9Z"));
+        assert_ne!(Base58Str::new(&data), Base58Str::new("D7LMXYjUbXc1fS9a"));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "sqlx")]
+    async fn sqlx_round_trip() {
+        use sqlx::{Connection, SqliteConnection};
+
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE keys (s TEXT, b TEXT)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        let s = Base58String::new("D7LMXYjUbXc1fS9Z").unwrap();
+        let b = Base58Bytes::new(&b"Hello World"[..]);
+        sqlx::query("INSERT INTO keys VALUES (?, ?)")
+            .bind(&s)
+            .bind(&b)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        // Stored as the base58 text
+        let row: (String, String) = sqlx::query_as("SELECT s, b FROM keys")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!((s.reveal().to_string(), s.reveal().to_string()), row);
+        let row: (Base58String, Base58Bytes) = sqlx::query_as("SELECT s, b FROM keys")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!((s, b), row);
+
+        // Invalid base58 is rejected when decoding the column
+        let res: Result<(Base58String,), _> =
+            sqlx::query_as("SELECT '10'").fetch_one(&mut conn).await;
+        assert!(res.is_err());
+        let res: Result<(Base58Bytes,), _> =
+            sqlx::query_as("SELECT '5R'").fetch_one(&mut conn).await;
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "diesel")]
+    fn diesel_round_trip() {
+        use diesel::connection::SimpleConnection;
+        use diesel::sql_types::{Binary, Text};
+        use diesel::{Connection, QueryableByName, RunQueryDsl, SqliteConnection};
+
+        #[derive(QueryableByName, Debug, PartialEq)]
+        struct Row {
+            #[diesel(sql_type = Text)]
+            s: Base58String,
+            #[diesel(sql_type = Binary)]
+            b: Base58Bytes,
+        }
+
+        let mut conn = SqliteConnection::establish(":memory:").unwrap();
+        conn.batch_execute("CREATE TABLE keys (s TEXT, b BLOB)")
+            .unwrap();
+        let row = Row {
+            s: Base58String::new("D7LMXYjUbXc1fS9Z").unwrap(),
+            b: Base58Bytes::new(&b"Hello World"[..]),
+        };
+        diesel::sql_query("INSERT INTO keys VALUES (?, ?)")
+            .bind::<Text, _>(&row.s)
+            .bind::<Binary, _>(&row.b)
+            .execute(&mut conn)
+            .unwrap();
+        let rows: Vec<Row> = diesel::sql_query("SELECT s, b FROM keys")
+            .load(&mut conn)
+            .unwrap();
+        assert_eq!(vec![row], rows);
+
+        // Invalid base58 is rejected when loading the column
+        conn.batch_execute("UPDATE keys SET s = '10'").unwrap();
+        let res: Result<Vec<Row>, _> = diesel::sql_query("SELECT s, b FROM keys").load(&mut conn);
+        assert!(res.is_err());
+    }
+}
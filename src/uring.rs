@@ -0,0 +1,175 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! File helpers built on `tokio-uring`
+//!
+//! Encode and decode whole files with the `io_uring` interface of Linux, files are processed in
+//! large chunks aligned on block sizes with owned buffers reused across reads and writes. The
+//! functions must run within a `tokio-uring` runtime.
+//!
+//! ## Examples
+//!
+//! ```rust,no_run
+//! use base58_monero::uring::{decode_file, encode_file};
+//!
+//! tokio_uring::start(async {
+//!     encode_file("archive.bin", "archive.b58").await?;
+//!     decode_file("archive.b58", "archive.out").await
+//! })
+//! .unwrap();
+//! ```
+
+use std::io;
+use std::path::Path;
+
+use tokio_uring::buf::IoBuf;
+use tokio_uring::fs::File;
+
+use crate::base58::{
+    decode_from_reader, encode_from_reader, FULL_BLOCK_SIZE, FULL_ENCODED_BLOCK_SIZE,
+};
+
+/// Number of blocks read from the input file at once
+pub const CHUNK_BLOCKS: usize = 8192;
+
+// Read at `pos` until `buf` is full or EOF is reached, returns the number of bytes read
+async fn read_full(file: &File, mut buf: Vec<u8>, pos: u64) -> (io::Result<usize>, Vec<u8>) {
+    let mut len = 0;
+    while len < buf.len() {
+        let end = buf.len();
+        let (res, slice) = file.read_at(buf.slice(len..end), pos + len as u64).await;
+        buf = slice.into_inner();
+        match res {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) => return (Err(e), buf),
+        }
+    }
+    (Ok(len), buf)
+}
+
+// Write the whole buffer at `pos`
+async fn write_all(file: &File, mut buf: Vec<u8>, pos: u64) -> (io::Result<()>, Vec<u8>) {
+    let mut len = 0;
+    while len < buf.len() {
+        let end = buf.len();
+        let (res, slice) = file.write_at(buf.slice(len..end), pos + len as u64).await;
+        buf = slice.into_inner();
+        match res {
+            Ok(0) => return (Err(io::ErrorKind::WriteZero.into()), buf),
+            Ok(n) => len += n,
+            Err(e) => return (Err(e), buf),
+        }
+    }
+    (Ok(()), buf)
+}
+
+// Read `src` in chunks of `chunk_size` bytes, convert each chunk with `f` and write the result
+// to `dst`
+async fn convert_file<F>(
+    src: impl AsRef<Path>,
+    dst: impl AsRef<Path>,
+    chunk_size: usize,
+    f: F,
+) -> io::Result<()>
+where
+    F: Fn(&[u8], &mut Vec<u8>) -> io::Result<()>,
+{
+    let input = File::open(src).await?;
+    let output = File::create(dst).await?;
+    let mut buf = vec![0; chunk_size];
+    let mut out = Vec::new();
+    let (mut read_pos, mut write_pos) = (0, 0);
+
+    loop {
+        let (res, b) = read_full(&input, buf, read_pos).await;
+        buf = b;
+        let len = res?;
+        read_pos += len as u64;
+
+        out.clear();
+        f(&buf[..len], &mut out)?;
+        let written = out.len() as u64;
+        let (res, o) = write_all(&output, out, write_pos).await;
+        out = o;
+        res?;
+        write_pos += written;
+
+        if len < chunk_size {
+            break;
+        }
+    }
+
+    input.close().await?;
+    output.close().await
+}
+
+/// Encode the content of the `src` file into base58 and write it to the `dst` file
+pub async fn encode_file(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> io::Result<()> {
+    convert_file(src, dst, CHUNK_BLOCKS * FULL_BLOCK_SIZE, |data, out| {
+        encode_from_reader(data, out)
+    })
+    .await
+}
+
+/// Decode the base58 content of the `src` file and write the bytes to the `dst` file
+///
+/// Decoding errors are returned as [`std::io::ErrorKind::InvalidData`] errors wrapping
+/// [`Error`](crate::base58::Error).
+pub async fn decode_file(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> io::Result<()> {
+    convert_file(
+        src,
+        dst,
+        CHUNK_BLOCKS * FULL_ENCODED_BLOCK_SIZE,
+        |data, out| decode_from_reader(data, out),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_file, encode_file, CHUNK_BLOCKS};
+    use crate::base58::{encode, FULL_BLOCK_SIZE};
+
+    #[test]
+    fn file_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("base58-monero-uring-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (src, encoded, decoded) = (dir.join("src"), dir.join("encoded"), dir.join("decoded"));
+
+        // Spans several chunks with a partial last block
+        let data: Vec<u8> = (0..CHUNK_BLOCKS * FULL_BLOCK_SIZE * 2 + 5)
+            .map(|i| i as u8)
+            .collect();
+        for len in [0, 5, data.len()] {
+            std::fs::write(&src, &data[..len]).unwrap();
+            tokio_uring::start(async {
+                encode_file(&src, &encoded).await.unwrap();
+                decode_file(&encoded, &decoded).await.unwrap();
+            });
+            assert_eq!(
+                encode(&data[..len]).unwrap().as_bytes(),
+                &std::fs::read(&encoded).unwrap()[..]
+            );
+            assert_eq!(&data[..len], &std::fs::read(&decoded).unwrap()[..]);
+        }
+
+        std::fs::write(&encoded, b"D7LMXYjUbXc10").unwrap();
+        let err = tokio_uring::start(decode_file(&encoded, &decoded)).unwrap_err();
+        assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,234 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Allocation count assertions
+//!
+//! A counting global allocator checks the documented allocation behavior of the functions, e.g.
+//! the fallible allocation functions only allocate their output once and the functions writing
+//! into slices, arrays and `heapless` containers do not allocate.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use base58_monero::{encode, try_decode, try_encode};
+
+struct CountingAllocator;
+
+thread_local! {
+    // Per thread count, tests run concurrently
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Return the result of `f` and the number of allocations, including reallocations, it made
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let start = ALLOCATIONS.with(Cell::get);
+    let res = f();
+    (res, ALLOCATIONS.with(Cell::get) - start)
+}
+
+// Standard address, 65 bytes and a 4 bytes checksum
+const ADDRESS: &str = "4Au2dGq2uFHWapfkU1RF4X6tFdY1rKtNfJrfsNSUinrRK3d8ZBViLtz5NGQiBM1xM5LeD4ak5Q2869PfC7hUWuDA5RzvSk5";
+
+#[test]
+fn test_try_encode_allocations() {
+    let data = [0xffu8; 69];
+    let (res, count) = count_allocations(|| try_encode(data));
+    assert_eq!(1, count);
+    assert_eq!(encode(data), res);
+
+    let (res, count) = count_allocations(|| try_encode(b""));
+    assert_eq!(0, count);
+    assert_eq!(Ok(String::new()), res);
+}
+
+#[test]
+// The `self-check` feature allocates to decode and re-encode the output
+#[cfg(not(all(feature = "self-check", debug_assertions)))]
+fn test_encode_allocations() {
+    let data = [0xffu8; 69];
+    let (res, count) = count_allocations(|| encode(data));
+    assert_eq!(1, count);
+    assert_eq!(95, res.unwrap().len());
+
+    #[cfg(feature = "check")]
+    {
+        use base58_monero::encode_check;
+
+        let (res, count) = count_allocations(|| encode_check(&data[..65]));
+        assert_eq!(1, count);
+        assert_eq!(95, res.unwrap().len());
+    }
+}
+
+#[test]
+fn test_try_decode_allocations() {
+    let (res, count) = count_allocations(|| try_decode(ADDRESS));
+    assert_eq!(1, count);
+    assert_eq!(69, res.unwrap().len());
+
+    // Errors do not allocate more than the output reservation
+    let (res, count) = count_allocations(|| try_decode("123456789AB5R"));
+    assert_eq!(1, count);
+    assert!(res.is_err());
+}
+
+#[test]
+// The `self-check` feature allocates to re-encode the decoded bytes
+#[cfg(all(
+    feature = "scratch",
+    feature = "check",
+    not(all(feature = "self-check", debug_assertions))
+))]
+fn test_decode_check_scratch_allocations() {
+    use base58_monero::decode_check;
+
+    // The first call grows the scratch buffer of the thread
+    decode_check(ADDRESS).unwrap();
+    let (res, count) = count_allocations(|| decode_check(ADDRESS));
+    assert_eq!(1, count);
+    assert_eq!(65, res.unwrap().len());
+}
+
+#[test]
+fn test_slice_allocations() {
+    use base58_monero::{decode_into, decode_to_array, encode_into, encode_to_array, is_base58};
+
+    let data = [0xffu8; 69];
+    let mut buf = [0u8; 128];
+    let (res, count) = count_allocations(|| encode_into(data, &mut buf));
+    assert_eq!(0, count);
+    assert_eq!(95, res.unwrap());
+    let (res, count) = count_allocations(|| encode_into(data, &mut buf[..94]));
+    assert_eq!(0, count);
+    assert!(res.is_err());
+    let (res, count) = count_allocations(|| encode_to_array::<95>(data));
+    assert_eq!(0, count);
+    assert_eq!(95, res.unwrap().1);
+
+    let (res, count) = count_allocations(|| decode_into(ADDRESS, &mut buf));
+    assert_eq!(0, count);
+    assert_eq!(69, res.unwrap());
+    let (res, count) = count_allocations(|| decode_to_array::<69>(ADDRESS));
+    assert_eq!(0, count);
+    assert_eq!(69, res.unwrap().1);
+    let (res, count) = count_allocations(|| is_base58(ADDRESS));
+    assert_eq!(0, count);
+    assert!(res);
+}
+
+#[test]
+#[cfg(feature = "check")]
+fn test_slice_check_allocations() {
+    use base58_monero::{
+        decode_check_into, decode_check_to_array, encode_check_into, encode_check_to_array,
+    };
+
+    let data = [0xffu8; 65];
+    let mut buf = [0u8; 128];
+    let (res, count) = count_allocations(|| encode_check_into(data, &mut buf));
+    assert_eq!(0, count);
+    assert_eq!(95, res.unwrap());
+    let (res, count) = count_allocations(|| encode_check_to_array::<95>(data));
+    assert_eq!(0, count);
+    assert_eq!(95, res.unwrap().1);
+
+    let (res, count) = count_allocations(|| decode_check_into(ADDRESS, &mut buf));
+    assert_eq!(0, count);
+    assert_eq!(65, res.unwrap());
+    let (res, count) = count_allocations(|| decode_check_to_array::<65>(ADDRESS));
+    assert_eq!(0, count);
+    assert_eq!(65, res.unwrap().1);
+}
+
+#[test]
+#[cfg(feature = "heapless")]
+fn test_heapless_allocations() {
+    use base58_monero::{decode_heapless, encode_heapless};
+
+    let data = [0xffu8; 69];
+    let (res, count) = count_allocations(|| encode_heapless::<95>(data));
+    assert_eq!(0, count);
+    assert_eq!(95, res.unwrap().len());
+    let (res, count) = count_allocations(|| decode_heapless::<69>(ADDRESS));
+    assert_eq!(0, count);
+    assert_eq!(69, res.unwrap().len());
+
+    #[cfg(feature = "check")]
+    {
+        use base58_monero::{decode_check_heapless, encode_check_heapless};
+
+        let (res, count) = count_allocations(|| encode_check_heapless::<95>(&data[..65]));
+        assert_eq!(0, count);
+        assert_eq!(95, res.unwrap().len());
+        let (res, count) = count_allocations(|| decode_check_heapless::<65>(ADDRESS));
+        assert_eq!(0, count);
+        assert_eq!(65, res.unwrap().len());
+    }
+}
+
+#[test]
+#[cfg(feature = "generic-array")]
+#[allow(deprecated)]
+fn test_generic_array_allocations() {
+    use base58_monero::decode_generic_array;
+    use generic_array::typenum::U69;
+
+    let (res, count) = count_allocations(|| decode_generic_array::<U69>(ADDRESS));
+    assert_eq!(0, count);
+    assert!(res.is_ok());
+}
+
+#[test]
+fn test_display_allocations() {
+    use base58_monero::display;
+    use core::fmt::Write;
+
+    // Writer into a fixed buffer
+    struct Buf([u8; 128], usize);
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+            self.1 += s.len();
+            Ok(())
+        }
+    }
+
+    let data = [0xffu8; 69];
+    let mut buf = Buf([0; 128], 0);
+    let (res, count) = count_allocations(|| write!(buf, "{}", display(&data)));
+    assert_eq!(0, count);
+    assert!(res.is_ok());
+    assert_eq!(95, buf.1);
+}
@@ -0,0 +1,116 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Differential conformance tests against the Monero reference implementation
+//!
+//! Replays a corpus generated with `conformance/generate.cpp` and asserts this crate agrees with
+//! the reference implementation on every record. The corpus path is read from the
+//! `BASE58_MONERO_CORPUS` environment variable, the test is ignored by default and fails when the
+//! variable is not set, run it with `cargo test --features conformance -- --ignored`.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use base58_monero::{decode, decode_check, encode, encode_check, parse_address_parts};
+
+// Maximum number of mismatches reported in the panic message
+const MAX_REPORTED: usize = 20;
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn hex(field: &str) -> Vec<u8> {
+    hex::decode(field).expect("valid hex field in corpus")
+}
+
+// Check a single record, return a description of the disagreement if any
+fn check(fields: &[&str]) -> Result<(), String> {
+    match fields {
+        ["encode", data, enc] => {
+            let data = hex(data);
+            if encode(&data).as_deref() != Ok(*enc) {
+                return Err(format!("encode: got {:?}", encode(&data)));
+            }
+            if decode(enc).as_ref() != Ok(&data) {
+                return Err(format!("decode: got {:?}", decode(enc)));
+            }
+        }
+        ["decode", enc, "-"] => {
+            if let Ok(data) = decode(enc) {
+                return Err(format!("decode: accepted {}", hex::encode(data)));
+            }
+        }
+        ["decode", enc, data] => {
+            if decode(enc) != Ok(hex(data)) {
+                return Err(format!("decode: got {:?}", decode(enc)));
+            }
+        }
+        ["encode_addr", tag, data, addr] => {
+            let mut bytes = Vec::new();
+            write_varint(&mut bytes, tag.parse().expect("valid tag in corpus"));
+            bytes.extend(hex(data));
+            if encode_check(&bytes).as_deref() != Ok(*addr) {
+                return Err(format!("encode_check: got {:?}", encode_check(&bytes)));
+            }
+        }
+        ["decode_addr", addr, "-", "-"] => {
+            if let Ok(parts) = decode_check(addr).and_then(|_| parse_address_parts(addr)) {
+                return Err(format!("decode_check: accepted {:?}", parts));
+            }
+        }
+        ["decode_addr", addr, tag, data] => {
+            decode_check(addr).map_err(|e| format!("decode_check: got {:?}", e))?;
+            let parts = parse_address_parts(addr).map_err(|e| format!("parse: got {:?}", e))?;
+            if parts.prefix.to_string() != *tag || parts.payload != hex(data) {
+                return Err(format!("parse: got {:?}", parts));
+            }
+        }
+        _ => panic!("malformed corpus record: {:?}", fields),
+    }
+    Ok(())
+}
+
+#[test]
+#[ignore = "needs a corpus generated by the reference implementation in BASE58_MONERO_CORPUS"]
+fn test_conformance() {
+    let path = std::env::var("BASE58_MONERO_CORPUS").expect("BASE58_MONERO_CORPUS is set");
+    let corpus = BufReader::new(File::open(&path).expect("readable corpus file"));
+
+    let mut records = 0;
+    let mut mismatches = Vec::new();
+    for (i, line) in corpus.lines().enumerate() {
+        let line = line.expect("valid corpus line");
+        // Fields are separated by a single space, base58 fields can be empty
+        let fields: Vec<&str> = line.split(' ').collect();
+        records += 1;
+        if let Err(e) = check(&fields) {
+            mismatches.push(format!("line {}: {}: {}", i + 1, line, e));
+        }
+    }
+
+    assert!(records > 0, "empty corpus {}", path);
+    assert!(
+        mismatches.is_empty(),
+        "{} mismatches over {} records:\n{}",
+        mismatches.len(),
+        records,
+        mismatches[..mismatches.len().min(MAX_REPORTED)].join("\n")
+    );
+}
@@ -0,0 +1,266 @@
+// Rust Monero Base58 Library
+// Written in 2019-2023 by
+//   Monero Rust Contributors
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+
+//! Regression corpus of inputs previously crashing or mis-handled
+//!
+//! Every past edge case is pinned here with its expected result and replayed through the same
+//! invariants as the fuzz targets in `fuzz/fuzz_targets`. New fuzz findings are added to
+//! [`DECODE_CORPUS`] when the crashing input is a string and to [`ENCODE_CORPUS`] when it is a
+//! byte slice, with the name of the target that found them.
+
+use base58_monero::{decode, decode_check, encode, encode_check, Error};
+
+// Base58 input with the results expected from `decode` and `decode_check`, decoded bytes are hex
+struct DecodeCase {
+    name: &'static str,
+    input: &'static str,
+    decode: Result<&'static str, Error>,
+    decode_check: Result<&'static str, Error>,
+}
+
+const DECODE_CORPUS: &[DecodeCase] = &[
+    DecodeCase {
+        name: "single symbol, invalid block size",
+        input: "1",
+        decode: Err(Error::InvalidBlockSize),
+        decode_check: Err(Error::InvalidBlockSize),
+    },
+    DecodeCase {
+        name: "full block followed by a single symbol",
+        input: "111111111111",
+        decode: Err(Error::InvalidBlockSize),
+        decode_check: Err(Error::InvalidBlockSize),
+    },
+    DecodeCase {
+        name: "NUL byte",
+        input: "\0",
+        decode: Err(Error::InvalidBlockSize),
+        decode_check: Err(Error::InvalidBlockSize),
+    },
+    DecodeCase {
+        name: "symbol outside of the alphabet",
+        input: "10",
+        decode: Err(Error::InvalidSymbol),
+        decode_check: Err(Error::InvalidSymbol),
+    },
+    DecodeCase {
+        name: "non-ASCII character",
+        input: "é",
+        decode: Err(Error::InvalidSymbol),
+        decode_check: Err(Error::InvalidSymbol),
+    },
+    DecodeCase {
+        name: "1 byte block over the maximum value",
+        input: "5R",
+        decode: Err(Error::Overflow),
+        decode_check: Err(Error::Overflow),
+    },
+    DecodeCase {
+        name: "empty input, shorter than the checksum",
+        input: "",
+        decode: Ok(""),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "1 byte, shorter than the checksum",
+        input: "5Q",
+        decode: Ok("ff"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "3 bytes, shorter than the checksum",
+        input: "11111",
+        decode: Ok("000000"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "full block of maximum value",
+        input: "jpXCZedGfVQ",
+        decode: Ok("ffffffffffffffff"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "full block over the maximum value",
+        input: "jpXCZedGfVR",
+        decode: Err(Error::Overflow),
+        decode_check: Err(Error::Overflow),
+    },
+    DecodeCase {
+        name: "full block overflowing 64 bits",
+        input: "zzzzzzzzzzz",
+        decode: Err(Error::Overflow),
+        decode_check: Err(Error::Overflow),
+    },
+    DecodeCase {
+        name: "full block of zeros",
+        input: "11111111111",
+        decode: Ok("0000000000000000"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "full block and 1 byte block of maximum value",
+        input: "jpXCZedGfVQ5Q",
+        decode: Ok("ffffffffffffffffff"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+    DecodeCase {
+        name: "valid checksum",
+        input: "D7LMXYjUbXc5LVkq6vWDY",
+        decode: Ok("48656c6c6f20576f726c64592fa743"),
+        decode_check: Ok("48656c6c6f20576f726c64"),
+    },
+    DecodeCase {
+        name: "invalid checksum",
+        input: "D7LMXYjUbXc5LVkq6vWDZ",
+        decode: Ok("48656c6c6f20576f726c64592fa744"),
+        decode_check: Err(Error::InvalidChecksum),
+    },
+];
+
+// Bytes with their expected encoding
+struct EncodeCase {
+    name: &'static str,
+    input: &'static [u8],
+    encode: &'static str,
+}
+
+const ENCODE_CORPUS: &[EncodeCase] = &[
+    EncodeCase {
+        name: "empty input",
+        input: &[],
+        encode: "",
+    },
+    EncodeCase {
+        name: "single zero byte",
+        input: &[0],
+        encode: "11",
+    },
+    EncodeCase {
+        name: "full block of maximum value",
+        input: &[0xff; 8],
+        encode: "jpXCZedGfVQ",
+    },
+    EncodeCase {
+        name: "full block and 1 byte block of maximum value",
+        input: &[0xff; 9],
+        encode: "jpXCZedGfVQ5Q",
+    },
+    EncodeCase {
+        name: "leading zeros are kept",
+        input: &[0; 16],
+        encode: "1111111111111111111111",
+    },
+    EncodeCase {
+        name: "address length of maximum value",
+        input: &[0xff; 69],
+        encode: "jpXCZedGfVQjpXCZedGfVQjpXCZedGfVQjpXCZedGfVQjpXCZedGfVQjpXCZedGfVQjpXCZedGfVQ\
+            jpXCZedGfVQVtB5VXc",
+    },
+];
+
+// Compare a decoding result with the expected hex bytes or error
+fn assert_decoded(expected: &Result<&str, Error>, res: Result<Vec<u8>, Error>, context: &str) {
+    let res = res.map(hex::encode);
+    assert_eq!(
+        expected.as_ref().map(|s| *s),
+        res.as_ref().map(String::as_str),
+        "{}",
+        context
+    );
+}
+
+#[cfg(feature = "stream")]
+fn collect_stream(
+    stream: impl futures_util::Stream<Item = Result<u8, Error>>,
+) -> Result<Vec<u8>, Error> {
+    use futures_util::{pin_mut, StreamExt};
+
+    tokio_test::block_on(async {
+        pin_mut!(stream);
+        let mut res = Vec::new();
+        while let Some(value) = stream.next().await {
+            res.push(value?);
+        }
+        Ok(res)
+    })
+}
+
+#[test]
+fn decode_corpus() {
+    for case in DECODE_CORPUS {
+        assert_decoded(&case.decode, decode(case.input), case.name);
+        assert_decoded(&case.decode_check, decode_check(case.input), case.name);
+
+        // Any valid base58 string has a single representation
+        if let Ok(bytes) = decode(case.input) {
+            assert_eq!(
+                Ok(String::from(case.input)),
+                encode(&bytes),
+                "{}",
+                case.name
+            );
+        }
+        if let Ok(bytes) = decode_check(case.input) {
+            assert_eq!(
+                Ok(String::from(case.input)),
+                encode_check(&bytes),
+                "{}",
+                case.name
+            );
+        }
+
+        // Stream decoders must agree with the one-shot decoders
+        #[cfg(feature = "stream")]
+        {
+            use base58_monero::{decode_stream, decode_stream_check};
+
+            let stream = decode_stream(case.input.as_bytes());
+            assert_eq!(decode(case.input), collect_stream(stream), "{}", case.name);
+            let stream = decode_stream_check(case.input.as_bytes());
+            assert_eq!(
+                decode_check(case.input),
+                collect_stream(stream),
+                "{}",
+                case.name
+            );
+        }
+    }
+}
+
+#[test]
+fn encode_corpus() {
+    for case in ENCODE_CORPUS {
+        assert_eq!(
+            Ok(String::from(case.encode)),
+            encode(case.input),
+            "{}",
+            case.name
+        );
+        assert_eq!(
+            Ok(Vec::from(case.input)),
+            decode(case.encode),
+            "{}",
+            case.name
+        );
+
+        let encoded = encode_check(case.input).unwrap();
+        assert_eq!(
+            Ok(Vec::from(case.input)),
+            decode_check(&encoded),
+            "{}",
+            case.name
+        );
+    }
+}